@@ -1,244 +1,1646 @@
-use crate::network::{NetworkUtils, PortManager};
-use crate::process::{ProcessKiller, ProcessMonitor};
-use anyhow::Result;
+use crate::commands::diagnostics::{
+    error_location, flatten, key_line, merge_layers, CheckRecord, CheckStatus, ConfigEntry,
+    PathRecord,
+};
+use crate::commands::output::{
+    format_box_table, optional, truncate, OutputFormat, OutputOptions, Record,
+};
+use crate::commands::ps::{
+    format_process_table, process_rows, select_processes, ProcessColumn, PsOptions,
+};
+use crate::commands::watch::{self, render_frame, ChangeTracker, RowChange};
+use crate::config::paths::config_dir_with_source;
+use crate::config::presets::{
+    load_presets, preset_paths, project_presets_path, user_presets_path, PresetLayer, Presets,
+    BUNDLED_PRESETS,
+};
+use crate::config::project::{current_project, find_project_file, Project, PROJECT_FILE};
+use crate::config::settings::{
+    apply_env_overrides, load_settings, settings_path, unknown_keys, UserSettings,
+};
+use crate::network::{NetworkUtils, PortInfo, PortManager};
+use crate::process::{
+    find_action, format_timestamp, plan_action, plan_project_free, plan_project_stop,
+    project_status, run_as_rule, ActionPlan, AuditEntry, AuditLog, KillError, KillFailure,
+    KillOutcome, MatchMode, ProcessInfo, ProcessKiller, ProcessManager, ProcessMatcher,
+    ProcessMonitor, ServiceState, ServiceStatus, Signal,
+};
+use crate::tui::themes::ThemeManager;
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+use sysinfo::MINIMUM_CPU_UPDATE_INTERVAL;
 
 pub struct CliHandler;
 
-impl CliHandler {
-    pub async fn show_port_info(port: u16) -> Result<()> {
-        let ports = PortManager::get_port_by_number(port)?;
+/// Options for `bossy-rust kill-process`.
+#[derive(Debug, Clone, Default)]
+pub struct KillProcessOptions {
+    pub pattern: String,
+    pub mode: MatchMode,
+    pub force: bool,
+    /// Match processes of every user, not just the current one
+    pub all_users: bool,
+    /// List what would be killed without signalling anything
+    pub dry_run: bool,
+    /// Show the matches and ask for confirmation first
+    pub interactive: bool,
+}
+
+/// Options for `bossy-rust signal`.
+#[derive(Debug, Clone)]
+pub struct SignalOptions {
+    /// A PID, or a process name matched according to `mode`
+    pub target: String,
+    pub signal: Signal,
+    pub mode: MatchMode,
+    pub all_users: bool,
+}
+
+/// Parse a signal argument: `STOP`, `SIGSTOP`, `stop` or `9`.
+pub fn parse_signal(value: &str) -> Result<Signal, String> {
+    value.parse().map_err(|e: anyhow::Error| e.to_string())
+}
 
-        if ports.is_empty() {
-            println!("No processes found using port {port}");
-            return Ok(());
+/// Outcome of a CLI command, mapped to the process exit status by `main`.
+///
+/// | Code | Meaning |
+/// |------|---------|
+/// | 0 | Success |
+/// | 1 | General error (including unclassified kill failures) |
+/// | 2 | Invalid usage (reported by clap, or a missing choice clap can't express) |
+/// | 3 | Nothing matched: no process, port or free port found |
+/// | 4 | Partial failure: some targets succeeded, others failed |
+/// | 5 | Permission denied |
+/// | 6 | Timed out waiting for a process to exit |
+/// | 7 | Every target was protected and skipped |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CliStatus {
+    Success,
+    Failure,
+    InvalidUsage,
+    NotFound,
+    PartialFailure,
+    PermissionDenied,
+    Timeout,
+    Protected,
+}
+
+impl CliStatus {
+    pub fn code(self) -> u8 {
+        match self {
+            CliStatus::Success => 0,
+            CliStatus::Failure => 1,
+            CliStatus::InvalidUsage => 2,
+            CliStatus::NotFound => 3,
+            CliStatus::PartialFailure => 4,
+            CliStatus::PermissionDenied => 5,
+            CliStatus::Timeout => 6,
+            CliStatus::Protected => 7,
         }
+    }
 
-        println!("Port {port} information:");
-        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    /// Summarise per-PID kill outcomes into a single status.
+    pub fn from_outcomes(outcomes: &[KillOutcome]) -> Self {
+        if outcomes.is_empty() {
+            return CliStatus::NotFound;
+        }
 
-        for port_info in ports {
-            println!("Protocol: {:?}", port_info.protocol);
-            println!("State: {:?}", port_info.state);
-            println!("Local Address: {}", port_info.local_address);
+        let failures: Vec<KillFailure> = outcomes.iter().filter_map(|o| o.failure).collect();
+        if failures.is_empty() {
+            return CliStatus::Success;
+        }
+        if failures.len() < outcomes.len() {
+            return CliStatus::PartialFailure;
+        }
 
-            if let Some(remote) = &port_info.remote_address {
-                println!("Remote Address: {remote}");
-            }
+        // Everything failed: report the cause if it was the same for all
+        let first = failures[0];
+        if failures.iter().any(|&f| f != first) {
+            return CliStatus::Failure;
+        }
+        match first {
+            KillFailure::NotFound => CliStatus::NotFound,
+            KillFailure::PermissionDenied => CliStatus::PermissionDenied,
+            KillFailure::Timeout => CliStatus::Timeout,
+            KillFailure::Protected => CliStatus::Protected,
+            KillFailure::Failed => CliStatus::Failure,
+        }
+    }
+}
 
-            if let Some(pid) = port_info.pid {
-                println!("PID: {pid}");
-            }
+/// Record emitted by `find-port` when a free port is found.
+#[derive(Debug, Clone, Serialize)]
+pub struct AvailablePort {
+    pub port: u16,
+    pub service: Option<String>,
+}
+
+impl Record for AvailablePort {
+    fn columns() -> &'static [&'static str] {
+        &["port", "service"]
+    }
 
-            if let Some(ref process_name) = port_info.process_name {
-                println!("Process: {process_name}");
+    fn values(&self) -> Vec<String> {
+        vec![self.port.to_string(), optional(self.service.as_ref())]
+    }
+}
+
+/// Options for `bossy-rust cleanup`.
+#[derive(Debug, Clone, Default)]
+pub struct CleanupOptions {
+    /// Preset groups to clean up, by name
+    pub groups: Vec<String>,
+    pub all_groups: bool,
+    /// List what would be terminated without signalling anything
+    pub dry_run: bool,
+    /// Skip the per-group confirmation prompt
+    pub yes: bool,
+}
+
+/// What happened to one process targeted by `cleanup` or `action`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetStatus {
+    Terminated,
+    Failed,
+    /// Would be terminated (dry run)
+    Planned,
+    /// The group was not confirmed
+    Declined,
+    /// Skipped by the protected-process list
+    Protected,
+}
+
+impl TargetStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TargetStatus::Terminated => "terminated",
+            TargetStatus::Failed => "failed",
+            TargetStatus::Planned => "planned",
+            TargetStatus::Declined => "declined",
+            TargetStatus::Protected => "protected",
+        }
+    }
+
+    fn from_outcome(outcome: &KillOutcome) -> Self {
+        match outcome.failure {
+            None => TargetStatus::Terminated,
+            Some(KillFailure::Protected) => TargetStatus::Protected,
+            Some(_) => TargetStatus::Failed,
+        }
+    }
+}
+
+/// One process handled by `cleanup`, tagged with its preset group.
+#[derive(Debug, Clone, Serialize)]
+pub struct CleanupRecord {
+    pub group: String,
+    pub pid: u32,
+    pub name: Option<String>,
+    pub status: TargetStatus,
+    pub error: Option<String>,
+}
+
+impl CleanupRecord {
+    fn new(group: &str, pid: u32, name: Option<String>, status: TargetStatus) -> Self {
+        Self {
+            group: group.to_string(),
+            pid,
+            name,
+            status,
+            error: None,
+        }
+    }
+
+    fn from_outcome(group: &str, outcome: &KillOutcome) -> Self {
+        Self {
+            error: outcome.error.clone(),
+            ..Self::new(
+                group,
+                outcome.pid,
+                outcome.name.clone(),
+                TargetStatus::from_outcome(outcome),
+            )
+        }
+    }
+}
+
+impl Record for CleanupRecord {
+    fn columns() -> &'static [&'static str] {
+        &["group", "pid", "name", "status", "error"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.group.clone(),
+            self.pid.to_string(),
+            optional(self.name.as_ref()),
+            self.status.as_str().to_string(),
+            optional(self.error.as_ref()),
+        ]
+    }
+}
+
+/// Options for `bossy-rust action`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionOptions {
+    /// Quick action to run; lists the configured actions when absent
+    pub name: Option<String>,
+    /// Show the affected processes and ports without signalling anything
+    pub dry_run: bool,
+    /// Don't ask for confirmation, even if the action requires it
+    pub yes: bool,
+}
+
+/// A configured quick action, as listed by `action` without a name.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionListing {
+    pub action: String,
+    pub name: String,
+    pub description: String,
+    pub processes: Vec<String>,
+    pub ports: Vec<u16>,
+    pub confirmation_required: bool,
+}
+
+impl Record for ActionListing {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "action",
+            "name",
+            "description",
+            "processes",
+            "ports",
+            "confirmation_required",
+        ]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.action.clone(),
+            self.name.clone(),
+            self.description.clone(),
+            self.processes.join(" "),
+            join_ports(&self.ports),
+            self.confirmation_required.to_string(),
+        ]
+    }
+}
+
+/// One process handled by `action`, with the action ports it was holding.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionRecord {
+    pub action: String,
+    pub pid: u32,
+    pub name: Option<String>,
+    pub ports: Vec<u16>,
+    pub status: TargetStatus,
+    pub error: Option<String>,
+}
+
+impl ActionRecord {
+    fn planned(plan: &ActionPlan, status: TargetStatus) -> Vec<Self> {
+        plan.targets
+            .iter()
+            .map(|target| Self {
+                action: plan.key.clone(),
+                pid: target.pid,
+                name: target.name.clone(),
+                ports: target.ports.clone(),
+                status,
+                error: None,
+            })
+            .collect()
+    }
+
+    /// One record per target, paired with the outcome for its PID.
+    fn from_outcomes(plan: &ActionPlan, outcomes: &[KillOutcome]) -> Vec<Self> {
+        plan.targets
+            .iter()
+            .map(|target| {
+                let outcome = outcomes.iter().find(|o| o.pid == target.pid);
+                Self {
+                    action: plan.key.clone(),
+                    pid: target.pid,
+                    name: target
+                        .name
+                        .clone()
+                        .or_else(|| outcome.and_then(|o| o.name.clone())),
+                    ports: target.ports.clone(),
+                    status: outcome.map_or(TargetStatus::Failed, TargetStatus::from_outcome),
+                    error: match outcome {
+                        Some(outcome) => outcome.error.clone(),
+                        None => Some("No result for this process".to_string()),
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+impl Record for ActionRecord {
+    fn columns() -> &'static [&'static str] {
+        &["action", "pid", "name", "ports", "status", "error"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.action.clone(),
+            self.pid.to_string(),
+            optional(self.name.as_ref()),
+            join_ports(&self.ports),
+            self.status.as_str().to_string(),
+            optional(self.error.as_ref()),
+        ]
+    }
+}
+
+/// Options for `bossy-rust stop` and `bossy-rust free`.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectOptions {
+    /// Show the affected processes and ports without signalling anything
+    pub dry_run: bool,
+    /// Don't ask for confirmation
+    pub yes: bool,
+}
+
+/// One service from `.bossy.toml`, as reported by `status`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceRecord {
+    pub service: String,
+    pub state: ServiceState,
+    pub port: Option<u16>,
+    /// The project's processes for the service
+    pub pids: Vec<u32>,
+    pub processes: Vec<String>,
+    /// Foreign process holding the service's port
+    pub holder_pid: Option<u32>,
+    pub holder_name: Option<String>,
+}
+
+impl From<&ServiceStatus> for ServiceRecord {
+    fn from(status: &ServiceStatus) -> Self {
+        Self {
+            service: status.service.clone(),
+            state: status.state,
+            port: status.port,
+            pids: status.processes.iter().map(|p| p.pid).collect(),
+            processes: status
+                .processes
+                .iter()
+                .filter_map(|p| p.name.clone())
+                .collect(),
+            holder_pid: status.holder.as_ref().map(|h| h.pid),
+            holder_name: status.holder.as_ref().and_then(|h| h.name.clone()),
+        }
+    }
+}
+
+impl Record for ServiceRecord {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "service",
+            "state",
+            "port",
+            "pids",
+            "processes",
+            "holder_pid",
+            "holder_name",
+        ]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.service.clone(),
+            self.state.as_str().to_string(),
+            optional(self.port),
+            join_pids(&self.pids),
+            self.processes.join(" "),
+            optional(self.holder_pid),
+            optional(self.holder_name.as_ref()),
+        ]
+    }
+}
+
+fn join_pids(pids: &[u32]) -> String {
+    pids.iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn join_ports(ports: &[u16]) -> String {
+    ports
+        .iter()
+        .map(u16::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+struct GroupSummary<'a> {
+    group: &'a str,
+    matched: usize,
+    terminated: usize,
+    failed: usize,
+    note: &'static str,
+}
+
+impl<'a> GroupSummary<'a> {
+    fn new(group: &'a str, matched: usize) -> Self {
+        Self {
+            group,
+            matched,
+            terminated: 0,
+            failed: 0,
+            note: "",
+        }
+    }
+}
+
+impl CliHandler {
+    pub async fn show_port_info(port: u16, output: &OutputOptions) -> Result<()> {
+        let presets = load_presets()?;
+        let mut ports = PortManager::get_port_by_number(port)?;
+        for port_info in &mut ports {
+            if port_info.service_name.is_none() {
+                port_info.service_name = port_info.service_label(&presets);
             }
+        }
 
-            if let Some(service) = port_info.get_service_suggestion() {
-                println!("Service: {service}");
+        output.emit(&ports, |ports| {
+            if ports.is_empty() {
+                println!("No processes found using port {port}");
+                return;
             }
 
+            println!("Port {port} information:");
             println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        }
 
-        Ok(())
+            for port_info in ports {
+                println!("Protocol: {:?}", port_info.protocol);
+                println!("State: {:?}", port_info.state);
+                println!("Local Address: {}", port_info.local_address);
+
+                if let Some(remote) = &port_info.remote_address {
+                    println!("Remote Address: {remote}");
+                }
+
+                if let Some(pid) = port_info.pid {
+                    println!("PID: {pid}");
+                }
+
+                if let Some(ref process_name) = port_info.process_name {
+                    println!("Process: {process_name}");
+                }
+
+                if let Some(ref service) = port_info.service_name {
+                    println!("Service: {service}");
+                }
+
+                println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+            }
+        })
     }
 
-    pub async fn kill_port(port: u16) -> Result<()> {
-        println!("Killing process using port {port}...");
+    pub async fn kill_port(port: u16, output: &OutputOptions) -> Result<CliStatus> {
+        if output.is_table() {
+            println!("Killing process using port {port}...");
+        }
 
-        match ProcessKiller::kill_process_by_port(port).await {
-            Ok(pid) => {
-                println!("✅ Successfully killed process {pid} using port {port}");
+        let pid = match ProcessKiller::find_pid_by_port(port) {
+            Ok(pid) => pid,
+            Err(e) if KillError::kind_of(&e) == KillFailure::NotFound => {
+                output.emit::<KillOutcome>(&[], |_| {
+                    println!("No process found using port {port}");
+                })?;
+                return Ok(CliStatus::NotFound);
             }
             Err(e) => {
-                eprintln!("❌ Failed to kill process on port {port}: {e}");
-                std::process::exit(1);
+                eprintln!("❌ Failed to look up the process using port {port}: {e}");
+                output.emit::<KillOutcome>(&[], |_| {})?;
+                return Ok(CliStatus::Failure);
             }
-        }
+        };
+
+        let outcomes = ProcessKiller::kill_pids(&[pid], false).await;
+        output.emit(&outcomes, |outcomes| {
+            for outcome in outcomes {
+                match &outcome.error {
+                    None => println!(
+                        "✅ Successfully killed process {} using port {port}",
+                        outcome.pid
+                    ),
+                    Some(e) => eprintln!("❌ Failed to kill process on port {port}: {e}"),
+                }
+            }
+        })?;
 
-        Ok(())
+        Ok(CliStatus::from_outcomes(&outcomes))
     }
 
-    pub async fn show_ports(common: bool, listening: bool) -> Result<()> {
-        let ports = if listening {
-            PortManager::get_listening_ports()?
-        } else if common {
-            PortManager::get_development_ports()?
-        } else {
-            PortManager::get_all_ports()?
-        };
+    pub async fn show_ports(common: bool, listening: bool, output: &OutputOptions) -> Result<()> {
+        let presets = load_presets()?;
+        let ports = collect_ports(common, listening, &presets)?;
 
-        if ports.is_empty() {
-            println!("No ports found");
-            return Ok(());
-        }
+        output.emit(&ports, |ports| {
+            if ports.is_empty() {
+                println!("No ports found");
+                return;
+            }
 
-        println!("Ports ({}):", ports.len());
-        println!("┌─────────┬─────────┬────────────┬─────────┬─────────────────────┬──────────────────────┐");
-        println!("│  Port   │ Proto   │   State    │   PID   │       Process       │       Service        │");
-        println!("├─────────┼─────────┼────────────┼─────────┼─────────────────────┼──────────────────────┤");
+            println!("Ports ({}):", ports.len());
+            print!("{}", format_ports_table(ports));
+        })
+    }
 
-        for port in ports {
-            let protocol = format!("{:?}", port.protocol);
-            let state = format!("{:?}", port.state);
-            let pid = port.pid.map_or("-".to_string(), |p| p.to_string());
-            let process = port.process_name.as_deref().unwrap_or("-");
-            let service = port.get_service_suggestion().unwrap_or("-".to_string());
+    /// Redraw the process table every `interval` until Ctrl-C. The monitor
+    /// refreshes on the same cadence, so CPU figures cover one interval.
+    pub async fn watch_processes(
+        options: &PsOptions,
+        interval: Duration,
+        highlight: bool,
+    ) -> Result<()> {
+        let mut monitor = ProcessMonitor::with_update_interval(interval);
+        let users = ProcessManager::get_user_names();
+        let columns = options.columns();
+        let mut tracker = ChangeTracker::new(
+            |p: &ProcessInfo| (p.pid, p.start_time),
+            |before, after| after.cpu_usage - before.cpu_usage >= CPU_JUMP_THRESHOLD,
+        );
 
-            println!(
-                "│ {:>7} │ {:>7} │ {:>10} │ {:>7} │ {:>19} │ {:>20} │",
-                port.port,
-                &protocol[..std::cmp::min(protocol.len(), 7)],
-                &state[..std::cmp::min(state.len(), 10)],
-                &pid[..std::cmp::min(pid.len(), 7)],
-                &process[..std::cmp::min(process.len(), 19)],
-                &service[..std::cmp::min(service.len(), 20)]
+        // CPU usage needs two samples; take the second before the first frame
+        tokio::time::sleep(MINIMUM_CPU_UPDATE_INTERVAL).await;
+        monitor.refresh();
+
+        watch::run(interval, || {
+            let all = monitor.get_processes();
+            let shown = select_processes(all.clone(), options, &users);
+            let (mut changes, exited) = tracker.update(&all, &shown);
+
+            let title = format!(
+                "{} · every {:.1}s · Ctrl-C to exit",
+                options.title(shown.len()),
+                interval.as_secs_f64()
             );
-        }
+            let mut rows = shown;
+            changes.extend(std::iter::repeat_n(RowChange::Exited, exited.len()));
+            rows.extend(exited);
 
-        println!("└─────────┴─────────┴────────────┴─────────┴─────────────────────┴──────────────────────┘");
+            Ok(render_frame(
+                &title,
+                &format_process_table(&rows, &columns, &users),
+                &changes,
+                highlight,
+            ))
+        })
+        .await
+    }
+
+    /// Redraw the port table every `interval` until Ctrl-C.
+    pub async fn watch_ports(
+        common: bool,
+        listening: bool,
+        interval: Duration,
+        highlight: bool,
+    ) -> Result<()> {
+        let mut tracker = ChangeTracker::new(
+            |p: &PortInfo| {
+                (
+                    p.port,
+                    p.protocol.as_str(),
+                    p.pid,
+                    p.local_address,
+                    p.remote_address,
+                )
+            },
+            |before, after| before.state != after.state,
+        );
+
+        let presets = load_presets()?;
+        watch::run(interval, || {
+            let ports = collect_ports(common, listening, &presets)?;
+            let (mut changes, exited) = tracker.update(&ports, &ports);
 
-        Ok(())
+            let title = format!(
+                "Ports ({}) · every {:.1}s · Ctrl-C to exit",
+                ports.len(),
+                interval.as_secs_f64()
+            );
+            let mut rows = ports;
+            changes.extend(std::iter::repeat_n(RowChange::Exited, exited.len()));
+            rows.extend(exited);
+
+            Ok(render_frame(
+                &title,
+                &format_ports_table(&rows),
+                &changes,
+                highlight,
+            ))
+        })
+        .await
     }
 
-    pub async fn kill_process(name: &str, force: bool) -> Result<()> {
-        println!("Killing processes matching '{name}'...");
+    pub async fn kill_process(
+        options: &KillProcessOptions,
+        output: &OutputOptions,
+    ) -> Result<CliStatus> {
+        let pattern = &options.pattern;
+        let matcher = ProcessMatcher::new(pattern, options.mode)?.all_users(options.all_users);
+        let matched = ProcessKiller::find_matching_processes(&matcher);
+        let users = ProcessManager::get_user_names();
 
-        match ProcessKiller::kill_processes_by_name(name, force).await {
-            Ok(pids) => {
-                if pids.is_empty() {
-                    println!("No processes found matching '{name}'");
-                } else {
-                    println!(
-                        "✅ Successfully killed {} process(es): {pids:?}",
-                        pids.len()
-                    );
+        if options.dry_run {
+            output.emit(&matched, |matched| {
+                if matched.is_empty() {
+                    println!("No processes found matching '{pattern}'");
+                    return;
                 }
+
+                print!(
+                    "{}",
+                    format_process_table(matched, &PREVIEW_COLUMNS, &users)
+                );
+                println!(
+                    "Dry run: {} process(es) would be sent {}",
+                    matched.len(),
+                    if options.force { "SIGKILL" } else { "SIGTERM" }
+                );
+            })?;
+            return Ok(if matched.is_empty() {
+                CliStatus::NotFound
+            } else {
+                CliStatus::Success
+            });
+        }
+
+        if options.interactive && !matched.is_empty() {
+            eprint!(
+                "{}",
+                format_process_table(&matched, &PREVIEW_COLUMNS, &users)
+            );
+            if !confirm(&format!("Kill {} process(es)?", matched.len()))? {
+                eprintln!("Aborted, no processes were signalled");
+                return Ok(CliStatus::Success);
             }
-            Err(e) => {
-                eprintln!("❌ Failed to kill processes: {e}");
-                std::process::exit(1);
+        }
+
+        if output.is_table() && !matched.is_empty() {
+            println!("Killing processes matching '{pattern}'...");
+        }
+
+        let pids: Vec<u32> = matched.iter().map(|p| p.pid).collect();
+        let outcomes = ProcessKiller::kill_pids(&pids, options.force).await;
+        output.emit(&outcomes, |outcomes| {
+            if outcomes.is_empty() {
+                println!("No processes found matching '{pattern}'");
+                return;
+            }
+
+            print_kill_outcomes(outcomes);
+        })?;
+
+        Ok(CliStatus::from_outcomes(&outcomes))
+    }
+
+    pub async fn send_signal(options: &SignalOptions, output: &OutputOptions) -> Result<CliStatus> {
+        let target = &options.target;
+        let pids: Vec<u32> = match target.parse::<u32>() {
+            Ok(pid) => vec![pid],
+            Err(_) => {
+                let matcher =
+                    ProcessMatcher::new(target, options.mode)?.all_users(options.all_users);
+                ProcessKiller::find_matching_processes(&matcher)
+                    .iter()
+                    .map(|p| p.pid)
+                    .collect()
             }
+        };
+
+        let outcomes = ProcessKiller::signal_pids(&pids, options.signal).await;
+        output.emit(&outcomes, |outcomes| {
+            if outcomes.is_empty() {
+                println!("No processes found matching '{target}'");
+                return;
+            }
+
+            print_signal_outcomes(outcomes, options.signal);
+        })?;
+
+        Ok(CliStatus::from_outcomes(&outcomes))
+    }
+
+    /// Set the nice value of one process, from -20 (highest priority) to 19.
+    pub async fn renice(pid: u32, nice: i32, output: &OutputOptions) -> Result<CliStatus> {
+        let previous = ProcessKiller::nice_of(pid);
+        let outcomes = ProcessKiller::renice_pids(&[pid], nice).await;
+        output.emit(&outcomes, |outcomes| {
+            for outcome in outcomes {
+                let name = outcome.name.as_deref().unwrap_or("unknown");
+                match (&outcome.error, outcome.failure, previous) {
+                    (None, _, Some(previous)) => {
+                        println!("✅ {name} ({pid}): nice {previous} → {nice}")
+                    }
+                    (None, _, None) => println!("✅ {name} ({pid}): nice {nice}"),
+                    (Some(e), Some(KillFailure::Protected), _) => println!("⛔ {e}"),
+                    (Some(e), _, _) => println!("❌ {}", e.trim_end()),
+                }
+            }
+        })?;
+
+        Ok(CliStatus::from_outcomes(&outcomes))
+    }
+
+    /// Print the last `limit` audit log entries (all with 0), oldest first.
+    pub fn show_history(
+        limit: usize,
+        search: Option<&str>,
+        output: &OutputOptions,
+    ) -> Result<CliStatus> {
+        let log = AuditLog::default_path()
+            .map(AuditLog::new)
+            .ok_or_else(|| anyhow!("Could not find the data directory for the audit log"))?;
+        let mut entries = log.read()?;
+        if let Some(search) = search {
+            entries.retain(|entry| entry.matches(search));
+        }
+        if limit > 0 && entries.len() > limit {
+            entries.drain(..entries.len() - limit);
         }
 
-        Ok(())
+        output.emit(&entries, |entries| {
+            if entries.is_empty() {
+                println!("No recorded actions in {}", log.path().display());
+                return;
+            }
+            print!("{}", format_history_table(entries));
+        })?;
+        Ok(CliStatus::Success)
     }
 
-    pub async fn show_processes(top_cpu: bool, top_memory: bool, limit: usize) -> Result<()> {
+    pub async fn show_processes(options: &PsOptions, output: &OutputOptions) -> Result<()> {
         let mut monitor = ProcessMonitor::new();
+        let users = ProcessManager::get_user_names();
+        let processes = select_processes(monitor.get_processes(), options, &users);
+        let columns = options.columns();
 
-        let processes = if top_cpu {
-            monitor.get_top_cpu_processes(limit)
-        } else if top_memory {
-            monitor.get_top_memory_processes(limit)
-        } else {
-            let mut procs = monitor.get_processes();
-            procs.truncate(limit);
-            procs
-        };
+        // Without --columns, JSON keeps the full process record
+        if output.format == OutputFormat::Json && options.columns.is_empty() {
+            return output.emit(&processes, |_| {});
+        }
+
+        let keys: Vec<&str> = columns.iter().map(|c| c.key()).collect();
+        let rows = process_rows(&processes, &columns, &users);
+        output.emit_dynamic(&keys, &rows, || {
+            if processes.is_empty() {
+                println!("No processes found");
+                return;
+            }
+
+            println!("{}:", options.title(processes.len()));
+            print!("{}", format_process_table(&processes, &columns, &users));
+        })
+    }
 
-        if processes.is_empty() {
-            println!("No processes found");
-            return Ok(());
+    pub async fn cleanup_processes(
+        options: &CleanupOptions,
+        output: &OutputOptions,
+    ) -> Result<CliStatus> {
+        let presets = load_presets()?;
+        let groups = resolve_groups(&presets, options)?;
+        if groups.is_empty() {
+            // Usage goes to stderr so it never mixes with JSON/CSV output
+            eprintln!(
+                "Please specify --dev (all groups), --all-groups or --group <names> to cleanup development processes"
+            );
+            eprintln!("Available groups: {}", presets.group_names().join(", "));
+            return Ok(CliStatus::InvalidUsage);
         }
 
-        let title = if top_cpu {
-            format!("Top {limit} CPU Consumers")
-        } else if top_memory {
-            format!("Top {limit} Memory Consumers")
-        } else {
-            format!("Processes (showing {limit})")
-        };
+        let users = ProcessManager::get_user_names();
+        let mut records = Vec::new();
+        let mut summaries = Vec::new();
+        let mut outcomes = Vec::new();
+        let mut handled = HashSet::new();
 
-        println!("{title}:");
-        println!("┌──────────┬─────────────────────┬─────────┬─────────────┬──────────────┐");
-        println!("│   PID    │       Process       │  CPU %  │   Memory    │    Status    │");
-        println!("├──────────┼─────────────────────┼─────────┼─────────────┼──────────────┤");
+        for (group, names) in groups {
+            // A process listed in several groups is only handled once
+            let matched: Vec<ProcessInfo> = ProcessKiller::find_processes_by_names(names)
+                .into_iter()
+                .filter(|p| handled.insert(p.pid))
+                .collect();
+            let mut summary = GroupSummary::new(group, matched.len());
 
-        for process in processes {
-            let name = if process.name.chars().count() > 19 {
-                format!("{}…", process.name.chars().take(18).collect::<String>())
+            if matched.is_empty() {
+                summary.note = "nothing running";
+            } else if options.dry_run {
+                if output.is_table() {
+                    println!("[{group}] would terminate:");
+                    print!(
+                        "{}",
+                        format_process_table(&matched, &PREVIEW_COLUMNS, &users)
+                    );
+                }
+                records.extend(matched.iter().map(|p| {
+                    CleanupRecord::new(group, p.pid, Some(p.name.clone()), TargetStatus::Planned)
+                }));
+                summary.note = "dry run";
+            } else if !(options.yes || confirm_group(group, &matched, &users)?) {
+                records.extend(matched.iter().map(|p| {
+                    CleanupRecord::new(group, p.pid, Some(p.name.clone()), TargetStatus::Declined)
+                }));
+                summary.note = "skipped";
             } else {
-                process.name.clone()
-            };
+                let pids: Vec<u32> = matched.iter().map(|p| p.pid).collect();
+                let group_outcomes =
+                    run_as_rule(group, ProcessKiller::kill_pids(&pids, false)).await;
+                summary.terminated = group_outcomes.iter().filter(|o| o.success).count();
+                summary.failed = group_outcomes.len() - summary.terminated;
+                records.extend(
+                    group_outcomes
+                        .iter()
+                        .map(|o| CleanupRecord::from_outcome(group, o)),
+                );
+                outcomes.extend(group_outcomes);
+            }
 
-            let status = if process.status.chars().count() > 12 {
-                format!("{}…", process.status.chars().take(11).collect::<String>())
-            } else {
-                process.status.clone()
-            };
+            summaries.push(summary);
+        }
 
-            println!(
-                "│ {:>8} │ {:>19} │ {:>7.1} │ {:>11} │ {:>12} │",
-                process.pid,
-                name,
-                process.cpu_usage,
-                process.format_memory(),
-                status
-            );
+        output.emit(&records, |_| print_group_summaries(&summaries))?;
+
+        // Finding nothing to clean up is not an error for cleanup
+        if outcomes.is_empty() {
+            return Ok(CliStatus::Success);
         }
+        Ok(CliStatus::from_outcomes(&outcomes))
+    }
 
-        println!("└──────────┴─────────────────────┴─────────┴─────────────┴──────────────┘");
+    /// Run a quick action from the presets, or list them when no name is
+    /// given.
+    pub async fn run_action(options: &ActionOptions, output: &OutputOptions) -> Result<CliStatus> {
+        let presets = load_presets()?;
+        let Some(key) = options.name.as_deref() else {
+            let listings: Vec<ActionListing> = presets
+                .quick_actions
+                .iter()
+                .map(|(key, action)| ActionListing {
+                    action: key.clone(),
+                    name: action.name.clone(),
+                    description: action.description.clone(),
+                    processes: action.processes.clone(),
+                    ports: action.ports.clone(),
+                    confirmation_required: action.confirmation_required,
+                })
+                .collect();
+            output.emit(&listings, print_action_listings)?;
+            return Ok(CliStatus::Success);
+        };
+
+        let action = find_action(&presets, key)?;
+        let plan = plan_action(key, action);
+        let needs_confirmation =
+            action.confirmation_required || presets.ui_settings.confirm_destructive_actions;
 
-        Ok(())
+        execute_plan(
+            &plan,
+            needs_confirmation,
+            options.dry_run,
+            options.yes,
+            output,
+        )
+        .await
     }
 
-    pub async fn cleanup_processes(dev: bool) -> Result<()> {
-        if dev {
-            println!("Cleaning up development processes...");
+    /// Report which services from the project's `.bossy.toml` are running.
+    pub fn project_status(output: &OutputOptions) -> Result<CliStatus> {
+        let project = current_project()?;
+        let statuses = project_status(&project);
+        let records: Vec<ServiceRecord> = statuses.iter().map(ServiceRecord::from).collect();
 
-            match ProcessKiller::cleanup_dev_processes().await {
-                Ok(pids) => {
-                    if pids.is_empty() {
-                        println!("No development processes found to cleanup");
-                    } else {
-                        println!(
-                            "✅ Cleaned up {} development processes: {pids:?}",
-                            pids.len()
-                        );
-                    }
-                }
-                Err(e) => {
-                    eprintln!("❌ Failed to cleanup processes: {e}");
-                    std::process::exit(1);
-                }
+        output.emit(&records, |records| {
+            println!("Project {} ({}):", project.name, project.root.display());
+            print_service_records(records);
+        })?;
+
+        let running = statuses
+            .iter()
+            .filter(|s| s.state == ServiceState::Running)
+            .count();
+        Ok(if running == statuses.len() {
+            CliStatus::Success
+        } else if running == 0 {
+            CliStatus::NotFound
+        } else {
+            CliStatus::PartialFailure
+        })
+    }
+
+    /// Terminate the project's own processes, leaving anything else on its
+    /// ports alone.
+    pub async fn project_stop(
+        options: &ProjectOptions,
+        output: &OutputOptions,
+    ) -> Result<CliStatus> {
+        let project = current_project()?;
+        let plan = plan_project_stop(&project);
+        execute_plan(&plan, true, options.dry_run, options.yes, output).await
+    }
+
+    /// Terminate whatever listens on the project's ports.
+    pub async fn project_free(
+        options: &ProjectOptions,
+        output: &OutputOptions,
+    ) -> Result<CliStatus> {
+        let project = current_project()?;
+        let plan = plan_project_free(&project);
+        execute_plan(&plan, true, options.dry_run, options.yes, output).await
+    }
+
+    /// Validate every config file, reporting problems with file and line.
+    pub fn config_check(output: &OutputOptions) -> Result<CliStatus> {
+        let mut records = Vec::new();
+
+        let layers = [
+            ("user presets", user_presets_path()),
+            ("project presets", project_presets_path()),
+        ];
+        for (file, path) in layers {
+            records.push(check_file(file, path.as_deref(), |source| {
+                PresetLayer::parse(source).map(drop)
+            }));
+        }
+        // Bindings can only clash once the layers are merged
+        if records.iter().all(|r| r.status != CheckStatus::Error) {
+            if let Err(e) = load_presets() {
+                records.push(CheckRecord::problem(
+                    "key bindings",
+                    "merged presets",
+                    CheckStatus::Error,
+                    None,
+                    format!("{e:#}"),
+                ));
             }
+        }
+
+        let path = settings_path();
+        let record = check_file("settings", path.as_deref(), |source| {
+            UserSettings::parse(source).map(drop)
+        });
+        let source = match (&path, record.status) {
+            (Some(path), CheckStatus::Ok) => fs::read_to_string(path).unwrap_or_default(),
+            _ => String::new(),
+        };
+        let unknown = unknown_keys(&source);
+        let settings_file = record.path.clone();
+        if unknown.is_empty() {
+            records.push(record);
+        }
+        for key in unknown {
+            records.push(CheckRecord::problem(
+                "settings",
+                &settings_file,
+                CheckStatus::Warning,
+                key_line(&source, &key),
+                format!("unknown setting `{key}` is ignored"),
+            ));
+        }
+
+        match apply_env_overrides(&mut UserSettings::default()) {
+            Ok(overrides) if !overrides.is_empty() => {
+                let vars: Vec<&str> = overrides.iter().map(|o| o.var.as_str()).collect();
+                records.push(CheckRecord::new(
+                    "environment",
+                    &vars.join(" "),
+                    CheckStatus::Ok,
+                ));
+            }
+            Ok(_) => {}
+            Err(e) => records.push(CheckRecord::problem(
+                "environment",
+                "BOSSY_*",
+                CheckStatus::Error,
+                None,
+                format!("{e:#}"),
+            )),
+        }
+
+        if let Some(dir) = ThemeManager::themes_dir().filter(|dir| dir.is_dir()) {
+            let mut paths: Vec<_> = fs::read_dir(&dir)?
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+                .collect();
+            paths.sort();
+            let builtins = ThemeManager::get_themes();
+            for path in paths {
+                records.push(check_file("theme", Some(&path), |source| {
+                    ThemeManager::parse_theme(source, &builtins).map(drop)
+                }));
+            }
+        }
+
+        let cwd = std::env::current_dir()?;
+        match find_project_file(&cwd) {
+            Some(path) => {
+                let root = path.parent().unwrap_or(&cwd).to_path_buf();
+                records.push(check_file("project profile", Some(&path), |source| {
+                    Project::parse(source, &root).map(drop)
+                }));
+            }
+            None => records.push(CheckRecord::new(
+                "project profile",
+                &cwd.join(PROJECT_FILE).display().to_string(),
+                CheckStatus::Missing,
+            )),
+        }
+
+        output.emit(&records, print_check_records)?;
+
+        let failed = records.iter().any(|r| r.status == CheckStatus::Error);
+        Ok(if failed {
+            CliStatus::Failure
         } else {
-            println!("Please specify --dev to cleanup development processes");
+            CliStatus::Success
+        })
+    }
+
+    /// Print the effective configuration and where each value comes from.
+    pub fn config_show(output: &OutputOptions) -> Result<CliStatus> {
+        let mut layers = vec![(
+            "bundled".to_string(),
+            BUNDLED_PRESETS.parse::<toml::Table>()?,
+        )];
+        for path in preset_paths().iter().filter(|path| path.exists()) {
+            let source = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            PresetLayer::parse(&source).with_context(|| {
+                format!(
+                    "Invalid presets in {} (run `bossy-rust config check`)",
+                    path.display()
+                )
+            })?;
+            layers.push((path.display().to_string(), source.parse()?));
         }
 
-        Ok(())
+        let mut entries: Vec<ConfigEntry> = merge_layers(&layers)
+            .into_iter()
+            .map(|(key, (value, source))| ConfigEntry {
+                key,
+                value: value.to_string(),
+                source,
+            })
+            .collect();
+
+        let mut settings = load_settings().context("Run `bossy-rust config check` for details")?;
+        let overrides = apply_env_overrides(&mut settings)?;
+        let path = settings_path().filter(|path| path.exists());
+        let in_file: toml::Table = match &path {
+            Some(path) => fs::read_to_string(path)?.parse()?,
+            None => toml::Table::new(),
+        };
+
+        let mut values = Vec::new();
+        flatten("settings", &toml::Table::try_from(&settings)?, &mut values);
+        for (key, value) in values {
+            let setting = key.split('.').nth(1).unwrap_or_default();
+            let source = match (
+                overrides.iter().find(|o| o.key == setting),
+                path.as_ref().filter(|_| in_file.contains_key(setting)),
+            ) {
+                (Some(env), _) => format!("env {}", env.var),
+                (None, Some(path)) => path.display().to_string(),
+                (None, None) => "default".to_string(),
+            };
+            entries.push(ConfigEntry {
+                key,
+                value: value.to_string(),
+                source,
+            });
+        }
+
+        output.emit(&entries, |entries| {
+            let width = entries
+                .iter()
+                .map(|e| e.key.len() + e.value.len() + 3)
+                .max()
+                .unwrap_or(0)
+                .min(60);
+            for entry in entries {
+                let line = format!("{} = {}", entry.key, entry.value);
+                println!("{line:<width$}  # {}", entry.source);
+            }
+        })?;
+        Ok(CliStatus::Success)
     }
 
-    pub async fn find_available_port(start: u16, end: u16) -> Result<()> {
-        println!("Searching for available ports in range {start}-{end}...");
+    /// Print where each config file is looked up.
+    pub fn config_path(output: &OutputOptions) -> Result<CliStatus> {
+        let (dir, source) =
+            config_dir_with_source().ok_or_else(|| anyhow!("Could not find config directory"))?;
+        let cwd = std::env::current_dir()?;
+        let project = find_project_file(&cwd).unwrap_or_else(|| cwd.join(PROJECT_FILE));
+
+        let files = [
+            ("config directory", Some(dir.clone())),
+            ("user presets", user_presets_path()),
+            ("settings", settings_path()),
+            ("themes", ThemeManager::themes_dir()),
+            ("project presets", project_presets_path()),
+            ("project profile", Some(project)),
+        ];
+        let records: Vec<PathRecord> = files
+            .into_iter()
+            .filter_map(|(file, path)| {
+                path.map(|path| PathRecord {
+                    file: file.to_string(),
+                    exists: path.exists(),
+                    path: path.display().to_string(),
+                })
+            })
+            .collect();
+
+        output.emit(&records, |records| {
+            println!("Config directory from {source}");
+            let rows: Vec<Vec<String>> = records
+                .iter()
+                .map(|r| {
+                    vec![
+                        r.file.clone(),
+                        r.path.clone(),
+                        if r.exists { "found" } else { "missing" }.to_string(),
+                    ]
+                })
+                .collect();
+            print!(
+                "{}",
+                format_box_table(&["File", "Path", "Status"], &rows, &[false, false, false])
+            );
+        })?;
+        Ok(CliStatus::Success)
+    }
+
+    pub async fn find_available_port(
+        start: u16,
+        end: u16,
+        output: &OutputOptions,
+    ) -> Result<CliStatus> {
+        if output.is_table() {
+            println!("Searching for available ports in range {start}-{end}...");
+        }
 
         match ProcessKiller::find_available_port(start, end) {
             Ok(port) => {
-                println!("✅ Available port found: {port}");
+                let service = NetworkUtils::get_well_known_ports()
+                    .get(&port)
+                    .map(|service| service.to_string());
+                let record = AvailablePort { port, service };
+
+                output.emit(&[record], |records| {
+                    for record in records {
+                        println!("✅ Available port found: {}", record.port);
 
-                // Show suggestions for common development ports
-                if NetworkUtils::is_development_port(port) {
-                    if let Some(service) = NetworkUtils::get_well_known_ports().get(&port) {
-                        println!("💡 This port is commonly used for: {service}");
+                        // Show suggestions for common development ports
+                        if NetworkUtils::is_development_port(record.port) {
+                            if let Some(service) = &record.service {
+                                println!("💡 This port is commonly used for: {service}");
+                            }
+                        }
                     }
-                }
+                })?;
+
+                Ok(CliStatus::Success)
             }
             Err(e) => {
-                println!("❌ {e}");
+                eprintln!("❌ {e}");
 
                 // Suggest alternatives
                 let alternatives = NetworkUtils::suggest_alternative_port(start);
                 if !alternatives.is_empty() {
-                    println!("💡 Consider trying these alternative ports: {alternatives:?}");
+                    eprintln!("💡 Consider trying these alternative ports: {alternatives:?}");
                 }
 
-                std::process::exit(1);
+                output.emit::<AvailablePort>(&[], |_| {})?;
+                Ok(CliStatus::NotFound)
             }
         }
+    }
+}
+
+fn collect_ports(common: bool, listening: bool, presets: &Presets) -> Result<Vec<PortInfo>> {
+    let mut ports = if listening {
+        PortManager::get_listening_ports()?
+    } else if common {
+        PortManager::get_common_ports(presets)?
+    } else {
+        PortManager::get_all_ports()?
+    };
+    for port in &mut ports {
+        if port.service_name.is_none() {
+            port.service_name = port.service_label(presets);
+        }
+    }
+    Ok(ports)
+}
+
+fn format_ports_table(ports: &[PortInfo]) -> String {
+    let mut table = String::new();
+    table.push_str("┌─────────┬─────────┬────────────┬─────────┬─────────────────────┬──────────────────────┐\n");
+    table.push_str("│  Port   │ Proto   │   State    │   PID   │       Process       │       Service        │\n");
+    table.push_str("├─────────┼─────────┼────────────┼─────────┼─────────────────────┼──────────────────────┤\n");
+
+    for port in ports {
+        let protocol = format!("{:?}", port.protocol);
+        let state = format!("{:?}", port.state);
+        let pid = port.pid.map_or("-".to_string(), |p| p.to_string());
+        let process = port.process_name.as_deref().unwrap_or("-");
+        let service = port.service_name.as_deref().unwrap_or("-");
+
+        table.push_str(&format!(
+            "│ {:>7} │ {:>7} │ {:>10} │ {:>7} │ {:>19} │ {:>20} │\n",
+            port.port,
+            truncate(&protocol, 7),
+            truncate(&state, 10),
+            truncate(&pid, 7),
+            truncate(process, 19),
+            truncate(service, 20)
+        ));
+    }
+
+    table.push_str("└─────────┴─────────┴────────────┴─────────┴─────────────────────┴──────────────────────┘\n");
+    table
+}
+
+/// The preset groups selected by `options`, in preset order.
+fn resolve_groups<'a>(
+    presets: &'a Presets,
+    options: &CleanupOptions,
+) -> Result<Vec<(&'a str, &'a [String])>> {
+    if options.all_groups {
+        return Ok(presets
+            .development_processes
+            .iter()
+            .map(|(group, names)| (group.as_str(), names.as_slice()))
+            .collect());
+    }
 
-        Ok(())
+    options
+        .groups
+        .iter()
+        .map(|group| {
+            presets
+                .development_processes
+                .get_key_value(group)
+                .map(|(group, names)| (group.as_str(), names.as_slice()))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Unknown process group '{group}' (available: {})",
+                        presets.group_names().join(", ")
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Preview a group's processes on stderr and ask before terminating them.
+fn confirm_group(
+    group: &str,
+    matched: &[ProcessInfo],
+    users: &HashMap<u32, String>,
+) -> Result<bool> {
+    eprintln!("[{group}]");
+    eprint!("{}", format_process_table(matched, &PREVIEW_COLUMNS, users));
+    confirm(&format!("Terminate {} {group} process(es)?", matched.len()))
+}
+
+fn print_group_summaries(summaries: &[GroupSummary]) {
+    let rows: Vec<Vec<String>> = summaries
+        .iter()
+        .map(|s| {
+            vec![
+                s.group.to_string(),
+                s.matched.to_string(),
+                s.terminated.to_string(),
+                s.failed.to_string(),
+                s.note.to_string(),
+            ]
+        })
+        .collect();
+
+    println!("Cleanup summary:");
+    print!(
+        "{}",
+        format_box_table(
+            &["Group", "Matched", "Terminated", "Failed", "Note"],
+            &rows,
+            &[false, true, true, true, false],
+        )
+    );
+}
+
+fn print_action_listings(listings: &[ActionListing]) {
+    if listings.is_empty() {
+        println!("No quick actions configured");
+        return;
+    }
+
+    let rows: Vec<Vec<String>> = listings
+        .iter()
+        .map(|l| {
+            let targets = if l.ports.is_empty() {
+                l.processes.join(", ")
+            } else {
+                format!("ports {}", join_ports(&l.ports))
+            };
+            vec![l.action.clone(), l.name.clone(), truncate(&targets, 40)]
+        })
+        .collect();
+
+    println!("Quick actions:");
+    print!(
+        "{}",
+        format_box_table(
+            &["Action", "Name", "Targets"],
+            &rows,
+            &[false, false, false]
+        )
+    );
+}
+
+/// Preview `plan`, confirm it if needed and terminate its targets.
+async fn execute_plan(
+    plan: &ActionPlan,
+    needs_confirmation: bool,
+    dry_run: bool,
+    yes: bool,
+    output: &OutputOptions,
+) -> Result<CliStatus> {
+    print_action_plan(plan);
+    if plan.is_empty() {
+        output.emit::<ActionRecord>(&[], |_| {})?;
+        return Ok(CliStatus::Success);
     }
+
+    if dry_run {
+        output.emit(&ActionRecord::planned(plan, TargetStatus::Planned), |_| {
+            println!(
+                "Dry run: {} process(es) would be terminated",
+                plan.targets.len()
+            );
+        })?;
+        return Ok(CliStatus::Success);
+    }
+
+    let prompt = format!(
+        "Run '{}' and terminate {} process(es)?",
+        plan.action.name,
+        plan.targets.len()
+    );
+    if needs_confirmation && !yes && !confirm(&prompt)? {
+        eprintln!("Aborted, no processes were signalled");
+        output.emit(&ActionRecord::planned(plan, TargetStatus::Declined), |_| {})?;
+        return Ok(CliStatus::Success);
+    }
+
+    let outcomes = run_as_rule(&plan.key, ProcessKiller::kill_pids(&plan.pids(), false)).await;
+    output.emit(&ActionRecord::from_outcomes(plan, &outcomes), |_| {
+        print_kill_outcomes(&outcomes);
+    })?;
+
+    Ok(CliStatus::from_outcomes(&outcomes))
+}
+
+fn print_service_records(records: &[ServiceRecord]) {
+    let rows: Vec<Vec<String>> = records
+        .iter()
+        .map(|r| {
+            let note = match (&r.holder_name, r.holder_pid) {
+                (name, Some(pid)) => format!(
+                    "port held by {} ({pid})",
+                    name.as_deref().unwrap_or("unknown")
+                ),
+                _ => r.processes.join(" "),
+            };
+            vec![
+                r.service.clone(),
+                r.state.as_str().to_string(),
+                r.port.map_or_else(|| "-".to_string(), |p| p.to_string()),
+                if r.pids.is_empty() {
+                    "-".to_string()
+                } else {
+                    join_pids(&r.pids)
+                },
+                note,
+            ]
+        })
+        .collect();
+
+    print!(
+        "{}",
+        format_box_table(
+            &["Service", "State", "Port", "PIDs", "Processes"],
+            &rows,
+            &[false, false, true, false, false],
+        )
+    );
+}
+
+/// Validate the file at `path` with `validate`, locating any error in it.
+fn check_file(
+    file: &str,
+    path: Option<&Path>,
+    validate: impl FnOnce(&str) -> Result<()>,
+) -> CheckRecord {
+    let Some(path) = path else {
+        return CheckRecord::problem(
+            file,
+            "-",
+            CheckStatus::Missing,
+            None,
+            "no config directory".to_string(),
+        );
+    };
+    let display = path.display().to_string();
+    if !path.exists() {
+        return CheckRecord::new(file, &display, CheckStatus::Missing);
+    }
+
+    let source = match fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(e) => {
+            return CheckRecord::problem(
+                file,
+                &display,
+                CheckStatus::Error,
+                None,
+                format!("Failed to read: {e}"),
+            )
+        }
+    };
+    match validate(&source) {
+        Ok(()) => CheckRecord::new(file, &display, CheckStatus::Ok),
+        Err(e) => {
+            let (line, message) = error_location(&source, &e);
+            CheckRecord::problem(file, &display, CheckStatus::Error, line, message)
+        }
+    }
+}
+
+fn print_check_records(records: &[CheckRecord]) {
+    for record in records {
+        match (record.status, &record.message) {
+            (CheckStatus::Missing, _) => println!(
+                "·  {}: {} (not found, using defaults)",
+                record.file, record.path
+            ),
+            (CheckStatus::Ok, _) => println!("✅ {}: {}", record.file, record.path),
+            (status, message) => {
+                let icon = if status == CheckStatus::Error {
+                    "❌"
+                } else {
+                    "⚠️ "
+                };
+                println!(
+                    "{icon} {}: {}: {}",
+                    record.file,
+                    record.location(),
+                    message.as_deref().unwrap_or_default()
+                );
+            }
+        }
+    }
+
+    let count = |status| records.iter().filter(|r| r.status == status).count();
+    match (count(CheckStatus::Error), count(CheckStatus::Warning)) {
+        (0, 0) => println!("\nConfiguration is valid"),
+        (errors, warnings) => println!("\n{errors} error(s), {warnings} warning(s)"),
+    }
+}
+
+/// Preview a quick action's targets on stderr, so the preview is shown
+/// before confirming and stays out of machine-readable output.
+fn print_action_plan(plan: &ActionPlan) {
+    eprintln!("{}: {}", plan.action.name, plan.action.description);
+    if plan.is_empty() {
+        eprintln!("Nothing to do: no matching processes are running");
+    } else {
+        let rows: Vec<Vec<String>> = plan
+            .targets
+            .iter()
+            .map(|t| {
+                vec![
+                    t.pid.to_string(),
+                    t.name.clone().unwrap_or_else(|| "-".to_string()),
+                    if t.ports.is_empty() {
+                        "-".to_string()
+                    } else {
+                        join_ports(&t.ports)
+                    },
+                ]
+            })
+            .collect();
+        eprint!(
+            "{}",
+            format_box_table(&["PID", "Process", "Ports"], &rows, &[true, false, false])
+        );
+    }
+    if !plan.free_ports.is_empty() {
+        eprintln!("Already free: {}", join_ports(&plan.free_ports));
+    }
+    if !plan.held_ports.is_empty() {
+        eprintln!(
+            "Skipped, held by bossy-rust or its parent: {}",
+            join_ports(&plan.held_ports)
+        );
+    }
+}
+
+/// Increase in CPU percentage points between ticks that counts as a jump.
+const CPU_JUMP_THRESHOLD: f32 = 20.0;
+
+/// Columns shown when previewing processes before they are signalled.
+const PREVIEW_COLUMNS: [ProcessColumn; 4] = [
+    ProcessColumn::Pid,
+    ProcessColumn::User,
+    ProcessColumn::Name,
+    ProcessColumn::Command,
+];
+
+/// Ask a yes/no question on stderr; anything but "y"/"yes" declines.
+fn confirm(prompt: &str) -> Result<bool> {
+    eprint!("{prompt} [y/N] ");
+    io::stderr().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+fn print_kill_outcomes(outcomes: &[KillOutcome]) {
+    for outcome in outcomes {
+        let name = outcome.name.as_deref().unwrap_or("unknown");
+        match (&outcome.error, outcome.failure) {
+            (None, _) => println!("✅ Killed {name} ({})", outcome.pid),
+            (Some(e), Some(KillFailure::Protected)) => println!("⛔ {e}"),
+            (Some(e), _) => println!("❌ Failed to kill {name} ({}): {e}", outcome.pid),
+        }
+    }
+
+    let killed = outcomes.iter().filter(|outcome| outcome.success).count();
+    println!("Killed {killed} of {} process(es)", outcomes.len());
+
+    let protected = outcomes
+        .iter()
+        .filter(|outcome| outcome.failure == Some(KillFailure::Protected))
+        .count();
+    if protected > 0 {
+        println!("Skipped {protected} protected process(es); pass --allow-protected to kill them");
+    }
+}
+
+fn format_history_table(entries: &[AuditEntry]) -> String {
+    let rows: Vec<Vec<String>> = entries
+        .iter()
+        .map(|entry| {
+            let initiator = match &entry.rule {
+                Some(rule) => format!("rule:{rule}"),
+                None => entry.initiator.as_str().to_string(),
+            };
+            vec![
+                format_timestamp(entry.timestamp),
+                entry.pid.to_string(),
+                truncate(entry.name.as_deref().unwrap_or("-"), 19),
+                entry.action.clone(),
+                truncate(&initiator, 16),
+                entry.outcome().to_string(),
+                truncate(entry.command_line.as_deref().unwrap_or("-"), 40),
+            ]
+        })
+        .collect();
+    format_box_table(
+        &[
+            "Time", "PID", "Process", "Action", "By", "Result", "Command",
+        ],
+        &rows,
+        &[false, true, false, false, false, false, false],
+    )
+}
+
+fn print_signal_outcomes(outcomes: &[KillOutcome], signal: Signal) {
+    for outcome in outcomes {
+        let name = outcome.name.as_deref().unwrap_or("unknown");
+        match (&outcome.error, outcome.failure) {
+            (None, _) => println!("✅ Sent {signal} to {name} ({})", outcome.pid),
+            (Some(e), Some(KillFailure::Protected)) => println!("⛔ {e}"),
+            (Some(e), _) => println!("❌ {}", e.trim_end()),
+        }
+    }
+
+    let sent = outcomes.iter().filter(|outcome| outcome.success).count();
+    println!("Sent {signal} to {sent} of {} process(es)", outcomes.len());
 }
 
 #[cfg(test)]
@@ -254,54 +1656,242 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore] // Ignore this test because kill_port calls std::process::exit(1)
     async fn test_kill_port_invalid() {
         // Test with a port that's very unlikely to be in use
-        // NOTE: This test is ignored because the kill_port function calls std::process::exit(1)
-        // which would terminate the test process. This is a design issue that should be addressed
-        // by refactoring the CLI functions to return errors instead of calling exit.
-        let result = CliHandler::kill_port(65534).await;
-        match result {
-            Ok(_) => assert!(true),
-            Err(_) => assert!(true),
-        }
+        let result = CliHandler::kill_port(65534, &OutputOptions::default()).await;
+        assert_eq!(result.unwrap(), CliStatus::NotFound);
     }
 
     #[tokio::test]
     async fn test_show_all_ports() {
-        let result = CliHandler::show_ports(false, false).await;
+        let result = CliHandler::show_ports(false, false, &OutputOptions::default()).await;
         // Should not panic, may succeed or fail based on system state
         assert!(result.is_ok() || result.is_err());
     }
 
     #[tokio::test]
     async fn test_kill_process_non_existent() {
-        let result = CliHandler::kill_process("non_existent_process_xyz_123", false).await;
-        // Should handle non-existent process gracefully
-        assert!(result.is_ok() || result.is_err());
+        let options = KillProcessOptions {
+            pattern: "non_existent_process_xyz_123".to_string(),
+            ..Default::default()
+        };
+        let result = CliHandler::kill_process(&options, &OutputOptions::default()).await;
+        // Should report that nothing matched instead of exiting
+        assert_eq!(result.unwrap(), CliStatus::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_kill_process_dry_run_signals_nothing() {
+        // A duration no other process is sleeping for, so only the child matches
+        let duration = format!("30.{}", std::process::id());
+        let mut child = std::process::Command::new("sleep")
+            .arg(&duration)
+            .spawn()
+            .unwrap();
+        let options = KillProcessOptions {
+            pattern: format!("^sleep {}$", regex::escape(&duration)),
+            mode: MatchMode::Cmdline,
+            dry_run: true,
+            ..Default::default()
+        };
+        let result = CliHandler::kill_process(&options, &OutputOptions::default()).await;
+        let alive = child.try_wait().unwrap().is_none();
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert_eq!(result.unwrap(), CliStatus::Success);
+        assert!(alive, "--dry-run signalled the matched process");
+    }
+
+    #[tokio::test]
+    async fn test_kill_process_invalid_regex() {
+        let options = KillProcessOptions {
+            pattern: "node(".to_string(),
+            mode: MatchMode::Regex,
+            ..Default::default()
+        };
+        let result = CliHandler::kill_process(&options, &OutputOptions::default()).await;
+        assert!(result.is_err());
     }
 
     #[tokio::test]
     async fn test_show_processes() {
-        let result = CliHandler::show_processes(false, false, 5).await;
+        let options = PsOptions {
+            limit: 5,
+            ..Default::default()
+        };
+        let result = CliHandler::show_processes(&options, &OutputOptions::default()).await;
         // Should not panic
         assert!(result.is_ok() || result.is_err());
     }
 
     #[tokio::test]
     async fn test_cleanup_development_processes() {
-        let result = CliHandler::cleanup_processes(false).await;
-        // Should handle cleanup gracefully
-        assert!(result.is_ok());
+        let result =
+            CliHandler::cleanup_processes(&CleanupOptions::default(), &OutputOptions::default())
+                .await;
+        // Without a group, cleanup is a usage error
+        assert_eq!(result.unwrap(), CliStatus::InvalidUsage);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_dry_run_signals_nothing() {
+        let options = CleanupOptions {
+            all_groups: true,
+            dry_run: true,
+            ..Default::default()
+        };
+        let result = CliHandler::cleanup_processes(&options, &OutputOptions::default()).await;
+        assert_eq!(result.unwrap(), CliStatus::Success);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_unknown_group() {
+        let options = CleanupOptions {
+            groups: vec!["no_such_group".to_string()],
+            dry_run: true,
+            ..Default::default()
+        };
+        let error = CliHandler::cleanup_processes(&options, &OutputOptions::default())
+            .await
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Unknown process group 'no_such_group'"));
+    }
+
+    #[test]
+    fn test_resolve_groups() {
+        let presets = Presets::bundled();
+
+        let options = CleanupOptions {
+            groups: vec!["frontend".to_string(), "databases".to_string()],
+            ..Default::default()
+        };
+        let groups = resolve_groups(&presets, &options).unwrap();
+        let names: Vec<&str> = groups.iter().map(|(group, _)| *group).collect();
+        assert_eq!(names, vec!["frontend", "databases"]);
+
+        let all = CleanupOptions {
+            all_groups: true,
+            ..Default::default()
+        };
+        assert_eq!(resolve_groups(&presets, &all).unwrap().len(), 4);
+        assert!(resolve_groups(&presets, &CleanupOptions::default())
+            .unwrap()
+            .is_empty());
     }
 
     #[tokio::test]
     async fn test_find_available_port() {
-        let result = CliHandler::find_available_port(50000, 50010).await;
+        let result = CliHandler::find_available_port(50000, 50010, &OutputOptions::default()).await;
         // Should find an available port in this range
         assert!(result.is_ok() || result.is_err());
     }
 
+    fn outcome(pid: u32, failure: Option<KillFailure>) -> KillOutcome {
+        KillOutcome {
+            pid,
+            name: None,
+            success: failure.is_none(),
+            failure,
+            error: failure.map(|f| f.as_str().to_string()),
+        }
+    }
+
+    #[test]
+    fn test_status_from_outcomes() {
+        assert_eq!(CliStatus::from_outcomes(&[]), CliStatus::NotFound);
+        assert_eq!(
+            CliStatus::from_outcomes(&[outcome(1, None), outcome(2, None)]),
+            CliStatus::Success
+        );
+        assert_eq!(
+            CliStatus::from_outcomes(&[
+                outcome(1, None),
+                outcome(2, Some(KillFailure::PermissionDenied))
+            ]),
+            CliStatus::PartialFailure
+        );
+        assert_eq!(
+            CliStatus::from_outcomes(&[outcome(1, Some(KillFailure::PermissionDenied))]),
+            CliStatus::PermissionDenied
+        );
+        assert_eq!(
+            CliStatus::from_outcomes(&[outcome(1, Some(KillFailure::Timeout))]),
+            CliStatus::Timeout
+        );
+        assert_eq!(
+            CliStatus::from_outcomes(&[
+                outcome(1, Some(KillFailure::Protected)),
+                outcome(2, Some(KillFailure::Protected))
+            ]),
+            CliStatus::Protected
+        );
+        assert_eq!(
+            CliStatus::from_outcomes(&[
+                outcome(1, Some(KillFailure::Timeout)),
+                outcome(2, Some(KillFailure::NotFound))
+            ]),
+            CliStatus::Failure
+        );
+    }
+
+    #[test]
+    fn test_action_records_pair_outcomes_by_pid() {
+        let action = crate::config::presets::QuickAction {
+            name: "Test".to_string(),
+            description: String::new(),
+            processes: Vec::new(),
+            ports: vec![3000, 3001, 3002],
+            confirmation_required: false,
+        };
+        let plan = ActionPlan::build(
+            "test",
+            &action,
+            &[],
+            &[(3000, Some(10)), (3001, Some(20)), (3002, Some(30))],
+        );
+        let outcomes = [
+            outcome(20, None),
+            outcome(10, Some(KillFailure::PermissionDenied)),
+        ];
+
+        let records = ActionRecord::from_outcomes(&plan, &outcomes);
+        let summary: Vec<(u32, Vec<u16>, TargetStatus)> = records
+            .iter()
+            .map(|r| (r.pid, r.ports.clone(), r.status))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (10, vec![3000], TargetStatus::Failed),
+                (20, vec![3001], TargetStatus::Terminated),
+                (30, vec![3002], TargetStatus::Failed),
+            ]
+        );
+        assert!(records[2].error.is_some());
+    }
+
+    #[test]
+    fn test_status_codes_are_distinct() {
+        let statuses = [
+            CliStatus::Success,
+            CliStatus::Failure,
+            CliStatus::InvalidUsage,
+            CliStatus::NotFound,
+            CliStatus::PartialFailure,
+            CliStatus::PermissionDenied,
+            CliStatus::Timeout,
+            CliStatus::Protected,
+        ];
+        let mut codes: Vec<u8> = statuses.iter().map(|s| s.code()).collect();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), statuses.len());
+        assert_eq!(CliStatus::Success.code(), 0);
+    }
+
     #[test]
     fn test_mock_system_outputs() {
         let ps_output = MockSystemOutputs::mock_ps_output();
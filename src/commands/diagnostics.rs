@@ -0,0 +1,309 @@
+use crate::commands::output::{optional, Record};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Outcome of validating one config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Ok,
+    /// The file doesn't exist; the defaults apply
+    Missing,
+    /// Loads, but something in it is ignored
+    Warning,
+    Error,
+}
+
+impl CheckStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckStatus::Ok => "ok",
+            CheckStatus::Missing => "missing",
+            CheckStatus::Warning => "warning",
+            CheckStatus::Error => "error",
+        }
+    }
+}
+
+/// One finding from `config check`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckRecord {
+    /// What the file is, e.g. `user presets`
+    pub file: String,
+    pub path: String,
+    pub status: CheckStatus,
+    pub line: Option<usize>,
+    pub message: Option<String>,
+}
+
+impl CheckRecord {
+    pub fn new(file: &str, path: &str, status: CheckStatus) -> Self {
+        Self {
+            file: file.to_string(),
+            path: path.to_string(),
+            status,
+            line: None,
+            message: None,
+        }
+    }
+
+    pub fn problem(
+        file: &str,
+        path: &str,
+        status: CheckStatus,
+        line: Option<usize>,
+        message: String,
+    ) -> Self {
+        Self {
+            line,
+            message: Some(message),
+            ..Self::new(file, path, status)
+        }
+    }
+
+    /// `path:line` when the line is known.
+    pub fn location(&self) -> String {
+        match self.line {
+            Some(line) => format!("{}:{line}", self.path),
+            None => self.path.clone(),
+        }
+    }
+}
+
+impl Record for CheckRecord {
+    fn columns() -> &'static [&'static str] {
+        &["file", "path", "status", "line", "message"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.file.clone(),
+            self.path.clone(),
+            self.status.as_str().to_string(),
+            optional(self.line),
+            optional(self.message.as_ref()),
+        ]
+    }
+}
+
+/// One effective value from `config show`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigEntry {
+    pub key: String,
+    /// The value as TOML
+    pub value: String,
+    /// `bundled`, `default`, a file path or `env VAR`
+    pub source: String,
+}
+
+impl Record for ConfigEntry {
+    fn columns() -> &'static [&'static str] {
+        &["key", "value", "source"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![self.key.clone(), self.value.clone(), self.source.clone()]
+    }
+}
+
+/// A location from `config path`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PathRecord {
+    pub file: String,
+    pub path: String,
+    pub exists: bool,
+}
+
+impl Record for PathRecord {
+    fn columns() -> &'static [&'static str] {
+        &["file", "path", "exists"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.file.clone(),
+            self.path.clone(),
+            self.exists.to_string(),
+        ]
+    }
+}
+
+/// Line (1-based) and message for an error found while loading `source`.
+/// TOML syntax and type errors carry their position; validation errors are
+/// located by the dotted key they start with, e.g. `ui_settings.max_port_display`.
+pub fn error_location(source: &str, error: &anyhow::Error) -> (Option<usize>, String) {
+    if let Some(toml_error) = error
+        .chain()
+        .find_map(|e| e.downcast_ref::<toml::de::Error>())
+    {
+        let line = toml_error
+            .span()
+            .map(|span| source[..span.start.min(source.len())].matches('\n').count() + 1);
+        return (line, toml_error.message().trim().to_string());
+    }
+
+    let message = format!("{error:#}");
+    (key_line(source, leading_key(&message)), message)
+}
+
+/// The dotted key an error message starts with.
+fn leading_key(message: &str) -> &str {
+    let end = message
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')))
+        .unwrap_or(message.len());
+    message[..end].trim_end_matches('.')
+}
+
+/// Line of the most specific table header or key in `source` that `path`
+/// starts with.
+pub fn key_line(source: &str, path: &str) -> Option<usize> {
+    if path.is_empty() {
+        return None;
+    }
+    let wanted: Vec<&str> = path.split('.').collect();
+    let split = |key: &str| -> Vec<String> {
+        key.split('.')
+            .map(|part| part.trim().trim_matches('"').to_string())
+            .collect()
+    };
+
+    let mut table: Vec<String> = Vec::new();
+    let mut best: Option<(usize, usize)> = None;
+    for (index, line) in source.lines().enumerate() {
+        let line = line.trim();
+        let full = if line.starts_with('[') {
+            table = split(line.trim_start_matches('[').split(']').next().unwrap_or(""));
+            table.clone()
+        } else if let Some((key, _)) = line.split_once('=').filter(|_| !line.starts_with('#')) {
+            let mut full = table.clone();
+            full.extend(split(key));
+            full
+        } else {
+            continue;
+        };
+
+        let is_prefix = full.len() <= wanted.len() && full.iter().zip(&wanted).all(|(a, b)| a == b);
+        if is_prefix && best.is_none_or(|(depth, _)| full.len() > depth) {
+            best = Some((full.len(), index + 1));
+        }
+    }
+    best.map(|(_, line)| line)
+}
+
+/// Dotted keys and leaf values of `table`; arrays are leaves.
+pub fn flatten(prefix: &str, table: &toml::Table, out: &mut Vec<(String, toml::Value)>) {
+    for (key, value) in table {
+        let key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            toml::Value::Table(inner) => flatten(&key, inner, out),
+            _ => out.push((key, value.clone())),
+        }
+    }
+}
+
+/// Named presets entries that a later layer replaces as a whole rather
+/// than key by key.
+fn replace_unit(key: &str) -> &str {
+    let mut parts = key.splitn(3, '.');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(section @ ("quick_actions" | "search_patterns")), Some(name), Some(_)) => {
+            &key[..section.len() + 1 + name.len()]
+        }
+        _ => key,
+    }
+}
+
+/// Overlay presets layers, lowest precedence first, the way they are merged
+/// when loading, remembering which layer each value came from.
+pub fn merge_layers(layers: &[(String, toml::Table)]) -> BTreeMap<String, (toml::Value, String)> {
+    let mut merged: BTreeMap<String, (toml::Value, String)> = BTreeMap::new();
+
+    for (source, table) in layers {
+        let mut entries = Vec::new();
+        flatten("", table, &mut entries);
+
+        let mut replaced: Vec<&str> = Vec::new();
+        for (key, _) in &entries {
+            let unit = replace_unit(key);
+            if unit != key && !replaced.contains(&unit) {
+                let prefix = format!("{unit}.");
+                merged.retain(|existing, _| !existing.starts_with(&prefix));
+                replaced.push(unit);
+            }
+        }
+        for (key, value) in entries {
+            merged.insert(key, (value, source.clone()));
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+[ui_settings]
+refresh_interval_ms = 10
+
+[quick_actions.dev]
+name = "Dev"
+"#;
+
+    #[test]
+    fn test_key_line() {
+        assert_eq!(key_line(SOURCE, "ui_settings.refresh_interval_ms"), Some(3));
+        assert_eq!(key_line(SOURCE, "quick_actions.dev"), Some(5));
+        assert_eq!(key_line(SOURCE, "quick_actions.dev.ports"), Some(5));
+        assert_eq!(key_line(SOURCE, "common_ports.web"), None);
+
+        let error =
+            anyhow::anyhow!("ui_settings.refresh_interval_ms must be at least 100 (got 10)");
+        assert_eq!(error_location(SOURCE, &error).0, Some(3));
+
+        let error =
+            anyhow::Error::from(toml::from_str::<toml::Table>("a = 1\nb = \n").unwrap_err());
+        assert_eq!(error_location("a = 1\nb = \n", &error).0, Some(2));
+    }
+
+    #[test]
+    fn test_merge_layers_replaces_named_entries() {
+        let bundled: toml::Table = r#"
+[ui_settings]
+refresh_interval_ms = 2000
+max_port_display = 50
+
+[quick_actions.dev]
+name = "Dev"
+processes = ["node"]
+confirmation_required = false
+"#
+        .parse()
+        .unwrap();
+        let user: toml::Table = r#"
+[ui_settings]
+refresh_interval_ms = 500
+
+[quick_actions.dev]
+name = "Mine"
+ports = [3000]
+"#
+        .parse()
+        .unwrap();
+
+        let merged = merge_layers(&[("bundled".to_string(), bundled), ("user".to_string(), user)]);
+        let source = |key: &str| merged.get(key).map(|(_, source)| source.as_str());
+
+        assert_eq!(source("ui_settings.refresh_interval_ms"), Some("user"));
+        assert_eq!(source("ui_settings.max_port_display"), Some("bundled"));
+        assert_eq!(source("quick_actions.dev.name"), Some("user"));
+        // The user entry replaced the bundled one entirely
+        assert_eq!(source("quick_actions.dev.processes"), None);
+        assert_eq!(source("quick_actions.dev.confirmation_required"), None);
+    }
+}
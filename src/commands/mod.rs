@@ -1,3 +1,9 @@
 pub mod cli;
+pub mod diagnostics;
+pub mod output;
+pub mod ps;
+pub mod watch;
 
 pub use cli::*;
+pub use output::*;
+pub use ps::*;
@@ -0,0 +1,450 @@
+use crate::network::{ConnectionInfo, PortInfo};
+use crate::process::{AuditEntry, KillOutcome, ProcessInfo};
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Output formats supported by every CLI command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable box-drawing tables (default)
+    #[default]
+    Table,
+    /// Pretty-printed JSON array of records
+    Json,
+    /// Comma-separated values
+    Csv,
+    /// Tab-separated values
+    Tsv,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OutputOptions {
+    pub format: OutputFormat,
+    pub no_header: bool,
+}
+
+/// A record that can be emitted in the delimited (CSV/TSV) formats.
+///
+/// JSON output uses the `Serialize` implementation directly, so the column
+/// names here should match the serialized field names.
+pub trait Record: Serialize {
+    fn columns() -> &'static [&'static str];
+    fn values(&self) -> Vec<String>;
+}
+
+impl OutputOptions {
+    pub fn new(format: OutputFormat, no_header: bool) -> Self {
+        Self { format, no_header }
+    }
+
+    pub fn is_table(&self) -> bool {
+        self.format == OutputFormat::Table
+    }
+
+    /// Emit `records` in the selected format. `table` renders the
+    /// human-readable variant and is only called for `OutputFormat::Table`.
+    pub fn emit<T: Record>(&self, records: &[T], table: impl FnOnce(&[T])) -> Result<()> {
+        match self.format {
+            OutputFormat::Table => table(records),
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(records)?),
+            OutputFormat::Csv => print!("{}", format_delimited(records, ',', self.no_header)),
+            OutputFormat::Tsv => print!("{}", format_delimited(records, '\t', self.no_header)),
+        }
+        Ok(())
+    }
+
+    /// Emit rows whose columns are chosen at runtime. Each row holds one
+    /// JSON value per entry in `columns`, in the same order.
+    pub fn emit_dynamic(
+        &self,
+        columns: &[&str],
+        rows: &[Vec<Value>],
+        table: impl FnOnce(),
+    ) -> Result<()> {
+        match self.format {
+            OutputFormat::Table => table(),
+            OutputFormat::Json => {
+                let objects: Vec<Map<String, Value>> = rows
+                    .iter()
+                    .map(|row| {
+                        columns
+                            .iter()
+                            .map(|c| c.to_string())
+                            .zip(row.iter().cloned())
+                            .collect()
+                    })
+                    .collect();
+                println!("{}", serde_json::to_string_pretty(&objects)?);
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                let separator = if self.format == OutputFormat::Csv {
+                    ','
+                } else {
+                    '\t'
+                };
+                let mut output = String::new();
+                if !self.no_header {
+                    let header: Vec<String> = columns.iter().map(|c| c.to_string()).collect();
+                    output.push_str(&join_fields(&header, separator));
+                    output.push('\n');
+                }
+                for row in rows {
+                    let fields: Vec<String> = row.iter().map(value_to_field).collect();
+                    output.push_str(&join_fields(&fields, separator));
+                    output.push('\n');
+                }
+                print!("{output}");
+            }
+        }
+        Ok(())
+    }
+}
+
+fn value_to_field(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Render a box-drawing table sized to its contents.
+pub fn format_box_table(headers: &[&str], rows: &[Vec<String>], right_align: &[bool]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let border = |left: &str, mid: &str, right: &str| {
+        let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+        format!("{left}{}{right}\n", segments.join(mid))
+    };
+    let line = |cells: Vec<&str>, align: &dyn Fn(usize) -> bool| {
+        let cells: Vec<String> = cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                let padding = widths[i].saturating_sub(cell.chars().count());
+                if align(i) {
+                    format!(" {}{cell} ", " ".repeat(padding))
+                } else {
+                    format!(" {cell}{} ", " ".repeat(padding))
+                }
+            })
+            .collect();
+        format!("│{}│\n", cells.join("│"))
+    };
+
+    let mut output = border("┌", "┬", "┐");
+    output.push_str(&line(headers.to_vec(), &|_| false));
+    output.push_str(&border("├", "┼", "┤"));
+    for row in rows {
+        let cells: Vec<&str> = row.iter().map(String::as_str).collect();
+        output.push_str(&line(cells, &|i| {
+            right_align.get(i).copied().unwrap_or(false)
+        }));
+    }
+    output.push_str(&border("└", "┴", "┘"));
+    output
+}
+
+/// Truncate to `max` characters, marking the cut with an ellipsis.
+pub fn truncate(value: &str, max: usize) -> String {
+    if value.chars().count() > max {
+        format!(
+            "{}…",
+            value
+                .chars()
+                .take(max.saturating_sub(1))
+                .collect::<String>()
+        )
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn format_delimited<T: Record>(records: &[T], separator: char, no_header: bool) -> String {
+    let mut output = String::new();
+
+    if !no_header {
+        let header: Vec<String> = T::columns().iter().map(|c| c.to_string()).collect();
+        output.push_str(&join_fields(&header, separator));
+        output.push('\n');
+    }
+
+    for record in records {
+        output.push_str(&join_fields(&record.values(), separator));
+        output.push('\n');
+    }
+
+    output
+}
+
+fn join_fields(fields: &[String], separator: char) -> String {
+    fields
+        .iter()
+        .map(|field| escape_field(field, separator))
+        .collect::<Vec<_>>()
+        .join(&separator.to_string())
+}
+
+fn escape_field(field: &str, separator: char) -> String {
+    if separator == '\t' {
+        // TSV has no quoting convention; flatten characters that would break rows
+        return field.replace(['\t', '\n', '\r'], " ");
+    }
+
+    if field.contains(separator) || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Render an optional value as an empty field when absent.
+pub fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+impl Record for ProcessInfo {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "pid",
+            "parent_pid",
+            "name",
+            "cpu_usage",
+            "memory",
+            "status",
+            "start_time",
+            "user_id",
+            "executable_path",
+            "command_line",
+        ]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.pid.to_string(),
+            optional(self.parent_pid),
+            self.name.clone(),
+            format!("{:.1}", self.cpu_usage),
+            self.memory.to_string(),
+            self.status.clone(),
+            self.start_time.to_string(),
+            optional(self.user_id),
+            optional(self.executable_path.as_ref()),
+            self.command_line.join(" "),
+        ]
+    }
+}
+
+impl Record for PortInfo {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "port",
+            "protocol",
+            "pid",
+            "process_name",
+            "local_address",
+            "remote_address",
+            "state",
+            "service_name",
+        ]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.port.to_string(),
+            self.protocol.as_str().to_string(),
+            optional(self.pid),
+            optional(self.process_name.as_ref()),
+            self.local_address.to_string(),
+            optional(self.remote_address),
+            self.state.as_str().to_string(),
+            optional(self.service_name.as_ref()),
+        ]
+    }
+}
+
+impl Record for ConnectionInfo {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "protocol",
+            "local_address",
+            "remote_address",
+            "pid",
+            "process_name",
+        ]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.protocol.as_str().to_string(),
+            self.local_address.to_string(),
+            self.remote_address.to_string(),
+            optional(self.pid),
+            optional(self.process_name.as_ref()),
+        ]
+    }
+}
+
+impl Record for KillOutcome {
+    fn columns() -> &'static [&'static str] {
+        &["pid", "name", "success", "failure", "error"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.pid.to_string(),
+            optional(self.name.as_ref()),
+            self.success.to_string(),
+            optional(self.failure.map(|f| f.as_str())),
+            optional(self.error.as_ref()),
+        ]
+    }
+}
+
+impl Record for AuditEntry {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "timestamp",
+            "pid",
+            "name",
+            "command_line",
+            "action",
+            "initiator",
+            "rule",
+            "success",
+            "failure",
+            "error",
+        ]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.timestamp.to_string(),
+            self.pid.to_string(),
+            optional(self.name.as_ref()),
+            optional(self.command_line.as_ref()),
+            self.action.clone(),
+            self.initiator.as_str().to_string(),
+            optional(self.rule.as_ref()),
+            self.success.to_string(),
+            optional(self.failure.map(|f| f.as_str())),
+            optional(self.error.as_ref()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::KillFailure;
+
+    #[derive(Serialize)]
+    struct TestRecord {
+        id: u32,
+        label: String,
+    }
+
+    impl Record for TestRecord {
+        fn columns() -> &'static [&'static str] {
+            &["id", "label"]
+        }
+
+        fn values(&self) -> Vec<String> {
+            vec![self.id.to_string(), self.label.clone()]
+        }
+    }
+
+    fn records() -> Vec<TestRecord> {
+        vec![
+            TestRecord {
+                id: 1,
+                label: "plain".to_string(),
+            },
+            TestRecord {
+                id: 2,
+                label: "with, comma and \"quotes\"".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_csv_output() {
+        let output = format_delimited(&records(), ',', false);
+        assert_eq!(
+            output,
+            "id,label\n1,plain\n2,\"with, comma and \"\"quotes\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_tsv_output_without_header() {
+        let mut records = records();
+        records[0].label = "tab\there".to_string();
+
+        let output = format_delimited(&records, '\t', true);
+        assert_eq!(output, "1\ttab here\n2\twith, comma and \"quotes\"\n");
+    }
+
+    #[test]
+    fn test_empty_records_still_print_header() {
+        let output = format_delimited::<TestRecord>(&[], ',', false);
+        assert_eq!(output, "id,label\n");
+    }
+
+    #[test]
+    fn test_kill_outcome_record() {
+        let outcome = KillOutcome {
+            pid: 42,
+            name: Some("node".to_string()),
+            success: false,
+            failure: Some(KillFailure::PermissionDenied),
+            error: Some("Operation not permitted".to_string()),
+        };
+
+        let output = format_delimited(std::slice::from_ref(&outcome), ',', false);
+        assert_eq!(
+            output,
+            "pid,name,success,failure,error\n42,node,false,permission_denied,Operation not permitted\n"
+        );
+
+        let json = serde_json::to_value(&outcome).unwrap();
+        assert_eq!(json["pid"], 42);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["failure"], "permission_denied");
+    }
+
+    #[test]
+    fn test_box_table_sizes_to_contents() {
+        let rows = vec![
+            vec!["1".to_string(), "init".to_string()],
+            vec!["1234".to_string(), "node".to_string()],
+        ];
+        let table = format_box_table(&["PID", "Name"], &rows, &[true, false]);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines[0], "┌──────┬──────┐");
+        assert_eq!(lines[1], "│ PID  │ Name │");
+        assert_eq!(lines[3], "│    1 │ init │");
+        assert_eq!(lines[4], "│ 1234 │ node │");
+        assert_eq!(lines[5], "└──────┴──────┘");
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("a-very-long-name", 6), "a-ver…");
+    }
+
+    #[test]
+    fn test_optional_field() {
+        assert_eq!(optional(Some(42)), "42");
+        assert_eq!(optional::<u32>(None), "");
+    }
+}
@@ -0,0 +1,506 @@
+use crate::commands::output::{format_box_table, truncate};
+use crate::process::ProcessInfo;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Columns that `ps` can display, sort by and export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessColumn {
+    Pid,
+    Ppid,
+    User,
+    Name,
+    Cpu,
+    Mem,
+    Status,
+    Nice,
+    Pri,
+    Start,
+    Command,
+}
+
+impl ProcessColumn {
+    pub const DEFAULT: [ProcessColumn; 5] = [
+        ProcessColumn::Pid,
+        ProcessColumn::Name,
+        ProcessColumn::Cpu,
+        ProcessColumn::Mem,
+        ProcessColumn::Status,
+    ];
+
+    /// Machine-readable column name, used for CSV/TSV headers and JSON keys.
+    pub fn key(self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "pid",
+            ProcessColumn::Ppid => "ppid",
+            ProcessColumn::User => "user",
+            ProcessColumn::Name => "name",
+            ProcessColumn::Cpu => "cpu",
+            ProcessColumn::Mem => "mem",
+            ProcessColumn::Status => "status",
+            ProcessColumn::Nice => "nice",
+            ProcessColumn::Pri => "pri",
+            ProcessColumn::Start => "start",
+            ProcessColumn::Command => "command",
+        }
+    }
+
+    pub fn header(self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "PID",
+            ProcessColumn::Ppid => "PPID",
+            ProcessColumn::User => "User",
+            ProcessColumn::Name => "Process",
+            ProcessColumn::Cpu => "CPU %",
+            ProcessColumn::Mem => "Memory",
+            ProcessColumn::Status => "Status",
+            ProcessColumn::Nice => "NI",
+            ProcessColumn::Pri => "PRI",
+            ProcessColumn::Start => "Elapsed",
+            ProcessColumn::Command => "Command",
+        }
+    }
+
+    fn is_numeric(self) -> bool {
+        matches!(
+            self,
+            ProcessColumn::Pid
+                | ProcessColumn::Ppid
+                | ProcessColumn::Cpu
+                | ProcessColumn::Mem
+                | ProcessColumn::Nice
+                | ProcessColumn::Pri
+        )
+    }
+}
+
+/// Selection, ordering and presentation options for `bossy-rust ps`.
+#[derive(Debug, Clone)]
+pub struct PsOptions {
+    pub top_cpu: bool,
+    pub top_memory: bool,
+    /// Maximum rows to show; 0 shows everything
+    pub limit: usize,
+    /// Query in the `ProcessInfo::matches_search` grammar
+    pub filter: Option<String>,
+    pub sort: Option<ProcessColumn>,
+    pub reverse: bool,
+    pub columns: Vec<ProcessColumn>,
+    /// User name or numeric UID
+    pub user: Option<String>,
+    /// Only show descendants of this PID
+    pub children_of: Option<u32>,
+}
+
+impl Default for PsOptions {
+    fn default() -> Self {
+        Self {
+            top_cpu: false,
+            top_memory: false,
+            limit: 10,
+            filter: None,
+            sort: None,
+            reverse: false,
+            columns: Vec::new(),
+            user: None,
+            children_of: None,
+        }
+    }
+}
+
+impl PsOptions {
+    pub fn columns(&self) -> Vec<ProcessColumn> {
+        if self.columns.is_empty() {
+            ProcessColumn::DEFAULT.to_vec()
+        } else {
+            self.columns.clone()
+        }
+    }
+
+    /// Effective sort column and whether it is descending. `--top-cpu` and
+    /// `--top-memory` are shorthands for a descending sort on that column;
+    /// otherwise processes are listed by PID.
+    fn ordering(&self) -> (ProcessColumn, bool) {
+        if let Some(column) = self.sort {
+            (column, self.reverse)
+        } else if self.top_cpu {
+            (ProcessColumn::Cpu, !self.reverse)
+        } else if self.top_memory {
+            (ProcessColumn::Mem, !self.reverse)
+        } else {
+            (ProcessColumn::Pid, self.reverse)
+        }
+    }
+
+    pub fn title(&self, shown: usize) -> String {
+        if self.sort.is_none() && self.top_cpu {
+            format!("Top {shown} CPU Consumers")
+        } else if self.sort.is_none() && self.top_memory {
+            format!("Top {shown} Memory Consumers")
+        } else {
+            format!("Processes (showing {shown})")
+        }
+    }
+}
+
+/// Apply the selectors, filter, sort and limit from `options`.
+pub fn select_processes(
+    processes: Vec<ProcessInfo>,
+    options: &PsOptions,
+    users: &HashMap<u32, String>,
+) -> Vec<ProcessInfo> {
+    let descendants = options
+        .children_of
+        .map(|pid| descendants_of(pid, &processes));
+
+    let mut selected: Vec<ProcessInfo> = processes
+        .into_iter()
+        .filter(|p| descendants.as_ref().is_none_or(|set| set.contains(&p.pid)))
+        .filter(|p| {
+            options
+                .user
+                .as_deref()
+                .is_none_or(|user| matches_user(p, user, users))
+        })
+        .filter(|p| {
+            options
+                .filter
+                .as_deref()
+                .is_none_or(|query| p.matches_search(query))
+        })
+        .collect();
+
+    let (column, descending) = options.ordering();
+    selected.sort_by(|a, b| {
+        let ordering = compare(a, b, column, users);
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    if options.limit > 0 {
+        selected.truncate(options.limit);
+    }
+    selected
+}
+
+/// All transitive children of `root` (not including `root` itself).
+pub fn descendants_of(root: u32, processes: &[ProcessInfo]) -> HashSet<u32> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for process in processes {
+        if let Some(parent) = process.parent_pid {
+            children.entry(parent).or_default().push(process.pid);
+        }
+    }
+
+    let mut found = HashSet::new();
+    let mut stack = vec![root];
+    while let Some(pid) = stack.pop() {
+        for &child in children.get(&pid).into_iter().flatten() {
+            if found.insert(child) {
+                stack.push(child);
+            }
+        }
+    }
+    found
+}
+
+fn matches_user(process: &ProcessInfo, user: &str, users: &HashMap<u32, String>) -> bool {
+    let Some(uid) = process.user_id else {
+        return false;
+    };
+    match user.parse::<u32>() {
+        Ok(wanted) => uid == wanted,
+        Err(_) => users.get(&uid).is_some_and(|name| name == user),
+    }
+}
+
+pub fn user_label(process: &ProcessInfo, users: &HashMap<u32, String>) -> String {
+    match process.user_id {
+        Some(uid) => users.get(&uid).cloned().unwrap_or_else(|| uid.to_string()),
+        None => String::new(),
+    }
+}
+
+pub fn command_label(process: &ProcessInfo) -> String {
+    if process.command_line.is_empty() {
+        // Kernel threads and some protected processes have no argv
+        format!("[{}]", process.name)
+    } else {
+        process.command_line.join(" ")
+    }
+}
+
+fn compare(
+    a: &ProcessInfo,
+    b: &ProcessInfo,
+    column: ProcessColumn,
+    users: &HashMap<u32, String>,
+) -> Ordering {
+    match column {
+        ProcessColumn::Pid => a.pid.cmp(&b.pid),
+        ProcessColumn::Ppid => a.parent_pid.cmp(&b.parent_pid),
+        ProcessColumn::User => user_label(a, users).cmp(&user_label(b, users)),
+        ProcessColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        ProcessColumn::Cpu => a.cpu_usage.total_cmp(&b.cpu_usage),
+        ProcessColumn::Mem => a.memory.cmp(&b.memory),
+        ProcessColumn::Status => a.status.cmp(&b.status),
+        ProcessColumn::Nice => a.nice.cmp(&b.nice),
+        ProcessColumn::Pri => a.priority.cmp(&b.priority),
+        ProcessColumn::Start => a.start_time.cmp(&b.start_time),
+        ProcessColumn::Command => command_label(a).cmp(&command_label(b)),
+    }
+    .then(a.pid.cmp(&b.pid))
+}
+
+/// Typed value of a column, used for JSON and delimited output.
+pub fn column_value(
+    process: &ProcessInfo,
+    column: ProcessColumn,
+    users: &HashMap<u32, String>,
+) -> Value {
+    match column {
+        ProcessColumn::Pid => json!(process.pid),
+        ProcessColumn::Ppid => json!(process.parent_pid),
+        ProcessColumn::User => match process.user_id {
+            Some(_) => json!(user_label(process, users)),
+            None => Value::Null,
+        },
+        ProcessColumn::Name => json!(process.name),
+        ProcessColumn::Cpu => json!((process.cpu_usage * 10.0).round() / 10.0),
+        ProcessColumn::Mem => json!(process.memory),
+        ProcessColumn::Status => json!(process.status),
+        ProcessColumn::Nice => json!(process.nice),
+        ProcessColumn::Pri => json!(process.priority),
+        ProcessColumn::Start => json!(process.start_time),
+        ProcessColumn::Command => json!(command_label(process)),
+    }
+}
+
+/// Human-readable cell for the table view.
+fn column_cell(
+    process: &ProcessInfo,
+    column: ProcessColumn,
+    users: &HashMap<u32, String>,
+    now: u64,
+) -> String {
+    match column {
+        ProcessColumn::Pid => process.pid.to_string(),
+        ProcessColumn::Ppid => process
+            .parent_pid
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        ProcessColumn::User => truncate(&user_label(process, users), 12),
+        ProcessColumn::Name => truncate(&process.name, 19),
+        ProcessColumn::Cpu => format!("{:.1}", process.cpu_usage),
+        ProcessColumn::Mem => process.format_memory(),
+        ProcessColumn::Status => truncate(&process.status, 12),
+        ProcessColumn::Nice => optional_number(process.nice),
+        ProcessColumn::Pri => optional_number(process.priority),
+        ProcessColumn::Start => format_elapsed(now.saturating_sub(process.start_time)),
+        ProcessColumn::Command => truncate(&command_label(process), 60),
+    }
+}
+
+fn optional_number(value: Option<i32>) -> String {
+    value
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Compact elapsed time: `42m07s`, `3h05m`, `12d04h`.
+pub fn format_elapsed(seconds: u64) -> String {
+    let days = seconds / 86_400;
+    let hours = (seconds % 86_400) / 3_600;
+    let minutes = (seconds % 3_600) / 60;
+    let secs = seconds % 60;
+
+    if days > 0 {
+        format!("{days}d{hours:02}h")
+    } else if hours > 0 {
+        format!("{hours}h{minutes:02}m")
+    } else {
+        format!("{minutes}m{secs:02}s")
+    }
+}
+
+pub fn process_rows(
+    processes: &[ProcessInfo],
+    columns: &[ProcessColumn],
+    users: &HashMap<u32, String>,
+) -> Vec<Vec<Value>> {
+    processes
+        .iter()
+        .map(|p| columns.iter().map(|&c| column_value(p, c, users)).collect())
+        .collect()
+}
+
+pub fn format_process_table(
+    processes: &[ProcessInfo],
+    columns: &[ProcessColumn],
+    users: &HashMap<u32, String>,
+) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    let headers: Vec<&str> = columns.iter().map(|c| c.header()).collect();
+    let align: Vec<bool> = columns.iter().map(|c| c.is_numeric()).collect();
+    let rows: Vec<Vec<String>> = processes
+        .iter()
+        .map(|p| {
+            columns
+                .iter()
+                .map(|&c| column_cell(p, c, users, now))
+                .collect()
+        })
+        .collect();
+
+    format_box_table(&headers, &rows, &align)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::create_test_process;
+
+    fn process(pid: u32, parent: Option<u32>, name: &str, cpu: f32, memory: u64) -> ProcessInfo {
+        ProcessInfo {
+            parent_pid: parent,
+            user_id: Some(if pid < 100 { 0 } else { 501 }),
+            command_line: vec![name.to_string(), "--flag".to_string()],
+            ..create_test_process(pid, name, cpu, memory)
+        }
+    }
+
+    fn fixture() -> Vec<ProcessInfo> {
+        vec![
+            process(1, None, "init", 0.1, 10 * 1024 * 1024),
+            process(50, Some(1), "sshd", 0.0, 5 * 1024 * 1024),
+            process(200, Some(50), "zsh", 1.0, 8 * 1024 * 1024),
+            process(300, Some(200), "node", 40.0, 400 * 1024 * 1024),
+            process(301, Some(300), "esbuild", 12.5, 90 * 1024 * 1024),
+            process(400, Some(1), "postgres", 3.0, 200 * 1024 * 1024),
+        ]
+    }
+
+    fn users() -> HashMap<u32, String> {
+        HashMap::from([(0, "root".to_string()), (501, "dev".to_string())])
+    }
+
+    fn pids(processes: &[ProcessInfo]) -> Vec<u32> {
+        processes.iter().map(|p| p.pid).collect()
+    }
+
+    #[test]
+    fn test_sort_ascending_and_reverse() {
+        let mut options = PsOptions {
+            sort: Some(ProcessColumn::Mem),
+            limit: 0,
+            ..Default::default()
+        };
+        let sorted = select_processes(fixture(), &options, &users());
+        assert_eq!(pids(&sorted), vec![50, 200, 1, 301, 400, 300]);
+
+        options.reverse = true;
+        options.limit = 2;
+        let sorted = select_processes(fixture(), &options, &users());
+        assert_eq!(pids(&sorted), vec![300, 400]);
+    }
+
+    #[test]
+    fn test_top_cpu_is_descending_cpu_sort() {
+        let options = PsOptions {
+            top_cpu: true,
+            limit: 3,
+            ..Default::default()
+        };
+        let sorted = select_processes(fixture(), &options, &users());
+        assert_eq!(pids(&sorted), vec![300, 301, 400]);
+        assert_eq!(options.title(sorted.len()), "Top 3 CPU Consumers");
+    }
+
+    #[test]
+    fn test_filter_uses_search_grammar() {
+        let options = PsOptions {
+            filter: Some(">10%".to_string()),
+            ..Default::default()
+        };
+        let selected = select_processes(fixture(), &options, &users());
+        assert_eq!(pids(&selected), vec![300, 301]);
+    }
+
+    #[test]
+    fn test_user_selector_accepts_name_or_uid() {
+        let by_name = PsOptions {
+            user: Some("root".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            pids(&select_processes(fixture(), &by_name, &users())),
+            vec![1, 50]
+        );
+
+        let by_uid = PsOptions {
+            user: Some("501".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            pids(&select_processes(fixture(), &by_uid, &users())),
+            vec![200, 300, 301, 400]
+        );
+    }
+
+    #[test]
+    fn test_children_of_is_recursive() {
+        let options = PsOptions {
+            children_of: Some(50),
+            ..Default::default()
+        };
+        let selected = select_processes(fixture(), &options, &users());
+        assert_eq!(pids(&selected), vec![200, 300, 301]);
+    }
+
+    #[test]
+    fn test_column_values() {
+        let node = &fixture()[3];
+        let columns = [
+            ProcessColumn::Pid,
+            ProcessColumn::User,
+            ProcessColumn::Command,
+        ];
+        let rows = process_rows(std::slice::from_ref(node), &columns, &users());
+
+        assert_eq!(
+            rows[0],
+            vec![json!(300), json!("dev"), json!("node --flag")]
+        );
+    }
+
+    #[test]
+    fn test_table_uses_selected_columns() {
+        let table = format_process_table(
+            &fixture()[..2],
+            &[ProcessColumn::Pid, ProcessColumn::Ppid, ProcessColumn::User],
+            &users(),
+        );
+        assert!(table.contains("PPID"));
+        assert!(table.contains("│   1 │    - │ root │"));
+        assert!(!table.contains("Memory"));
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(7), "0m07s");
+        assert_eq!(format_elapsed(3 * 3600 + 5 * 60), "3h05m");
+        assert_eq!(format_elapsed(12 * 86_400 + 4 * 3600), "12d04h");
+    }
+}
@@ -0,0 +1,310 @@
+use anyhow::Result;
+use crossterm::{
+    cursor, queue,
+    style::{Print, Stylize},
+    terminal::{self, Clear, ClearType, DisableLineWrap, EnableLineWrap},
+};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// Rows in a watch table start after the top border, header and separator.
+pub const TABLE_HEADER_LINES: usize = 3;
+
+/// How a row differs from the previous tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowChange {
+    Unchanged,
+    /// Not present on the previous tick
+    New,
+    /// Present on the previous tick but gone now; shown for one tick
+    Exited,
+    /// Present on both ticks, with a notable change (e.g. a CPU jump)
+    Changed,
+}
+
+/// Parse a watch interval: `2`, `1.5`, `2s` or `500ms`.
+pub fn parse_interval(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let (number, scale) = if let Some(ms) = value.strip_suffix("ms") {
+        (ms, 0.001)
+    } else if let Some(secs) = value.strip_suffix('s') {
+        (secs, 1.0)
+    } else {
+        (value, 1.0)
+    };
+
+    let seconds = number
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| format!("invalid interval '{value}' (expected e.g. 2, 1.5s or 500ms)"))?
+        * scale;
+
+    if !seconds.is_finite() || seconds < 0.1 {
+        return Err("interval must be at least 100ms".to_string());
+    }
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+/// Tracks rows between ticks to work out what changed.
+///
+/// Rows are identified by `key`; `changed` decides whether a row present on
+/// both ticks should be flagged.
+pub struct ChangeTracker<K, T> {
+    key: fn(&T) -> K,
+    changed: fn(&T, &T) -> bool,
+    previous: Option<HashMap<K, T>>,
+    shown: Vec<T>,
+}
+
+impl<K: Eq + Hash, T: Clone> ChangeTracker<K, T> {
+    pub fn new(key: fn(&T) -> K, changed: fn(&T, &T) -> bool) -> Self {
+        Self {
+            key,
+            changed,
+            previous: None,
+            shown: Vec::new(),
+        }
+    }
+
+    /// Record a new tick. `all` is the full sample, `shown` the rows that
+    /// will be displayed. Returns the change for each shown row, plus the
+    /// previously shown rows that no longer exist.
+    pub fn update(&mut self, all: &[T], shown: &[T]) -> (Vec<RowChange>, Vec<T>) {
+        let current: HashMap<K, T> = all
+            .iter()
+            .map(|row| ((self.key)(row), row.clone()))
+            .collect();
+
+        let (changes, exited) = match &self.previous {
+            // Nothing to compare against on the first tick
+            None => (vec![RowChange::Unchanged; shown.len()], Vec::new()),
+            Some(previous) => {
+                let changes = shown
+                    .iter()
+                    .map(|row| match previous.get(&(self.key)(row)) {
+                        None => RowChange::New,
+                        Some(before) if (self.changed)(before, row) => RowChange::Changed,
+                        Some(_) => RowChange::Unchanged,
+                    })
+                    .collect();
+                let exited = self
+                    .shown
+                    .iter()
+                    .filter(|row| !current.contains_key(&(self.key)(row)))
+                    .cloned()
+                    .collect();
+                (changes, exited)
+            }
+        };
+
+        self.previous = Some(current);
+        self.shown = shown.to_vec();
+        (changes, exited)
+    }
+}
+
+/// Build a frame from a rendered table, colouring each row by its change.
+pub fn render_frame(
+    title: &str,
+    table: &str,
+    changes: &[RowChange],
+    highlight: bool,
+) -> Vec<String> {
+    let mut lines = vec![title.to_string()];
+    if highlight {
+        lines.push(format!(
+            "{}  {}  {}",
+            "■ new".green(),
+            "■ changed".yellow(),
+            "■ exited".red()
+        ));
+    }
+
+    for (i, line) in table.lines().enumerate() {
+        let change = i
+            .checked_sub(TABLE_HEADER_LINES)
+            .and_then(|row| changes.get(row))
+            .copied()
+            .unwrap_or(RowChange::Unchanged);
+
+        let line = if highlight {
+            match change {
+                RowChange::Unchanged => line.to_string(),
+                RowChange::New => line.green().to_string(),
+                RowChange::Changed => line.yellow().to_string(),
+                RowChange::Exited => line.red().dim().to_string(),
+            }
+        } else {
+            line.to_string()
+        };
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Cut `frame` to `height` terminal rows, replacing the lines that don't
+/// fit with a "… N more lines" line.
+pub fn fit_to_height(mut frame: Vec<String>, height: usize) -> Vec<String> {
+    if frame.len() <= height {
+        return frame;
+    }
+    let kept = height.saturating_sub(1);
+    let hidden = frame.len() - kept;
+    frame.truncate(kept);
+    frame.push(format!("… {hidden} more lines"));
+    frame
+}
+
+/// Redraw `frame` over the previous one without clearing the whole screen
+/// first, which avoids flicker in slow terminals. The frame is cut to the
+/// screen height, since scrolling would offset every later redraw.
+fn draw(out: &mut impl Write, frame: Vec<String>) -> Result<()> {
+    let height = terminal::size().map_or(usize::MAX, |(_, rows)| rows as usize);
+    queue!(out, cursor::MoveTo(0, 0))?;
+    for (i, line) in fit_to_height(frame, height).iter().enumerate() {
+        // No newline after the last line, so a full screen doesn't scroll
+        if i > 0 {
+            queue!(out, Print("\r\n"))?;
+        }
+        queue!(out, Print(line), Clear(ClearType::UntilNewLine))?;
+    }
+    queue!(out, Clear(ClearType::FromCursorDown))?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Redraw the frame produced by `next_frame` every `interval` until Ctrl-C
+/// is pressed.
+pub async fn run(
+    interval: Duration,
+    mut next_frame: impl FnMut() -> Result<Vec<String>>,
+) -> Result<()> {
+    let mut stdout = io::stdout();
+    // Without wrapping, each line takes exactly one row
+    queue!(stdout, Clear(ClearType::All), cursor::Hide, DisableLineWrap)?;
+
+    // One listener for the whole loop so a Ctrl-C during a redraw isn't missed
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    let result = async {
+        loop {
+            draw(&mut stdout, next_frame()?)?;
+
+            tokio::select! {
+                _ = &mut ctrl_c => return Ok(()),
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+    }
+    .await;
+
+    // Always give the cursor back, even if a refresh failed
+    queue!(stdout, EnableLineWrap, cursor::Show, Print("\r\n"))?;
+    stdout.flush()?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Row {
+        id: u32,
+        cpu: f32,
+    }
+
+    fn row(id: u32, cpu: f32) -> Row {
+        Row { id, cpu }
+    }
+
+    fn tracker() -> ChangeTracker<u32, Row> {
+        ChangeTracker::new(|r| r.id, |before, after| after.cpu - before.cpu >= 20.0)
+    }
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("2"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_interval("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_interval("500ms"), Ok(Duration::from_millis(500)));
+        assert!(parse_interval("50ms").is_err());
+        assert!(parse_interval("soon").is_err());
+    }
+
+    #[test]
+    fn test_first_tick_has_no_changes() {
+        let mut tracker = tracker();
+        let rows = vec![row(1, 0.0), row(2, 5.0)];
+
+        let (changes, exited) = tracker.update(&rows, &rows);
+        assert_eq!(changes, vec![RowChange::Unchanged; 2]);
+        assert!(exited.is_empty());
+    }
+
+    #[test]
+    fn test_new_exited_and_changed_rows() {
+        let mut tracker = tracker();
+        let first = vec![row(1, 0.0), row(2, 5.0), row(3, 1.0)];
+        tracker.update(&first, &first);
+
+        let second = vec![row(1, 1.0), row(2, 50.0), row(4, 0.0)];
+        let (changes, exited) = tracker.update(&second, &second);
+
+        assert_eq!(
+            changes,
+            vec![RowChange::Unchanged, RowChange::Changed, RowChange::New]
+        );
+        assert_eq!(exited, vec![row(3, 1.0)]);
+    }
+
+    #[test]
+    fn test_rows_dropping_out_of_view_are_not_exited() {
+        let mut tracker = tracker();
+        let all = vec![row(1, 10.0), row(2, 5.0)];
+        tracker.update(&all, &all[..1]);
+
+        // Row 1 is still running, just no longer shown
+        let all = vec![row(1, 0.0), row(2, 30.0)];
+        let (_, exited) = tracker.update(&all, &all[1..]);
+        assert!(exited.is_empty());
+    }
+
+    #[test]
+    fn test_render_frame_without_highlight() {
+        let table = "top\nheader\nsep\nrow one\nbottom";
+        let frame = render_frame("Title", table, &[RowChange::New], false);
+        assert_eq!(
+            frame,
+            vec!["Title", "top", "header", "sep", "row one", "bottom"]
+        );
+    }
+
+    #[test]
+    fn test_render_frame_highlights_rows() {
+        let table = "top\nheader\nsep\nrow one\nrow two\nbottom";
+        let frame = render_frame(
+            "Title",
+            table,
+            &[RowChange::Unchanged, RowChange::New],
+            true,
+        );
+        // Title, legend, then the table
+        assert_eq!(frame.len(), 8);
+        assert_eq!(frame[5], "row one");
+        assert!(frame[6].contains("row two"));
+        assert_ne!(frame[6], "row two");
+    }
+
+    #[test]
+    fn test_fit_to_height() {
+        let frame: Vec<String> = (1..=5).map(|i| format!("line {i}")).collect();
+        assert_eq!(fit_to_height(frame.clone(), 5), frame);
+
+        let fitted = fit_to_height(frame, 3);
+        assert_eq!(fitted, vec!["line 1", "line 2", "… 3 more lines"]);
+    }
+}
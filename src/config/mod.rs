@@ -1 +1,4 @@
+pub mod paths;
+pub mod presets;
+pub mod project;
 pub mod settings;
@@ -0,0 +1,69 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+/// Environment variable selecting the config directory, like `--config`.
+pub const CONFIG_ENV: &str = "BOSSY_CONFIG";
+
+/// Where the config directory in use came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigDirSource {
+    Flag,
+    Env,
+    Default,
+}
+
+impl fmt::Display for ConfigDirSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigDirSource::Flag => f.write_str("--config"),
+            ConfigDirSource::Env => f.write_str(CONFIG_ENV),
+            ConfigDirSource::Default => f.write_str("default"),
+        }
+    }
+}
+
+/// Directory chosen with `--config` or `BOSSY_CONFIG`. Set once at startup.
+static CONFIG_DIR: RwLock<Option<(PathBuf, ConfigDirSource)>> = RwLock::new(None);
+
+/// Use the directory from the `--config` flag, falling back to
+/// `BOSSY_CONFIG`, instead of the platform config directory for
+/// `presets.toml`, `settings.toml` and `themes/`.
+pub fn init_config_dir(flag: Option<PathBuf>) {
+    let chosen = match flag {
+        Some(dir) => Some((dir, ConfigDirSource::Flag)),
+        None => std::env::var_os(CONFIG_ENV)
+            .filter(|value| !value.is_empty())
+            .map(|value| (PathBuf::from(value), ConfigDirSource::Env)),
+    };
+    if chosen.is_some() {
+        *CONFIG_DIR.write().unwrap_or_else(|e| e.into_inner()) = chosen;
+    }
+}
+
+/// The user config directory and where it came from: `~/.config/bossy-rust`
+/// on Linux, `~/Library/Application Support/bossy-rust` on macOS, unless
+/// overridden.
+pub fn config_dir_with_source() -> Option<(PathBuf, ConfigDirSource)> {
+    if let Some(chosen) = CONFIG_DIR.read().unwrap_or_else(|e| e.into_inner()).clone() {
+        return Some(chosen);
+    }
+    dirs::config_dir().map(|dir| (dir.join("bossy-rust"), ConfigDirSource::Default))
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    config_dir_with_source().map(|(dir, _)| dir)
+}
+
+/// Environment variable selecting the data directory (audit log).
+pub const DATA_ENV: &str = "BOSSY_DATA";
+
+/// Where bossy-rust keeps what it writes on its own, such as the audit log:
+/// `BOSSY_DATA` if set, else `~/.local/share/bossy-rust` on Linux and
+/// `~/Library/Application Support/bossy-rust` on macOS.
+pub fn data_dir() -> Option<PathBuf> {
+    match std::env::var_os(DATA_ENV).filter(|value| !value.is_empty()) {
+        Some(value) => Some(PathBuf::from(value)),
+        None => dirs::data_dir().map(|dir| dir.join("bossy-rust")),
+    }
+}
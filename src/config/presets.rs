@@ -0,0 +1,572 @@
+use crate::config::paths::config_dir;
+use crate::tui::keymap::Keymap;
+use anyhow::{anyhow, Context, Result};
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Defaults shipped with the binary; user and project presets are layered on top.
+pub const BUNDLED_PRESETS: &str = include_str!("../../config/presets.toml");
+
+/// Presets file looked up in the current directory.
+pub const PROJECT_PRESETS_FILE: &str = ".bossy-presets.toml";
+
+/// The merged presets: bundled defaults, then the user config directory,
+/// then the project-local file.
+#[derive(Debug, Clone, Default)]
+pub struct Presets {
+    /// Well-known service names and the port they usually listen on
+    pub common_ports: BTreeMap<String, u16>,
+    /// Named groups of process names, used by `cleanup --group`
+    pub development_processes: BTreeMap<String, Vec<String>>,
+    pub quick_actions: BTreeMap<String, QuickAction>,
+    pub search_patterns: BTreeMap<String, SearchPattern>,
+    pub ui_settings: UiSettings,
+    pub keyboard_shortcuts: KeyboardShortcuts,
+    pub protected_processes: ProtectedProcesses,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuickAction {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub processes: Vec<String>,
+    #[serde(default)]
+    pub ports: Vec<u16>,
+    #[serde(default = "default_true")]
+    pub confirmation_required: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SearchPattern {
+    pub name: String,
+    pub pattern: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiSettings {
+    pub refresh_interval_ms: u64,
+    pub max_process_display: usize,
+    pub max_port_display: usize,
+    pub show_process_tree: bool,
+    pub highlight_dev_ports: bool,
+    pub confirm_destructive_actions: bool,
+    pub colors: UiColors,
+}
+
+/// Colour names as accepted by ratatui, e.g. `blue`, `light-red` or `#ff8800`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiColors {
+    pub primary: String,
+    pub secondary: String,
+    pub warning: String,
+    pub error: String,
+    pub success: String,
+    pub highlight: String,
+}
+
+/// Processes that are never signalled without `--allow-protected`, in
+/// addition to PID 1, bossy-rust itself and its ancestors.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProtectedProcesses {
+    /// Exact process names
+    pub names: Vec<String>,
+    /// Executable path prefixes, e.g. `/System/`
+    pub paths: Vec<String>,
+    /// Owners, by login name or numeric UID
+    pub users: Vec<String>,
+}
+
+/// TUI key bindings by scope and action, e.g. `global.kill = ["x", "Delete"]`.
+/// Resolved against the defaults by `tui::Keymap::from_config`.
+pub type KeyboardShortcuts = BTreeMap<String, BTreeMap<String, Vec<String>>>;
+
+impl Default for UiSettings {
+    fn default() -> Self {
+        Self {
+            refresh_interval_ms: 2000,
+            max_process_display: 100,
+            max_port_display: 50,
+            show_process_tree: true,
+            highlight_dev_ports: true,
+            confirm_destructive_actions: true,
+            colors: UiColors::default(),
+        }
+    }
+}
+
+impl Default for UiColors {
+    fn default() -> Self {
+        Self {
+            primary: "blue".to_string(),
+            secondary: "cyan".to_string(),
+            warning: "yellow".to_string(),
+            error: "red".to_string(),
+            success: "green".to_string(),
+            highlight: "magenta".to_string(),
+        }
+    }
+}
+
+impl UiSettings {
+    pub fn refresh_interval(&self) -> Duration {
+        Duration::from_millis(self.refresh_interval_ms)
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// One presets file as written on disk. Every key is optional so a file only
+/// needs to contain what it overrides.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PresetLayer {
+    #[serde(default)]
+    common_ports: BTreeMap<String, u16>,
+    #[serde(default)]
+    development_processes: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    quick_actions: BTreeMap<String, QuickAction>,
+    #[serde(default)]
+    search_patterns: BTreeMap<String, SearchPattern>,
+    ui_settings: Option<UiSettingsLayer>,
+    #[serde(default)]
+    keyboard_shortcuts: BTreeMap<String, BTreeMap<String, KeyList>>,
+    protected_processes: Option<ProtectedProcessesLayer>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProtectedProcessesLayer {
+    names: Option<Vec<String>>,
+    paths: Option<Vec<String>>,
+    users: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UiSettingsLayer {
+    refresh_interval_ms: Option<u64>,
+    max_process_display: Option<usize>,
+    max_port_display: Option<usize>,
+    show_process_tree: Option<bool>,
+    highlight_dev_ports: Option<bool>,
+    confirm_destructive_actions: Option<bool>,
+    colors: Option<UiColorsLayer>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UiColorsLayer {
+    primary: Option<String>,
+    secondary: Option<String>,
+    warning: Option<String>,
+    error: Option<String>,
+    success: Option<String>,
+    highlight: Option<String>,
+}
+
+/// A single key or a list of keys.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            KeyList::One(key) => vec![key],
+            KeyList::Many(keys) => keys,
+        }
+    }
+}
+
+impl PresetLayer {
+    /// Parse and validate a presets file. Unknown keys are rejected so typos
+    /// don't silently fall back to the defaults.
+    pub fn parse(source: &str) -> Result<Self> {
+        let layer: PresetLayer = toml::from_str(source)?;
+        layer.validate()?;
+        Ok(layer)
+    }
+
+    fn validate(&self) -> Result<()> {
+        for (name, port) in &self.common_ports {
+            if *port == 0 {
+                return Err(anyhow!(
+                    "common_ports.{name}: port must be between 1 and 65535"
+                ));
+            }
+        }
+
+        for (name, processes) in &self.development_processes {
+            if processes.iter().any(|p| p.trim().is_empty()) {
+                return Err(anyhow!(
+                    "development_processes.{name}: process names must not be empty"
+                ));
+            }
+        }
+
+        for (key, action) in &self.quick_actions {
+            if action.processes.is_empty() && action.ports.is_empty() {
+                return Err(anyhow!(
+                    "quick_actions.{key}: needs at least one entry in `processes` or `ports`"
+                ));
+            }
+            if action.ports.contains(&0) {
+                return Err(anyhow!(
+                    "quick_actions.{key}.ports: port must be between 1 and 65535"
+                ));
+            }
+        }
+
+        for (key, pattern) in &self.search_patterns {
+            if pattern.pattern.trim().is_empty() {
+                return Err(anyhow!("search_patterns.{key}.pattern must not be empty"));
+            }
+        }
+
+        if let Some(ui) = &self.ui_settings {
+            if let Some(ms) = ui.refresh_interval_ms {
+                if ms < 100 {
+                    return Err(anyhow!(
+                        "ui_settings.refresh_interval_ms must be at least 100 (got {ms})"
+                    ));
+                }
+            }
+            for (key, value) in [
+                ("max_process_display", ui.max_process_display),
+                ("max_port_display", ui.max_port_display),
+            ] {
+                if value == Some(0) {
+                    return Err(anyhow!("ui_settings.{key} must be greater than 0"));
+                }
+            }
+            if let Some(colors) = &ui.colors {
+                for (key, value) in colors.entries() {
+                    if let Some(value) = value {
+                        Color::from_str(value).map_err(|_| {
+                            anyhow!("ui_settings.colors.{key}: unknown colour '{value}'")
+                        })?;
+                    }
+                }
+            }
+        }
+
+        if let Some(protected) = &self.protected_processes {
+            for (key, values) in [
+                ("names", &protected.names),
+                ("paths", &protected.paths),
+                ("users", &protected.users),
+            ] {
+                if values.iter().flatten().any(|v| v.trim().is_empty()) {
+                    return Err(anyhow!(
+                        "protected_processes.{key}: entries must not be empty"
+                    ));
+                }
+            }
+        }
+
+        for (scope, actions) in &self.keyboard_shortcuts {
+            for (action, keys) in actions {
+                let keys = match keys {
+                    KeyList::One(key) => std::slice::from_ref(key),
+                    KeyList::Many(keys) => keys.as_slice(),
+                };
+                // Conflicts depend on every layer, so they are checked once
+                // the layers are merged
+                Keymap::check_binding(scope, action, keys)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl UiColorsLayer {
+    fn entries(&self) -> [(&'static str, &Option<String>); 6] {
+        [
+            ("primary", &self.primary),
+            ("secondary", &self.secondary),
+            ("warning", &self.warning),
+            ("error", &self.error),
+            ("success", &self.success),
+            ("highlight", &self.highlight),
+        ]
+    }
+}
+
+fn overlay<T>(target: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *target = value;
+    }
+}
+
+impl Presets {
+    pub fn bundled() -> Self {
+        let mut presets = Self::default();
+        presets.apply(PresetLayer::parse(BUNDLED_PRESETS).expect("bundled presets.toml is valid"));
+        presets
+    }
+
+    /// Layer a presets file on top of these presets. Map entries with the
+    /// same name (port names, groups, actions, patterns) are replaced, new
+    /// ones are added, and only the settings present in `layer` change.
+    pub fn apply(&mut self, layer: PresetLayer) {
+        self.common_ports.extend(layer.common_ports);
+        self.development_processes
+            .extend(layer.development_processes);
+        self.quick_actions.extend(layer.quick_actions);
+        self.search_patterns.extend(layer.search_patterns);
+
+        if let Some(ui) = layer.ui_settings {
+            let settings = &mut self.ui_settings;
+            overlay(&mut settings.refresh_interval_ms, ui.refresh_interval_ms);
+            overlay(&mut settings.max_process_display, ui.max_process_display);
+            overlay(&mut settings.max_port_display, ui.max_port_display);
+            overlay(&mut settings.show_process_tree, ui.show_process_tree);
+            overlay(&mut settings.highlight_dev_ports, ui.highlight_dev_ports);
+            overlay(
+                &mut settings.confirm_destructive_actions,
+                ui.confirm_destructive_actions,
+            );
+            if let Some(colors) = ui.colors {
+                let target = &mut settings.colors;
+                overlay(&mut target.primary, colors.primary);
+                overlay(&mut target.secondary, colors.secondary);
+                overlay(&mut target.warning, colors.warning);
+                overlay(&mut target.error, colors.error);
+                overlay(&mut target.success, colors.success);
+                overlay(&mut target.highlight, colors.highlight);
+            }
+        }
+
+        // Each list replaces the one from the layers below, so entries can be
+        // removed as well as added
+        if let Some(protected) = layer.protected_processes {
+            let target = &mut self.protected_processes;
+            overlay(&mut target.names, protected.names);
+            overlay(&mut target.paths, protected.paths);
+            overlay(&mut target.users, protected.users);
+        }
+
+        // Each configured action replaces that action's keys
+        for (scope, actions) in layer.keyboard_shortcuts {
+            let target = self.keyboard_shortcuts.entry(scope).or_default();
+            for (action, keys) in actions {
+                target.insert(action, keys.into_vec());
+            }
+        }
+    }
+
+    pub fn group_names(&self) -> Vec<&str> {
+        self.development_processes
+            .keys()
+            .map(String::as_str)
+            .collect()
+    }
+
+    pub fn is_common_port(&self, port: u16) -> bool {
+        self.common_ports.values().any(|&p| p == port)
+    }
+
+    /// Preset names registered for `port`, e.g. `["express", "next_js", …]`.
+    pub fn port_names(&self, port: u16) -> Vec<&str> {
+        self.common_ports
+            .iter()
+            .filter(|(_, &p)| p == port)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// The first development group listing `process_name`, if any.
+    pub fn development_group(&self, process_name: &str) -> Option<&str> {
+        self.development_processes
+            .iter()
+            .find(|(_, names)| names.iter().any(|n| n == process_name))
+            .map(|(group, _)| group.as_str())
+    }
+}
+
+pub fn user_presets_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("presets.toml"))
+}
+
+pub fn project_presets_path() -> Option<PathBuf> {
+    std::env::current_dir()
+        .ok()
+        .map(|dir| dir.join(PROJECT_PRESETS_FILE))
+}
+
+/// Presets files that are layered over the bundled defaults, lowest
+/// precedence first. Files that don't exist are skipped when loading.
+pub fn preset_paths() -> Vec<PathBuf> {
+    user_presets_path()
+        .into_iter()
+        .chain(project_presets_path())
+        .collect()
+}
+
+fn load_layer(path: &Path) -> Result<PresetLayer> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    PresetLayer::parse(&source).with_context(|| format!("Invalid presets in {}", path.display()))
+}
+
+/// Bundled presets, overlaid with `presets.toml` from the user config
+/// directory and then `.bossy-presets.toml` from the current directory.
+pub fn load_presets() -> Result<Presets> {
+    let mut presets = Presets::bundled();
+
+    for path in preset_paths().iter().filter(|path| path.exists()) {
+        presets.apply(load_layer(path)?);
+    }
+
+    Keymap::from_config(&presets.keyboard_shortcuts).context("Invalid key bindings")?;
+    Ok(presets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layered(source: &str) -> Presets {
+        let mut presets = Presets::bundled();
+        presets.apply(PresetLayer::parse(source).unwrap());
+        presets
+    }
+
+    fn error(source: &str) -> String {
+        format!("{:#}", PresetLayer::parse(source).unwrap_err())
+    }
+
+    #[test]
+    fn test_bundled_presets() {
+        let presets = Presets::bundled();
+        assert_eq!(
+            presets.group_names(),
+            vec!["backend", "build", "databases", "frontend"]
+        );
+        assert!(presets.development_processes["frontend"].contains(&"vite".to_string()));
+        assert_eq!(presets.common_ports["postgresql"], 5432);
+        assert_eq!(presets.quick_actions["free_dev_ports"].ports.len(), 6);
+        assert_eq!(presets.search_patterns["high_cpu"].pattern, ">25%");
+        assert_eq!(presets.ui_settings, UiSettings::default());
+        assert!(presets
+            .protected_processes
+            .names
+            .contains(&"sshd".to_string()));
+        assert_eq!(
+            presets.keyboard_shortcuts["global"]["refresh"],
+            vec!["r", "R"]
+        );
+        assert!(Keymap::from_config(&presets.keyboard_shortcuts).is_ok());
+    }
+
+    #[test]
+    fn test_user_groups_override_and_extend() {
+        let presets = layered(
+            r#"
+            [development_processes]
+            databases = ["postgres"]
+            mobile = ["metro", "expo"]
+            "#,
+        );
+
+        assert_eq!(presets.development_processes["databases"], vec!["postgres"]);
+        assert_eq!(
+            presets.development_processes["mobile"],
+            vec!["metro", "expo"]
+        );
+        assert!(presets.development_processes.contains_key("frontend"));
+    }
+
+    #[test]
+    fn test_layers_only_override_present_settings() {
+        let mut presets = layered(
+            r#"
+            [ui_settings]
+            refresh_interval_ms = 500
+
+            [ui_settings.colors]
+            primary = "light-green"
+
+            [keyboard_shortcuts.global]
+            kill = "Ctrl+k"
+            "#,
+        );
+        presets.apply(PresetLayer::parse("[ui_settings]\nmax_port_display = 10\n").unwrap());
+        presets.apply(PresetLayer::parse("[protected_processes]\nusers = [\"root\"]\n").unwrap());
+
+        assert_eq!(
+            presets.ui_settings.refresh_interval(),
+            Duration::from_millis(500)
+        );
+        assert_eq!(presets.ui_settings.max_port_display, 10);
+        assert_eq!(presets.ui_settings.max_process_display, 100);
+        assert_eq!(presets.ui_settings.colors.primary, "light-green");
+        assert_eq!(presets.ui_settings.colors.error, "red");
+        assert_eq!(presets.keyboard_shortcuts["global"]["kill"], vec!["Ctrl+k"]);
+        assert_eq!(presets.protected_processes.users, vec!["root"]);
+        assert!(!presets.protected_processes.names.is_empty());
+        assert_eq!(
+            presets.keyboard_shortcuts["global"]["refresh"],
+            vec!["r", "R"]
+        );
+    }
+
+    #[test]
+    fn test_port_and_process_lookups() {
+        let presets = Presets::bundled();
+        assert!(presets.is_common_port(6379));
+        assert!(!presets.is_common_port(6380));
+        assert_eq!(presets.port_names(5432), vec!["postgresql"]);
+        assert_eq!(presets.development_group("mongod"), Some("databases"));
+        assert_eq!(presets.development_group("bash"), None);
+    }
+
+    #[test]
+    fn test_unknown_keys_are_rejected() {
+        assert!(error("[ui_settings]\nrefresh_interval = 500\n")
+            .contains("unknown field `refresh_interval`"));
+        assert!(error("[shortcuts]\nquick_kill = \"K\"\n").contains("unknown field `shortcuts`"));
+        assert!(error("[development_processes]\nfrontend = \"node\"\n").contains("invalid type"));
+    }
+
+    #[test]
+    fn test_bad_values_are_rejected() {
+        assert!(error("[common_ports]\nweb = 0\n").contains("common_ports.web"));
+        assert!(error("[common_ports]\nweb = 70000\n").contains("70000"));
+        assert!(error("[ui_settings]\nrefresh_interval_ms = 10\n").contains("at least 100"));
+        assert!(error("[ui_settings]\nmax_port_display = 0\n").contains("max_port_display"));
+        assert!(error("[ui_settings.colors]\nprimary = \"blurple\"\n").contains("'blurple'"));
+        assert!(error("[keyboard_shortcuts.global]\nkil = \"x\"\n")
+            .contains("keyboard_shortcuts.global.kil: unknown action"));
+        assert!(error("[keyboard_shortcuts.theme_view]\nquit = \"q\"\n").contains("unknown scope"));
+        assert!(error("[keyboard_shortcuts.global]\nhelp = \"F13\"\n").contains("'F13'"));
+        assert!(
+            error("[quick_actions.noop]\nname = \"Noop\"\ndescription = \"\"\n")
+                .contains("quick_actions.noop")
+        );
+        assert!(
+            error("[protected_processes]\nnames = [\"\"]\n").contains("protected_processes.names")
+        );
+        assert!(
+            error("[search_patterns.empty]\nname = \"Empty\"\npattern = \"\"\n")
+                .contains("search_patterns.empty")
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use crate::process::{MatchMode, ProcessMatcher};
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Project profile looked up in the current directory and its parents.
+pub const PROJECT_FILE: &str = ".bossy.toml";
+
+/// A service the project expects to run: something listening on `port`,
+/// a process matching `process`, or both.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Service {
+    pub port: Option<u16>,
+    pub process: Option<String>,
+    /// How `process` is matched, as in `kill-process --match`
+    #[serde(rename = "match", default)]
+    pub match_mode: MatchMode,
+    /// Count matching processes started outside the project directory too,
+    /// e.g. a database shared between projects
+    #[serde(default)]
+    pub anywhere: bool,
+}
+
+impl Service {
+    /// Matcher for `process`, if the service declares one.
+    pub fn matcher(&self) -> Option<ProcessMatcher> {
+        let pattern = self.process.as_deref()?;
+        ProcessMatcher::new(pattern, self.match_mode).ok()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProjectFile {
+    name: Option<String>,
+    #[serde(default)]
+    services: BTreeMap<String, Service>,
+}
+
+/// A parsed `.bossy.toml` and the directory it was found in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Project {
+    pub name: String,
+    /// Directory containing the profile; processes working under it belong
+    /// to the project
+    pub root: PathBuf,
+    pub services: BTreeMap<String, Service>,
+}
+
+impl Project {
+    /// Parse a profile for the project rooted at `root`. The name defaults
+    /// to the directory name.
+    pub fn parse(source: &str, root: &Path) -> Result<Self> {
+        let file: ProjectFile = toml::from_str(source)?;
+
+        if file.services.is_empty() {
+            return Err(anyhow!("services: declare at least one service"));
+        }
+        for (key, service) in &file.services {
+            if service.port.is_none() && service.process.is_none() {
+                return Err(anyhow!(
+                    "services.{key}: needs a `port`, a `process` or both"
+                ));
+            }
+            if service.port == Some(0) {
+                return Err(anyhow!(
+                    "services.{key}.port: port must be between 1 and 65535"
+                ));
+            }
+            if let Some(pattern) = &service.process {
+                ProcessMatcher::new(pattern, service.match_mode)
+                    .with_context(|| format!("services.{key}.process"))?;
+            }
+        }
+
+        let name = file.name.unwrap_or_else(|| {
+            root.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "project".to_string())
+        });
+
+        Ok(Self {
+            name,
+            root: root.to_path_buf(),
+            services: file.services,
+        })
+    }
+
+    /// Ports declared by the services, sorted and deduplicated.
+    pub fn ports(&self) -> Vec<u16> {
+        let mut ports: Vec<u16> = self.services.values().filter_map(|s| s.port).collect();
+        ports.sort_unstable();
+        ports.dedup();
+        ports
+    }
+
+    /// Whether a process working in `cwd` was started inside the project.
+    pub fn contains(&self, cwd: Option<&str>) -> bool {
+        cwd.is_some_and(|cwd| Path::new(cwd).starts_with(&self.root))
+    }
+}
+
+/// The nearest `.bossy.toml` in `start` or one of its parents.
+pub fn find_project_file(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(PROJECT_FILE))
+        .find(|path| path.is_file())
+}
+
+/// Load the project profile governing `start`, if there is one.
+pub fn load_project(start: &Path) -> Result<Option<Project>> {
+    let Some(path) = find_project_file(start) else {
+        return Ok(None);
+    };
+
+    let source =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let root = path.parent().unwrap_or(start);
+    // Process working directories are reported with symlinks resolved
+    let root = fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+    Project::parse(&source, &root)
+        .with_context(|| format!("Invalid project profile in {}", path.display()))
+        .map(Some)
+}
+
+/// The project profile for the current directory, or an error explaining
+/// that none was found.
+pub fn current_project() -> Result<Project> {
+    let dir = std::env::current_dir()?;
+    load_project(&dir)?.ok_or_else(|| {
+        anyhow!(
+            "No {PROJECT_FILE} found in {} or its parents",
+            dir.display()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILE: &str = r#"
+name = "shop"
+
+[services.web]
+port = 3000
+process = "node"
+
+[services.api]
+port = 8080
+
+[services.worker]
+process = "sidekiq"
+match = "cmdline"
+
+[services.postgres]
+port = 5432
+process = "postgres"
+anywhere = true
+"#;
+
+    fn error(source: &str) -> String {
+        format!(
+            "{:#}",
+            Project::parse(source, Path::new("/work/shop")).unwrap_err()
+        )
+    }
+
+    #[test]
+    fn test_parse_profile() {
+        let project = Project::parse(PROFILE, Path::new("/work/shop")).unwrap();
+        assert_eq!(project.name, "shop");
+        assert_eq!(project.ports(), vec![3000, 5432, 8080]);
+        assert_eq!(project.services["worker"].match_mode, MatchMode::Cmdline);
+        assert!(project.services["postgres"].anywhere);
+        assert!(!project.services["web"].anywhere);
+
+        assert!(project.contains(Some("/work/shop/frontend")));
+        assert!(!project.contains(Some("/work/shopping")));
+        assert!(!project.contains(None));
+
+        let unnamed = Project::parse("[services.web]\nport = 3000\n", Path::new("/work/blog"));
+        assert_eq!(unnamed.unwrap().name, "blog");
+    }
+
+    #[test]
+    fn test_invalid_profiles() {
+        assert!(error("name = \"x\"\n").contains("declare at least one service"));
+        assert!(error("[services.web]\nmatch = \"regex\"\n").contains("needs a `port`"));
+        assert!(error("[services.web]\nport = 0\n").contains("services.web.port"));
+        assert!(
+            error("[services.web]\nprocess = \"(\"\nmatch = \"regex\"\n")
+                .contains("services.web.process: Invalid pattern")
+        );
+        assert!(error("[services.web]\nport = 3000\nhost = \"x\"\n").contains("unknown field"));
+    }
+
+    #[test]
+    fn test_find_project_file_walks_up() {
+        let root = std::env::temp_dir().join(format!("bossy-project-{}", std::process::id()));
+        let nested = root.join("packages").join("web");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join(PROJECT_FILE), "[services.web]\nport = 3000\n").unwrap();
+
+        let project = load_project(&nested).unwrap().unwrap();
+        assert_eq!(project.root, fs::canonicalize(&root).unwrap());
+        assert_eq!(project.ports(), vec![3000]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,42 +1,380 @@
-use anyhow::Result;
+use crate::commands::ProcessColumn;
+use crate::config::paths::config_dir;
+use crate::config::presets::SearchPattern;
+use crate::tui::{SortBy, SortOrder};
+use anyhow::{anyhow, Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Preferences edited from the TUI settings screen. Every field has a
+/// default so settings files written by older versions keep loading.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
 pub struct UserSettings {
     pub theme_name: String,
+    /// Overrides `ui_settings.refresh_interval_ms` from the presets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_interval_ms: Option<u64>,
+    pub auto_refresh: bool,
+    pub start_view: StartView,
+    pub process_sort: ViewSort,
+    pub port_sort: ViewSort,
+    pub connection_sort: ViewSort,
+    /// Columns shown in the process view, in order
+    pub process_columns: Vec<ProcessColumn>,
+    pub confirmations: ConfirmationLevel,
+    pub memory_units: MemoryUnits,
+    /// Searches saved from the TUI, shown next to the preset patterns
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub search_patterns: BTreeMap<String, SearchPattern>,
 }
 
 impl Default for UserSettings {
     fn default() -> Self {
         Self {
             theme_name: "Kanagawa".to_string(),
+            refresh_interval_ms: None,
+            auto_refresh: true,
+            start_view: StartView::Dashboard,
+            process_sort: ViewSort::new(SortBy::Cpu, SortOrder::Descending),
+            port_sort: ViewSort::new(SortBy::Port, SortOrder::Ascending),
+            connection_sort: ViewSort::new(SortBy::LocalAddress, SortOrder::Ascending),
+            process_columns: ProcessColumn::DEFAULT.to_vec(),
+            confirmations: ConfirmationLevel::Normal,
+            memory_units: MemoryUnits::Binary,
+            search_patterns: BTreeMap::new(),
         }
     }
 }
 
+impl UserSettings {
+    /// Parse and validate a `settings.toml`.
+    pub fn parse(source: &str) -> Result<Self> {
+        let settings: Self = toml::from_str(source)?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Reject values that parse but can't be used, with the same limits as
+    /// the presets and `--watch`.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(ms) = self.refresh_interval_ms {
+            if ms < 100 {
+                return Err(anyhow!(
+                    "refresh_interval_ms must be at least 100 (got {ms})"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// View the TUI opens in.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StartView {
+    Dashboard,
+    Processes,
+    Ports,
+    Connections,
+}
+
+impl StartView {
+    pub const ALL: [StartView; 4] = [
+        StartView::Dashboard,
+        StartView::Processes,
+        StartView::Ports,
+        StartView::Connections,
+    ];
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ViewSort {
+    pub by: SortBy,
+    pub order: SortOrder,
+}
+
+impl ViewSort {
+    pub fn new(by: SortBy, order: SortOrder) -> Self {
+        Self { by, order }
+    }
+}
+
+/// How much confirmation kills in the TUI ask for.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmationLevel {
+    /// Every kill must be confirmed by typing YES
+    Strict,
+    /// y/Enter confirms; critical operations need YES
+    Normal,
+    /// Low and medium risk kills run without a dialog
+    Relaxed,
+}
+
+impl ConfirmationLevel {
+    pub const ALL: [ConfirmationLevel; 3] = [
+        ConfirmationLevel::Strict,
+        ConfirmationLevel::Normal,
+        ConfirmationLevel::Relaxed,
+    ];
+}
+
+/// How memory sizes are written in the TUI.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryUnits {
+    /// Powers of 1024 with short suffixes: `512MB`, `2.0GB`
+    Binary,
+    /// Powers of 1024 with IEC suffixes: `512MiB`, `2.0GiB`
+    Iec,
+    /// Powers of 1000: `537MB`, `2.1GB`
+    Decimal,
+}
+
+impl MemoryUnits {
+    pub const ALL: [MemoryUnits; 3] = [MemoryUnits::Binary, MemoryUnits::Iec, MemoryUnits::Decimal];
+
+    pub fn format(self, bytes: u64) -> String {
+        let (base, suffixes) = match self {
+            MemoryUnits::Binary => (1024, ["KB", "MB", "GB"]),
+            MemoryUnits::Iec => (1024, ["KiB", "MiB", "GiB"]),
+            MemoryUnits::Decimal => (1000, ["kB", "MB", "GB"]),
+        };
+        let kb = bytes / base;
+        let mb = kb / base;
+
+        if mb >= base {
+            format!("{:.1}{}", mb as f64 / base as f64, suffixes[2])
+        } else if mb > 0 {
+            format!("{mb}{}", suffixes[1])
+        } else {
+            format!("{kb}{}", suffixes[0])
+        }
+    }
+}
+
+/// Settings that can be overridden for a single run with `BOSSY_<KEY>`,
+/// e.g. `BOSSY_THEME_NAME=Nord` or `BOSSY_REFRESH_INTERVAL_MS=500`.
+pub const ENV_KEYS: [&str; 6] = [
+    "theme_name",
+    "refresh_interval_ms",
+    "auto_refresh",
+    "start_view",
+    "confirmations",
+    "memory_units",
+];
+
+/// Environment variable overriding the setting `key`.
+pub fn env_var(key: &str) -> String {
+    format!("BOSSY_{}", key.to_uppercase())
+}
+
+/// A setting taken from the environment instead of `settings.toml`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvOverride {
+    pub key: &'static str,
+    pub var: String,
+    pub value: String,
+}
+
+/// Apply the `BOSSY_<KEY>` variables found by `lookup` to `settings`.
+/// Values are read as TOML (`true`, `500`), falling back to a plain string
+/// (`Nord`, `relaxed`).
+pub fn apply_env_overrides_from(
+    settings: &mut UserSettings,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<Vec<EnvOverride>> {
+    let mut table = toml::Table::try_from(&*settings)?;
+    let mut overrides = Vec::new();
+
+    for key in ENV_KEYS {
+        let var = env_var(key);
+        let Some(value) = lookup(&var) else {
+            continue;
+        };
+        let parsed = toml::from_str::<toml::Table>(&format!("v = {value}"))
+            .ok()
+            .and_then(|mut t| t.remove("v"))
+            .unwrap_or_else(|| toml::Value::String(value.clone()));
+        table.insert(key.to_string(), parsed);
+
+        // Check each variable on its own so the error names it
+        toml::Value::Table(table.clone())
+            .try_into::<UserSettings>()
+            .map_err(|e| {
+                anyhow!(
+                    "{var}: invalid value '{value}' for `{key}` ({})",
+                    e.message()
+                )
+            })?
+            .validate()
+            .map_err(|e| anyhow!("{var}: invalid value '{value}' for `{key}` ({e})"))?;
+        overrides.push(EnvOverride { key, var, value });
+    }
+
+    *settings = toml::Value::Table(table).try_into()?;
+    Ok(overrides)
+}
+
+pub fn apply_env_overrides(settings: &mut UserSettings) -> Result<Vec<EnvOverride>> {
+    apply_env_overrides_from(settings, |var| std::env::var(var).ok())
+}
+
+/// Top-level keys in a settings file that no setting reads. They are
+/// ignored when loading, so a typo silently keeps the default.
+pub fn unknown_keys(source: &str) -> Vec<String> {
+    let Ok(table) = source.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+    let known = toml::Table::try_from(UserSettings::default()).unwrap_or_default();
+    // Left out of the serialized defaults when unset
+    let optional = ["refresh_interval_ms", "search_patterns"];
+
+    table
+        .keys()
+        .filter(|key| !known.contains_key(*key) && !optional.contains(&key.as_str()))
+        .cloned()
+        .collect()
+}
+
+pub fn settings_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("settings.toml"))
+}
+
 fn get_config_path() -> Result<PathBuf> {
-    let config_dir =
-        dirs::config_dir().ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?;
-    let app_config_dir = config_dir.join("bossy-rust");
-    fs::create_dir_all(&app_config_dir)?;
-    Ok(app_config_dir.join("settings.toml"))
+    let path = settings_path().ok_or_else(|| anyhow!("Could not find config directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    Ok(path)
 }
 
+/// Save `settings`, keeping the file's own value for anything currently
+/// overridden from the environment.
 pub fn save_settings(settings: &UserSettings) -> Result<()> {
     let path = get_config_path()?;
-    let toml_string = toml::to_string(settings)?;
-    fs::write(path, toml_string)?;
+    let mut table = toml::Table::try_from(settings)?;
+
+    let overridden: Vec<&str> = ENV_KEYS
+        .into_iter()
+        .filter(|key| std::env::var_os(env_var(key)).is_some())
+        .collect();
+    if !overridden.is_empty() {
+        let on_disk = fs::read_to_string(&path)
+            .ok()
+            .and_then(|source| source.parse::<toml::Table>().ok())
+            .unwrap_or_default();
+        for key in overridden {
+            match on_disk.get(key) {
+                Some(value) => table.insert(key.to_string(), value.clone()),
+                None => table.remove(key),
+            };
+        }
+    }
+
+    fs::write(path, toml::to_string(&table)?)?;
     Ok(())
 }
 
+/// Settings from `settings.toml`, or the defaults when there is none. A file
+/// that doesn't parse is an error naming the file.
 pub fn load_settings() -> Result<UserSettings> {
-    let path = get_config_path()?;
+    let path = settings_path().ok_or_else(|| anyhow!("Could not find config directory"))?;
     if !path.exists() {
         return Ok(UserSettings::default());
     }
-    let toml_string = fs::read_to_string(path)?;
-    let settings: UserSettings = toml::from_str(&toml_string)?;
-    Ok(settings)
+    let toml_string = fs::read_to_string(&path)?;
+    UserSettings::parse(&toml_string)
+        .with_context(|| format!("Invalid settings in {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_old_settings_files_still_load() {
+        let settings: UserSettings = toml::from_str("theme_name = \"Nord\"\n").unwrap();
+        assert_eq!(settings.theme_name, "Nord");
+        assert!(settings.auto_refresh);
+        assert_eq!(settings.process_columns, ProcessColumn::DEFAULT.to_vec());
+        assert_eq!(settings.port_sort.by, SortBy::Port);
+    }
+
+    #[test]
+    fn test_settings_round_trip() {
+        let settings = UserSettings {
+            refresh_interval_ms: Some(750),
+            start_view: StartView::Ports,
+            process_sort: ViewSort::new(SortBy::Memory, SortOrder::Ascending),
+            process_columns: vec![ProcessColumn::Pid, ProcessColumn::Command],
+            confirmations: ConfirmationLevel::Strict,
+            memory_units: MemoryUnits::Iec,
+            ..UserSettings::default()
+        };
+
+        let source = toml::to_string(&settings).unwrap();
+        assert!(source.contains("start_view = \"ports\""));
+        assert!(source.contains("process_columns = [\"pid\", \"command\"]"));
+        assert_eq!(toml::from_str::<UserSettings>(&source).unwrap(), settings);
+    }
+
+    #[test]
+    fn test_memory_units() {
+        let bytes = 2 * 1024 * 1024 * 1024;
+        assert_eq!(MemoryUnits::Binary.format(bytes), "2.0GB");
+        assert_eq!(MemoryUnits::Iec.format(bytes), "2.0GiB");
+        assert_eq!(MemoryUnits::Decimal.format(bytes), "2.1GB");
+        assert_eq!(MemoryUnits::Binary.format(100 * 1024 * 1024), "100MB");
+        assert_eq!(MemoryUnits::Decimal.format(500_000), "500kB");
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let env = |var: &str| match var {
+            "BOSSY_THEME_NAME" => Some("Nord".to_string()),
+            "BOSSY_REFRESH_INTERVAL_MS" => Some("500".to_string()),
+            "BOSSY_AUTO_REFRESH" => Some("false".to_string()),
+            "BOSSY_CONFIRMATIONS" => Some("relaxed".to_string()),
+            _ => None,
+        };
+        let mut settings = UserSettings::default();
+        let overrides = apply_env_overrides_from(&mut settings, env).unwrap();
+
+        assert_eq!(overrides.len(), 4);
+        assert_eq!(settings.theme_name, "Nord");
+        assert_eq!(settings.refresh_interval_ms, Some(500));
+        assert!(!settings.auto_refresh);
+        assert_eq!(settings.confirmations, ConfirmationLevel::Relaxed);
+
+        let bad = |var: &str| (var == "BOSSY_START_VIEW").then(|| "sideways".to_string());
+        let error = apply_env_overrides_from(&mut UserSettings::default(), bad).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("BOSSY_START_VIEW: invalid value 'sideways' for `start_view`"));
+
+        // Parses as a number, but would refresh nonstop
+        let zero = |var: &str| (var == "BOSSY_REFRESH_INTERVAL_MS").then(|| "0".to_string());
+        let error = apply_env_overrides_from(&mut UserSettings::default(), zero).unwrap_err();
+        assert!(error.to_string().contains("must be at least 100 (got 0)"));
+    }
+
+    #[test]
+    fn test_refresh_interval_is_validated() {
+        assert!(UserSettings::parse("refresh_interval_ms = 100\n").is_ok());
+        let error = UserSettings::parse("refresh_interval_ms = 0\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "refresh_interval_ms must be at least 100 (got 0)"
+        );
+    }
+
+    #[test]
+    fn test_unknown_keys() {
+        let source = "theme = \"Nord\"\nrefresh_interval_ms = 500\nauto_refresh = false\n";
+        assert_eq!(unknown_keys(source), vec!["theme".to_string()]);
+    }
 }
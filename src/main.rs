@@ -9,13 +9,22 @@ mod testing;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use commands::watch::parse_interval;
+use commands::{
+    parse_signal, ActionOptions, CleanupOptions, CliStatus, KillProcessOptions, OutputFormat,
+    OutputOptions, ProcessColumn, ProjectOptions, PsOptions, SignalOptions,
+};
 use crossterm::{
+    event::{
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use process::{Initiator, MatchMode, ProtectionPolicy, Signal};
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::{io, time::Duration};
-use tokio::time::sleep;
+use std::{io, path::PathBuf, process::ExitCode, time::Duration};
 use tui::{AppEvent, AppState, EventHandler};
 
 #[derive(Parser)]
@@ -23,9 +32,30 @@ use tui::{AppEvent, AppState, EventHandler};
 #[command(about = "A lightweight Terminal User Interface (TUI) process manager for macOS")]
 #[command(version = "0.1.0")]
 #[command(author = "Pedro Nieto")]
+#[command(
+    after_help = "Exit status:\n  0  success\n  1  general error\n  2  invalid usage\n  3  no matching process, port or free port\n  4  partial failure (some targets failed)\n  5  permission denied\n  6  timed out waiting for a process to exit\n  7  every target was protected and skipped"
+)]
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+
+    /// Output format for CLI commands
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+
+    /// Omit the header row in csv/tsv output
+    #[arg(long, global = true)]
+    no_header: bool,
+
+    /// Allow killing processes on the protected list (system services,
+    /// PID 1, bossy-rust itself and the shell it runs in)
+    #[arg(long, global = true)]
+    allow_protected: bool,
+
+    /// Read presets.toml, settings.toml and themes/ from DIR instead of the
+    /// user config directory (also BOSSY_CONFIG)
+    #[arg(long, global = true, value_name = "DIR")]
+    config: Option<PathBuf>,
 }
 
 #[derive(Subcommand)]
@@ -42,6 +72,12 @@ enum Commands {
         /// Show only listening ports
         #[arg(long)]
         listening: bool,
+        /// Redraw every INTERVAL (e.g. 2, 1.5s, 500ms) until Ctrl-C
+        #[arg(long, value_name = "INTERVAL", value_parser = parse_interval)]
+        watch: Option<Duration>,
+        /// Highlight new, changed and closed rows while watching
+        #[arg(long, requires = "watch")]
+        highlight: bool,
     },
     /// Kill processes by name
     KillProcess {
@@ -49,24 +85,133 @@ enum Commands {
         /// Force kill (SIGKILL instead of SIGTERM)
         #[arg(short, long)]
         force: bool,
+        /// How NAME is matched against processes
+        #[arg(long = "match", value_enum, default_value_t = MatchMode::Exact)]
+        mode: MatchMode,
+        /// Match processes of all users, not only your own
+        #[arg(long)]
+        all_users: bool,
+        /// List matching processes without signalling them
+        #[arg(long, conflicts_with = "interactive")]
+        dry_run: bool,
+        /// Preview matching processes and ask before killing
+        #[arg(short, long)]
+        interactive: bool,
+    },
+    /// Send a signal (STOP, CONT, HUP, USR1, ...) to a process by PID or name
+    Signal {
+        /// PID, or process name matched with --match
+        target: String,
+        /// Signal name (STOP, SIGSTOP, stop, WINCH, ...) or one of the numbers 1, 2, 3, 9, 15
+        #[arg(value_parser = parse_signal)]
+        signal: Signal,
+        /// How a name TARGET is matched against processes
+        #[arg(long = "match", value_enum, default_value_t = MatchMode::Exact)]
+        mode: MatchMode,
+        /// Match processes of all users, not only your own
+        #[arg(long)]
+        all_users: bool,
+    },
+    /// Change a process's priority (nice value)
+    Renice {
+        /// Process ID
+        pid: u32,
+        /// Nice value from -20 (highest priority) to 19 (lowest); going below
+        /// the current value needs root
+        #[arg(allow_negative_numbers = true, value_parser = clap::value_parser!(i32).range(-20..=19))]
+        value: i32,
+    },
+    /// Show the signals bossy-rust has sent, from the audit log
+    History {
+        /// Show the most recent N entries (0 for all)
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+        /// Only entries whose process, command line, action or rule contains
+        /// this text, or whose PID is this number
+        #[arg(short, long)]
+        search: Option<String>,
     },
     /// Show processes with optional filtering
     Ps {
         /// Show top CPU consumers
-        #[arg(long)]
+        #[arg(long, conflicts_with = "top_memory")]
         top_cpu: bool,
         /// Show top memory consumers
         #[arg(long)]
         top_memory: bool,
-        /// Limit number of results
+        /// Limit number of results (0 for no limit)
         #[arg(short, long, default_value = "10")]
         limit: usize,
+        /// Only show processes matching a search query (e.g. "node", "#1234", ">50%", ">1GB")
+        #[arg(long)]
+        filter: Option<String>,
+        /// Sort by a column (ascending unless --reverse)
+        #[arg(long, value_enum, conflicts_with_all = ["top_cpu", "top_memory"])]
+        sort: Option<ProcessColumn>,
+        /// Reverse the sort order
+        #[arg(long)]
+        reverse: bool,
+        /// Comma-separated columns to display
+        #[arg(long, value_enum, value_delimiter = ',')]
+        columns: Vec<ProcessColumn>,
+        /// Only show processes owned by this user name or UID
+        #[arg(long)]
+        user: Option<String>,
+        /// Only show descendants of this PID
+        #[arg(long, value_name = "PID")]
+        children_of: Option<u32>,
+        /// Redraw every INTERVAL (e.g. 2, 1.5s, 500ms) until Ctrl-C
+        #[arg(long, value_name = "INTERVAL", value_parser = parse_interval)]
+        watch: Option<Duration>,
+        /// Highlight new, exited and CPU-jumping rows while watching
+        #[arg(long, requires = "watch")]
+        highlight: bool,
     },
     /// Cleanup common development processes
     Cleanup {
-        /// Target development processes
+        /// Preset process groups to clean up (comma-separated)
+        #[arg(long, value_delimiter = ',', value_name = "NAMES")]
+        group: Vec<String>,
+        /// Clean up every preset group
+        #[arg(long, visible_alias = "dev", conflicts_with = "group")]
+        all_groups: bool,
+        /// List matching processes per group without signalling them
+        #[arg(long)]
+        dry_run: bool,
+        /// Don't ask for confirmation before each group
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Run a quick action from the presets (lists them when NAME is omitted)
+    Action {
+        /// Quick action to run, e.g. kill_dev_servers
+        name: Option<String>,
+        /// Show the affected processes and ports without signalling them
+        #[arg(long)]
+        dry_run: bool,
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Show which services from the project's .bossy.toml are running
+    Status,
+    /// Stop this project's services (as declared in .bossy.toml)
+    Stop {
+        /// Show the affected processes without signalling them
+        #[arg(long)]
+        dry_run: bool,
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Free the project's ports, whatever is holding them
+    Free {
+        /// Show the affected processes and ports without signalling them
         #[arg(long)]
-        dev: bool,
+        dry_run: bool,
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
     },
     /// Find available port in range
     FindPort {
@@ -76,107 +221,254 @@ enum Commands {
         /// End port (default: start + 100)
         end: Option<u16>,
     },
+    /// Inspect and validate the configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Validate every config file, reporting errors with file and line
+    Check,
+    /// Print the effective configuration and the source of each value
+    Show,
+    /// Print where config files are looked up
+    Path,
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> Result<ExitCode> {
     let cli = Cli::parse();
+    config::paths::init_config_dir(cli.config);
+    let output = OutputOptions::new(cli.format, cli.no_header);
 
     match cli.command {
+        Some(Commands::Config { command }) => {
+            // Runs before the presets are loaded so broken files can be diagnosed
+            let status = handle_config_command(command, &output)?;
+            return Ok(ExitCode::from(status.code()));
+        }
         Some(command) => {
             // Handle CLI commands
-            handle_cli_command(command).await?;
+            process::set_protection_policy(ProtectionPolicy {
+                rules: config::presets::load_presets()?.protected_processes,
+                allow_protected: cli.allow_protected,
+            });
+            process::init_audit_log(Initiator::Cli);
+            let status = handle_cli_command(command, &output).await?;
+            return Ok(ExitCode::from(status.code()));
         }
         None => {
             // Launch interactive TUI
-            run_tui().await?;
+            run_tui(cli.allow_protected).await?;
         }
     }
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
 }
 
-async fn handle_cli_command(command: Commands) -> Result<()> {
+async fn handle_cli_command(command: Commands, output: &OutputOptions) -> Result<CliStatus> {
     use commands::CliHandler;
 
-    match command {
+    let status = match command {
         Commands::Port { port } => {
-            CliHandler::show_port_info(port).await?;
+            CliHandler::show_port_info(port, output).await?;
+            CliStatus::Success
+        }
+        Commands::KillPort { port } => CliHandler::kill_port(port, output).await?,
+        Commands::Ports {
+            common,
+            listening,
+            watch,
+            highlight,
+        } => {
+            match watch {
+                Some(interval) => {
+                    ensure_table_output(output)?;
+                    CliHandler::watch_ports(common, listening, interval, highlight).await?
+                }
+                None => CliHandler::show_ports(common, listening, output).await?,
+            }
+            CliStatus::Success
         }
-        Commands::KillPort { port } => {
-            CliHandler::kill_port(port).await?;
+        Commands::KillProcess {
+            name,
+            force,
+            mode,
+            all_users,
+            dry_run,
+            interactive,
+        } => {
+            let options = KillProcessOptions {
+                pattern: name,
+                mode,
+                force,
+                all_users,
+                dry_run,
+                interactive,
+            };
+            CliHandler::kill_process(&options, output).await?
         }
-        Commands::Ports { common, listening } => {
-            CliHandler::show_ports(common, listening).await?;
+        Commands::Signal {
+            target,
+            signal,
+            mode,
+            all_users,
+        } => {
+            let options = SignalOptions {
+                target,
+                signal,
+                mode,
+                all_users,
+            };
+            CliHandler::send_signal(&options, output).await?
         }
-        Commands::KillProcess { name, force } => {
-            CliHandler::kill_process(&name, force).await?;
+        Commands::Renice { pid, value } => CliHandler::renice(pid, value, output).await?,
+        Commands::History { limit, search } => {
+            CliHandler::show_history(limit, search.as_deref(), output)?
         }
         Commands::Ps {
             top_cpu,
             top_memory,
             limit,
+            filter,
+            sort,
+            reverse,
+            columns,
+            user,
+            children_of,
+            watch,
+            highlight,
         } => {
-            CliHandler::show_processes(top_cpu, top_memory, limit).await?;
+            let options = PsOptions {
+                top_cpu,
+                top_memory,
+                limit,
+                filter,
+                sort,
+                reverse,
+                columns,
+                user,
+                children_of,
+            };
+            match watch {
+                Some(interval) => {
+                    ensure_table_output(output)?;
+                    CliHandler::watch_processes(&options, interval, highlight).await?
+                }
+                None => CliHandler::show_processes(&options, output).await?,
+            }
+            CliStatus::Success
+        }
+        Commands::Cleanup {
+            group,
+            all_groups,
+            dry_run,
+            yes,
+        } => {
+            let options = CleanupOptions {
+                groups: group,
+                all_groups,
+                dry_run,
+                yes,
+            };
+            CliHandler::cleanup_processes(&options, output).await?
         }
-        Commands::Cleanup { dev } => {
-            CliHandler::cleanup_processes(dev).await?;
+        Commands::Action { name, dry_run, yes } => {
+            let options = ActionOptions { name, dry_run, yes };
+            CliHandler::run_action(&options, output).await?
+        }
+        Commands::Status => CliHandler::project_status(output)?,
+        Commands::Stop { dry_run, yes } => {
+            CliHandler::project_stop(&ProjectOptions { dry_run, yes }, output).await?
+        }
+        Commands::Free { dry_run, yes } => {
+            CliHandler::project_free(&ProjectOptions { dry_run, yes }, output).await?
         }
         Commands::FindPort { start, end } => {
-            let end = end.unwrap_or(start + 100);
-            CliHandler::find_available_port(start, end).await?;
+            let end = end.unwrap_or(start.saturating_add(100));
+            CliHandler::find_available_port(start, end, output).await?
         }
+        Commands::Config { command } => handle_config_command(command, output)?,
+    };
+
+    Ok(status)
+}
+
+fn handle_config_command(command: ConfigCommand, output: &OutputOptions) -> Result<CliStatus> {
+    use commands::CliHandler;
+
+    match command {
+        ConfigCommand::Check => CliHandler::config_check(output),
+        ConfigCommand::Show => CliHandler::config_show(output),
+        ConfigCommand::Path => CliHandler::config_path(output),
     }
+}
 
+fn ensure_table_output(output: &OutputOptions) -> Result<()> {
+    if !output.is_table() {
+        anyhow::bail!("--watch only supports table output");
+    }
     Ok(())
 }
 
-async fn run_tui() -> Result<()> {
+async fn run_tui(allow_protected: bool) -> Result<()> {
+    let mut events = EventHandler::default();
+
+    // Create app state before touching the terminal so errors (e.g. invalid
+    // presets) are printed normally
+    let mut app = AppState::new(events.sender())?;
+    process::set_protection_policy(ProtectionPolicy {
+        rules: app.presets.protected_processes.clone(),
+        allow_protected,
+    });
+    process::init_audit_log(Initiator::Tui);
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableFocusChange,
+        EnableBracketedPaste,
+        EnableMouseCapture
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app state
-    let mut app = AppState::new()?;
-    let event_handler = EventHandler::default();
-
-    // Main event loop
+    // Main event loop: redraw only when an event changed something
+    let mut redraw = true;
     loop {
-        // Render UI
-        terminal.draw(|f| {
-            tui::dashboard::render_dashboard(f, &mut app);
-        })?;
-
-        // Handle events
-        match event_handler.next().await? {
-            AppEvent::Key(key_event) => {
-                app.handle_key_event(key_event).await?;
-            }
-            AppEvent::Resize(width, height) => {
-                terminal.resize(ratatui::layout::Rect::new(0, 0, width, height))?;
-            }
-            AppEvent::Refresh => {
-                if app.should_refresh() {
-                    app.refresh_data()?;
-                }
-            }
+        if redraw {
+            terminal.draw(|f| {
+                tui::dashboard::render_dashboard(f, &mut app);
+            })?;
+        }
+
+        let event = events.next().await?;
+        if let AppEvent::Resize(width, height) = event {
+            terminal.resize(ratatui::layout::Rect::new(0, 0, width, height))?;
         }
+        redraw = app.handle_event(event).await?;
 
         // Check if we should quit
         if app.should_quit {
             break;
         }
-
-        // Small delay to prevent excessive CPU usage
-        sleep(Duration::from_millis(16)).await; // ~60fps
     }
 
     // Cleanup terminal
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        DisableFocusChange,
+        LeaveAlternateScreen
+    )?;
     terminal.show_cursor()?;
 
     Ok(())
@@ -1,7 +1,8 @@
 use crate::network::Protocol;
+use serde::Serialize;
 use std::net::SocketAddr;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConnectionInfo {
     pub protocol: Protocol,
     pub local_address: SocketAddr,
@@ -12,6 +13,15 @@ pub struct ConnectionInfo {
 
 impl ConnectionInfo {
     pub fn matches_search(&self, query: &str) -> bool {
+        // Alternation: node|python matches either term
+        if query.contains('|') {
+            return query
+                .split('|')
+                .map(str::trim)
+                .filter(|term| !term.is_empty())
+                .any(|term| self.matches_search(term));
+        }
+
         let query = query.to_lowercase();
 
         if self.local_address.to_string().contains(&query)
@@ -1,5 +1,6 @@
 pub mod connections;
 pub mod ports;
+pub mod remote;
 pub mod utils;
 
 pub use connections::*;
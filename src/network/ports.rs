@@ -1,11 +1,13 @@
+use crate::config::presets::Presets;
 use crate::network::connections::ConnectionInfo;
 use anyhow::{anyhow, Result};
 use regex::Regex;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::net::{IpAddr, SocketAddr};
 use std::process::Command;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PortInfo {
     pub port: u16,
     pub protocol: Protocol,
@@ -17,13 +19,15 @@ pub struct PortInfo {
     pub service_name: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Protocol {
     Tcp,
     Udp,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ConnectionState {
     Listen,
     Established,
@@ -54,8 +58,45 @@ impl From<&str> for ConnectionState {
     }
 }
 
+impl Protocol {
+    /// Stable lowercase name, matching the serialized form.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Protocol::Tcp => "tcp",
+            Protocol::Udp => "udp",
+        }
+    }
+}
+
+impl ConnectionState {
+    /// Stable snake_case name, matching the serialized form.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConnectionState::Listen => "listen",
+            ConnectionState::Established => "established",
+            ConnectionState::TimeWait => "time_wait",
+            ConnectionState::CloseWait => "close_wait",
+            ConnectionState::FinWait1 => "fin_wait1",
+            ConnectionState::FinWait2 => "fin_wait2",
+            ConnectionState::SynSent => "syn_sent",
+            ConnectionState::SynReceived => "syn_received",
+            ConnectionState::Closed => "closed",
+            ConnectionState::Unknown => "unknown",
+        }
+    }
+}
+
 impl PortInfo {
     pub fn matches_search(&self, query: &str) -> bool {
+        // Alternation: node|python matches either term
+        if query.contains('|') {
+            return query
+                .split('|')
+                .map(str::trim)
+                .filter(|term| !term.is_empty())
+                .any(|term| self.matches_search(term));
+        }
+
         let query = query.to_lowercase();
 
         // Handle port search patterns
@@ -136,6 +177,21 @@ impl PortInfo {
             _ => None,
         }
     }
+
+    /// Built-in development port, or a port listed in the presets'
+    /// `common_ports`.
+    pub fn is_known_dev_port(&self, presets: &Presets) -> bool {
+        self.is_development_port() || presets.is_common_port(self.port)
+    }
+
+    /// Service suggestion for this port, falling back to the names it is
+    /// registered under in the presets' `common_ports`.
+    pub fn service_label(&self, presets: &Presets) -> Option<String> {
+        self.get_service_suggestion().or_else(|| {
+            let names = presets.port_names(self.port);
+            (!names.is_empty()).then(|| names.join("/"))
+        })
+    }
 }
 
 pub struct PortManager;
@@ -167,6 +223,7 @@ impl PortManager {
             .collect())
     }
 
+    #[allow(dead_code)]
     pub fn get_development_ports() -> Result<Vec<PortInfo>> {
         Ok(Self::get_all_ports()?
             .into_iter()
@@ -174,6 +231,14 @@ impl PortManager {
             .collect())
     }
 
+    /// Development ports plus any port listed in the presets' `common_ports`.
+    pub fn get_common_ports(presets: &Presets) -> Result<Vec<PortInfo>> {
+        Ok(Self::get_all_ports()?
+            .into_iter()
+            .filter(|port| port.is_known_dev_port(presets))
+            .collect())
+    }
+
     pub fn get_active_connections() -> Result<Vec<ConnectionInfo>> {
         Ok(Self::get_all_ports()?
             .into_iter()
@@ -411,6 +476,31 @@ mod tests {
         assert!(!port_info.is_development_port());
     }
 
+    #[test]
+    fn test_preset_ports() {
+        let presets = Presets::bundled();
+        let mut port_info = create_test_port_info();
+
+        // CouchDB is only known through common_ports
+        port_info.port = 5984;
+        assert!(!port_info.is_development_port());
+        assert!(port_info.is_known_dev_port(&presets));
+        assert_eq!(
+            port_info.service_label(&presets),
+            Some("couchdb".to_string())
+        );
+
+        // Built-in suggestions take precedence
+        port_info.port = 5432;
+        assert_eq!(
+            port_info.service_label(&presets),
+            Some("PostgreSQL".to_string())
+        );
+
+        port_info.port = 443;
+        assert!(!port_info.is_known_dev_port(&presets));
+    }
+
     #[test]
     fn test_service_suggestions() {
         let mut port_info = create_test_port_info();
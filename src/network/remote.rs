@@ -0,0 +1,192 @@
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Where an address lives, judged from the address alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressScope {
+    Loopback,
+    /// RFC 1918 IPv4 ranges and IPv6 unique local addresses (fc00::/7)
+    Private,
+    /// Carrier-grade NAT range (100.64.0.0/10)
+    SharedNat,
+    LinkLocal,
+    Multicast,
+    Unspecified,
+    Public,
+}
+
+impl AddressScope {
+    pub fn classify(ip: IpAddr) -> Self {
+        match ip {
+            IpAddr::V4(ip) => Self::classify_v4(ip),
+            IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+                Some(mapped) => Self::classify_v4(mapped),
+                None => Self::classify_v6(ip),
+            },
+        }
+    }
+
+    fn classify_v4(ip: Ipv4Addr) -> Self {
+        let [a, b, ..] = ip.octets();
+        if ip.is_loopback() {
+            AddressScope::Loopback
+        } else if ip.is_unspecified() {
+            AddressScope::Unspecified
+        } else if ip.is_private() {
+            AddressScope::Private
+        } else if a == 100 && (64..128).contains(&b) {
+            AddressScope::SharedNat
+        } else if ip.is_link_local() {
+            AddressScope::LinkLocal
+        } else if ip.is_multicast() || ip.is_broadcast() {
+            AddressScope::Multicast
+        } else {
+            AddressScope::Public
+        }
+    }
+
+    fn classify_v6(ip: Ipv6Addr) -> Self {
+        let first = ip.segments()[0];
+        if ip.is_loopback() {
+            AddressScope::Loopback
+        } else if ip.is_unspecified() {
+            AddressScope::Unspecified
+        } else if first & 0xfe00 == 0xfc00 {
+            AddressScope::Private
+        } else if first & 0xffc0 == 0xfe80 {
+            AddressScope::LinkLocal
+        } else if ip.is_multicast() {
+            AddressScope::Multicast
+        } else {
+            AddressScope::Public
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AddressScope::Loopback => "loopback (this machine)",
+            AddressScope::Private => "private network",
+            AddressScope::SharedNat => "shared address space (carrier NAT)",
+            AddressScope::LinkLocal => "link-local",
+            AddressScope::Multicast => "multicast/broadcast",
+            AddressScope::Unspecified => "unspecified",
+            AddressScope::Public => "public internet",
+        }
+    }
+
+    /// Whether the address can only be reached from this machine or its
+    /// local network.
+    pub fn is_local(self) -> bool {
+        self != AddressScope::Public
+    }
+}
+
+/// What can be told about a remote address without leaving the machine,
+/// apart from the reverse lookup, which asks the system resolver.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteSummary {
+    pub address: IpAddr,
+    pub scope: AddressScope,
+    /// Names given to the address in /etc/hosts
+    pub host_names: Vec<String>,
+    /// None until the reverse lookup has been done, or when it found nothing
+    pub reverse_name: Option<String>,
+}
+
+impl RemoteSummary {
+    /// Classify `address` and look it up in /etc/hosts. The reverse lookup
+    /// can block, so it is left to `reverse_lookup`.
+    pub fn local(address: IpAddr) -> Self {
+        let host_names = fs::read_to_string("/etc/hosts")
+            .map(|contents| hosts_names(&contents, address))
+            .unwrap_or_default();
+        Self {
+            address,
+            scope: AddressScope::classify(address),
+            host_names,
+            reverse_name: None,
+        }
+    }
+}
+
+/// Names for `address` in a hosts file.
+pub fn hosts_names(contents: &str, address: IpAddr) -> Vec<String> {
+    let mut names = Vec::new();
+    for line in contents.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut fields = line.split_whitespace();
+        let Some(ip) = fields.next().and_then(|ip| ip.parse::<IpAddr>().ok()) else {
+            continue;
+        };
+        if ip == address {
+            for name in fields {
+                if !names.iter().any(|known| known == name) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+    }
+    names
+}
+
+/// Reverse lookup through the system resolver (hosts file, DNS, mDNS).
+/// Blocks until the resolver answers. The numeric form the resolver falls
+/// back to when there is no name counts as not found.
+pub fn reverse_lookup(address: IpAddr) -> Option<String> {
+    dns_lookup::lookup_addr(&address)
+        .ok()
+        .filter(|name| name.parse::<IpAddr>().is_err())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_classify_addresses() {
+        let cases = [
+            ("127.0.0.1", AddressScope::Loopback),
+            ("::1", AddressScope::Loopback),
+            ("10.1.2.3", AddressScope::Private),
+            ("172.16.0.9", AddressScope::Private),
+            ("192.168.1.20", AddressScope::Private),
+            ("fd12:3456::1", AddressScope::Private),
+            ("100.64.0.1", AddressScope::SharedNat),
+            ("169.254.10.1", AddressScope::LinkLocal),
+            ("fe80::1", AddressScope::LinkLocal),
+            ("224.0.0.251", AddressScope::Multicast),
+            ("ff02::fb", AddressScope::Multicast),
+            ("0.0.0.0", AddressScope::Unspecified),
+            ("::", AddressScope::Unspecified),
+            ("8.8.8.8", AddressScope::Public),
+            ("2606:4700::1111", AddressScope::Public),
+            ("::ffff:192.168.0.1", AddressScope::Private),
+        ];
+        for (address, scope) in cases {
+            assert_eq!(AddressScope::classify(ip(address)), scope, "{address}");
+        }
+        assert!(!AddressScope::Public.is_local());
+        assert!(AddressScope::Private.is_local());
+    }
+
+    #[test]
+    fn test_hosts_names() {
+        let hosts = "\
+# comment 10.0.0.5 ignored
+127.0.0.1\tlocalhost
+10.0.0.5   db.internal db  # primary
+10.0.0.5   db
+not-an-ip  whatever
+";
+        assert_eq!(
+            hosts_names(hosts, ip("10.0.0.5")),
+            vec!["db.internal".to_string(), "db".to_string()]
+        );
+        assert_eq!(hosts_names(hosts, ip("127.0.0.1")), vec!["localhost"]);
+        assert!(hosts_names(hosts, ip("10.0.0.6")).is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::config::presets::{Presets, QuickAction};
+use crate::process::{
+    lineage_of, MatchMode, ProcessInfo, ProcessKiller, ProcessManager, ProcessMatcher,
+};
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+
+/// A process a quick action will terminate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionTarget {
+    pub pid: u32,
+    pub name: Option<String>,
+    /// Ports from the action that this process is listening on
+    pub ports: Vec<u16>,
+}
+
+/// Exactly what running a quick action will affect, resolved against the
+/// processes and ports in use right now.
+#[derive(Debug, Clone)]
+pub struct ActionPlan {
+    /// Key of the action in `[quick_actions]`
+    pub key: String,
+    pub action: QuickAction,
+    /// Targets sorted by PID, each listed once
+    pub targets: Vec<ActionTarget>,
+    /// Ports from the action that nothing is using
+    pub free_ports: Vec<u16>,
+    /// Ports from the action held by the calling process or one of its
+    /// ancestors, which are left alone
+    pub held_ports: Vec<u16>,
+}
+
+impl ActionPlan {
+    /// Build a plan from a process snapshot and the owner of each of the
+    /// action's ports. The calling process and its ancestors are never
+    /// targeted.
+    pub fn build(
+        key: &str,
+        action: &QuickAction,
+        processes: &[ProcessInfo],
+        port_owners: &[(u16, Option<u32>)],
+    ) -> Self {
+        let mut targets: BTreeMap<u32, ActionTarget> = BTreeMap::new();
+
+        for name in &action.processes {
+            if let Ok(matcher) = ProcessMatcher::new(name, MatchMode::Exact) {
+                for process in matcher.select(processes) {
+                    targets.entry(process.pid).or_insert(ActionTarget {
+                        pid: process.pid,
+                        name: Some(process.name),
+                        ports: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        let lineage = lineage_of(std::process::id(), processes);
+        let mut free_ports = Vec::new();
+        let mut held_ports = Vec::new();
+        for &(port, owner) in port_owners {
+            match owner {
+                Some(pid) if lineage.contains(&pid) => held_ports.push(port),
+                Some(pid) => targets
+                    .entry(pid)
+                    .or_insert_with(|| ActionTarget {
+                        pid,
+                        name: processes
+                            .iter()
+                            .find(|p| p.pid == pid)
+                            .map(|p| p.name.clone()),
+                        ports: Vec::new(),
+                    })
+                    .ports
+                    .push(port),
+                None => free_ports.push(port),
+            }
+        }
+
+        Self {
+            key: key.to_string(),
+            action: action.clone(),
+            targets: targets.into_values().collect(),
+            free_ports,
+            held_ports,
+        }
+    }
+
+    pub fn pids(&self) -> Vec<u32> {
+        self.targets.iter().map(|t| t.pid).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+}
+
+/// The quick action named `key` in `presets`.
+pub fn find_action<'a>(presets: &'a Presets, key: &str) -> Result<&'a QuickAction> {
+    presets.quick_actions.get(key).ok_or_else(|| {
+        anyhow!(
+            "Unknown quick action '{key}' (available: {})",
+            presets
+                .quick_actions
+                .keys()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    })
+}
+
+/// Resolve `action` against the running system.
+pub fn plan_action(key: &str, action: &QuickAction) -> ActionPlan {
+    let processes = ProcessManager::new().get_processes();
+    ActionPlan::build(key, action, &processes, &port_owners(&action.ports))
+}
+
+/// The process listening on each of `ports` over TCP, if any. Clients
+/// connected to a port don't own it.
+pub fn port_owners(ports: &[u16]) -> Vec<(u16, Option<u32>)> {
+    ports
+        .iter()
+        .map(|&port| (port, ProcessKiller::find_listener_by_port(port).ok()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::current_uid;
+    use crate::testing::fixtures::create_test_process;
+
+    fn process(pid: u32, name: &str) -> ProcessInfo {
+        ProcessInfo {
+            user_id: current_uid(),
+            ..create_test_process(pid, name, 0.0, 0)
+        }
+    }
+
+    fn action(processes: &[&str], ports: &[u16]) -> QuickAction {
+        QuickAction {
+            name: "Test".to_string(),
+            description: String::new(),
+            processes: processes.iter().map(|s| s.to_string()).collect(),
+            ports: ports.to_vec(),
+            confirmation_required: true,
+        }
+    }
+
+    #[test]
+    fn test_plan_merges_names_and_ports() {
+        let processes = vec![
+            process(10, "node"),
+            process(20, "vite"),
+            process(30, "redis-server"),
+        ];
+        let plan = ActionPlan::build(
+            "dev",
+            &action(&["node", "vite"], &[3000, 6379, 8080]),
+            &processes,
+            &[(3000, Some(10)), (6379, Some(30)), (8080, None)],
+        );
+
+        assert_eq!(plan.pids(), vec![10, 20, 30]);
+        assert_eq!(plan.targets[0].ports, vec![3000]);
+        assert!(plan.targets[1].ports.is_empty());
+        assert_eq!(plan.targets[2].name.as_deref(), Some("redis-server"));
+        assert_eq!(plan.free_ports, vec![8080]);
+    }
+
+    #[test]
+    fn test_plan_never_targets_itself() {
+        let own_pid = std::process::id();
+        let processes = vec![process(own_pid, "node")];
+        let plan = ActionPlan::build(
+            "dev",
+            &action(&["node"], &[3000]),
+            &processes,
+            &[(3000, Some(own_pid))],
+        );
+
+        assert!(plan.is_empty());
+        assert!(plan.free_ports.is_empty());
+        assert_eq!(plan.held_ports, vec![3000]);
+    }
+
+    #[test]
+    fn test_find_action() {
+        let presets = Presets::bundled();
+        assert!(find_action(&presets, "kill_databases").is_ok());
+        let error = find_action(&presets, "nope").unwrap_err().to_string();
+        assert!(error.contains("free_dev_ports"));
+    }
+}
@@ -0,0 +1,333 @@
+use crate::config::paths::data_dir;
+use crate::process::KillFailure;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::future::Future;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Size at which `audit.log` is rotated to `audit.log.1`.
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+/// Rotated files kept next to the live log (`audit.log.1` is the newest).
+const ROTATED_FILES: usize = 3;
+
+/// What started an audited action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Initiator {
+    Tui,
+    Cli,
+    /// A preset rule: a quick action or cleanup group
+    Rule,
+}
+
+impl Initiator {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Initiator::Tui => "tui",
+            Initiator::Cli => "cli",
+            Initiator::Rule => "rule",
+        }
+    }
+}
+
+/// One signal sent (or priority changed) by bossy-rust, as stored in the
+/// audit log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Unix time in seconds
+    pub timestamp: u64,
+    pub pid: u32,
+    pub name: Option<String>,
+    pub command_line: Option<String>,
+    /// Signal name (`SIGTERM`) or priority change (`nice 10`)
+    pub action: String,
+    pub initiator: Initiator,
+    /// Quick action key or cleanup group, for `Initiator::Rule`
+    pub rule: Option<String>,
+    pub success: bool,
+    pub failure: Option<KillFailure>,
+    pub error: Option<String>,
+}
+
+impl AuditEntry {
+    /// Whether `query` appears in the process name, command line, action
+    /// or rule, ignoring case, or equals the PID.
+    pub fn matches(&self, query: &str) -> bool {
+        if query.parse::<u32>() == Ok(self.pid) {
+            return true;
+        }
+        let query = query.to_lowercase();
+        [
+            self.name.as_deref(),
+            self.command_line.as_deref(),
+            Some(self.action.as_str()),
+            self.rule.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|field| field.to_lowercase().contains(&query))
+    }
+
+    /// `ok`, or the failure kind (`permission_denied`, ...).
+    pub fn outcome(&self) -> &'static str {
+        match (self.success, self.failure) {
+            (true, _) => "ok",
+            (false, Some(failure)) => failure.as_str(),
+            (false, None) => "failed",
+        }
+    }
+}
+
+/// An append-only JSON Lines file, rotated when it grows past a size.
+#[derive(Debug, Clone)]
+pub struct AuditLog {
+    path: PathBuf,
+}
+
+impl AuditLog {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// `audit.log` in the data directory.
+    pub fn default_path() -> Option<PathBuf> {
+        data_dir().map(|dir| dir.join("audit.log"))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn append(&self, entry: &AuditEntry) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        if fs::metadata(&self.path).is_ok_and(|m| m.len() >= MAX_LOG_BYTES) {
+            self.rotate()?;
+        }
+
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        // One write per entry, so concurrent bossy-rust runs don't interleave
+        file.write_all(line.as_bytes())?;
+        Ok(())
+    }
+
+    fn rotated(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        PathBuf::from(path)
+    }
+
+    fn rotate(&self) -> Result<()> {
+        for index in (1..ROTATED_FILES).rev() {
+            let from = self.rotated(index);
+            if from.exists() {
+                fs::rename(&from, self.rotated(index + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.rotated(1))?;
+        Ok(())
+    }
+
+    /// Every entry in the live and rotated files, oldest first. Lines that
+    /// don't parse (e.g. cut short by a full disk) are skipped.
+    pub fn read(&self) -> Result<Vec<AuditEntry>> {
+        let mut files: Vec<PathBuf> = (1..=ROTATED_FILES).rev().map(|i| self.rotated(i)).collect();
+        files.push(self.path.clone());
+
+        let mut entries = Vec::new();
+        for file in files {
+            let contents = match fs::read_to_string(&file) {
+                Ok(contents) => contents,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            entries.extend(
+                contents
+                    .lines()
+                    .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok()),
+            );
+        }
+        Ok(entries)
+    }
+}
+
+/// Log every kill goes to, and who is running them. Off until
+/// `init_audit_log` is called, so tests and library users leave no trace.
+static AUDIT: RwLock<Option<(AuditLog, Initiator)>> = RwLock::new(None);
+
+tokio::task_local! {
+    /// Rule whose targets the current task is terminating
+    static RULE: String;
+}
+
+/// Record actions from now on in the default log, attributed to `initiator`.
+pub fn init_audit_log(initiator: Initiator) {
+    let log = AuditLog::default_path().map(AuditLog::new);
+    *AUDIT.write().unwrap_or_else(|e| e.into_inner()) = log.map(|log| (log, initiator));
+}
+
+/// The log `init_audit_log` set up, if any.
+pub fn audit_log() -> Option<AuditLog> {
+    AUDIT
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .map(|(log, _)| log.clone())
+}
+
+/// Run `future`, attributing the actions it records to `rule`.
+pub async fn run_as_rule<F: Future>(rule: &str, future: F) -> F::Output {
+    RULE.scope(rule.to_string(), future).await
+}
+
+/// Append an entry for one attempted action. Failing to write the log
+/// never fails the action itself, and nothing is printed because the TUI
+/// owns the terminal.
+pub fn record_action(
+    pid: u32,
+    name: Option<String>,
+    command_line: Option<String>,
+    action: String,
+    result: &Result<()>,
+) {
+    let Some((log, initiator)) = AUDIT.read().unwrap_or_else(|e| e.into_inner()).clone() else {
+        return;
+    };
+    let rule = RULE.try_with(|rule| rule.clone()).ok();
+    let entry = AuditEntry {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default(),
+        pid,
+        name,
+        command_line,
+        action,
+        initiator: if rule.is_some() {
+            Initiator::Rule
+        } else {
+            initiator
+        },
+        rule,
+        success: result.is_ok(),
+        failure: result
+            .as_ref()
+            .err()
+            .map(crate::process::KillError::kind_of),
+        error: result.as_ref().err().map(|e| e.to_string()),
+    };
+    let _ = log.append(&entry);
+}
+
+/// `2026-10-18 14:03:22 UTC`. The log stores Unix time; this avoids a
+/// time zone database.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+    // Days since the epoch to a civil date (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        seconds / 3_600,
+        (seconds % 3_600) / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn entry(pid: u32, action: &str) -> AuditEntry {
+        AuditEntry {
+            timestamp: 1_760_000_000,
+            pid,
+            name: Some("postgres".to_string()),
+            command_line: Some("postgres -D /var/lib/staging".to_string()),
+            action: action.to_string(),
+            initiator: Initiator::Cli,
+            rule: None,
+            success: true,
+            failure: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_append_and_rotate() {
+        let dir = TempDir::new().unwrap();
+        let log = AuditLog::new(dir.path().join("nested").join("audit.log"));
+        assert!(log.read().unwrap().is_empty());
+
+        log.append(&entry(1, "SIGTERM")).unwrap();
+        log.append(&entry(2, "SIGKILL")).unwrap();
+        let entries = log.read().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1], entry(2, "SIGKILL"));
+
+        // A full log moves aside; lines that aren't entries are skipped
+        let fill = || {
+            let mut file = OpenOptions::new().append(true).open(log.path()).unwrap();
+            writeln!(file, "{}", "x".repeat(MAX_LOG_BYTES as usize)).unwrap();
+        };
+        fill();
+        log.append(&entry(3, "SIGSTOP")).unwrap();
+        assert!(log.rotated(1).exists());
+        let pids: Vec<u32> = log.read().unwrap().iter().map(|e| e.pid).collect();
+        assert_eq!(pids, vec![1, 2, 3]);
+
+        // Only the newest rotated files are kept, read oldest first
+        for pid in 4..=6 {
+            fill();
+            log.append(&entry(pid, "SIGHUP")).unwrap();
+        }
+        assert!(log.rotated(ROTATED_FILES).exists());
+        assert!(!log.rotated(ROTATED_FILES + 1).exists());
+        let pids: Vec<u32> = log.read().unwrap().iter().map(|e| e.pid).collect();
+        assert_eq!(pids, vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_entry_matching() {
+        let entry = entry(4242, "SIGKILL");
+        assert!(entry.matches("4242"));
+        assert!(entry.matches("staging"));
+        assert!(entry.matches("Postgres"));
+        assert!(entry.matches("sigkill"));
+        assert!(!entry.matches("nginx"));
+        assert_eq!(entry.outcome(), "ok");
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00:00 UTC");
+        assert_eq!(format_timestamp(1_760_000_000), "2025-10-09 08:53:20 UTC");
+    }
+
+    #[tokio::test]
+    async fn test_rule_scope() {
+        assert!(RULE.try_with(|_| ()).is_err());
+        let rule = run_as_rule("web", async { RULE.with(|rule| rule.clone()) }).await;
+        assert_eq!(rule, "web");
+    }
+}
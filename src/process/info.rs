@@ -1,6 +1,10 @@
-use sysinfo::{Pid, Process, System};
+use crate::config::presets::Presets;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::process::Command;
+use sysinfo::{Pid, Process, ProcessStatus, System, Users};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ProcessInfo {
     pub pid: u32,
     pub name: String,
@@ -15,10 +19,21 @@ pub struct ProcessInfo {
     pub user_id: Option<u32>,
     #[allow(dead_code)]
     pub executable_path: Option<String>,
+    /// Working directory, when the OS lets us read it
+    #[allow(dead_code)]
+    pub cwd: Option<String>,
     #[allow(dead_code)]
     pub command_line: Vec<String>,
+    /// Nice value, from -20 (most favoured) to 19 (least favoured)
+    pub nice: Option<i32>,
+    /// Kernel scheduling priority as `ps` reports it; the scale differs
+    /// between macOS and Linux, but on both a lower value runs first
+    pub priority: Option<i32>,
 }
 
+/// `ProcessInfo::status` of a process paused by SIGSTOP (or Ctrl+Z).
+pub const STOPPED: &str = "Stopped";
+
 impl ProcessInfo {
     pub fn from_sysinfo(pid: Pid, process: &Process) -> Self {
         Self {
@@ -27,17 +42,42 @@ impl ProcessInfo {
             cpu_usage: process.cpu_usage(),
             memory: process.memory(),
             parent_pid: process.parent().map(|p| p.as_u32()),
-            status: format!("{:?}", process.status()),
+            status: match process.status() {
+                ProcessStatus::Stop => STOPPED.to_string(),
+                status => format!("{status:?}"),
+            },
             start_time: process.start_time(),
             user_id: process
                 .user_id()
                 .and_then(|u| u.to_string().parse::<u32>().ok()),
             executable_path: process.exe().and_then(|p| p.to_str().map(String::from)),
+            cwd: process.cwd().and_then(|p| p.to_str().map(String::from)),
             command_line: process.cmd().to_vec(),
+            nice: None,
+            priority: None,
         }
     }
 
+    pub fn is_stopped(&self) -> bool {
+        self.status == STOPPED
+    }
+
+    /// The preset development group this process belongs to, matched by
+    /// process name.
+    pub fn development_group<'a>(&self, presets: &'a Presets) -> Option<&'a str> {
+        presets.development_group(&self.name)
+    }
+
     pub fn matches_search(&self, query: &str) -> bool {
+        // Alternation: node|python matches either term
+        if query.contains('|') {
+            return query
+                .split('|')
+                .map(str::trim)
+                .filter(|term| !term.is_empty())
+                .any(|term| self.matches_search(term));
+        }
+
         let query = query.to_lowercase();
 
         // Handle special search patterns
@@ -90,6 +130,42 @@ impl ProcessInfo {
     }
 }
 
+/// Machine-wide figures shown on the dashboard next to the process list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct SystemStats {
+    /// Bytes
+    pub total_memory: u64,
+    pub used_memory: u64,
+    pub total_swap: u64,
+    pub used_swap: u64,
+    /// 1, 5 and 15 minute load averages (zero on Windows)
+    pub load_average: [f64; 3],
+    /// Seconds since boot
+    pub uptime: u64,
+    /// Threads across all processes; sysinfo only lists them on Linux
+    pub threads: Option<usize>,
+}
+
+impl SystemStats {
+    /// Used memory as a percentage of the total, 0-100.
+    pub fn memory_percent(&self) -> f64 {
+        percent_of(self.used_memory, self.total_memory)
+    }
+
+    /// Used swap as a percentage of the total, 0 when there is no swap.
+    pub fn swap_percent(&self) -> f64 {
+        percent_of(self.used_swap, self.total_swap)
+    }
+}
+
+fn percent_of(used: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        (used as f64 / total as f64 * 100.0).min(100.0)
+    }
+}
+
 pub struct ProcessManager {
     system: System,
 }
@@ -106,16 +182,87 @@ impl ProcessManager {
     }
 
     pub fn get_processes(&self) -> Vec<ProcessInfo> {
+        // sysinfo has no scheduling information, so ask ps once for all of it
+        let priorities = Self::get_priorities();
         self.system
             .processes()
             .iter()
-            .map(|(&pid, process)| ProcessInfo::from_sysinfo(pid, process))
+            // On Linux, sysinfo also lists threads; they share their process's
+            // command line and signalling one would hit the whole process
+            .filter(|(_, process)| process.thread_kind().is_none())
+            .map(|(&pid, process)| {
+                let mut info = ProcessInfo::from_sysinfo(pid, process);
+                if let Some(&(nice, priority)) = priorities.get(&info.pid) {
+                    info.nice = nice;
+                    info.priority = priority;
+                }
+                info
+            })
             .collect()
     }
 
+    /// Nice value and priority of every process, keyed by PID. Empty when
+    /// `ps` can't be run.
+    pub fn get_priorities() -> HashMap<u32, (Option<i32>, Option<i32>)> {
+        Command::new("ps")
+            .args(["-A", "-o", "pid=,ni=,pri="])
+            .output()
+            .map(|output| parse_priorities(&String::from_utf8_lossy(&output.stdout)))
+            .unwrap_or_default()
+    }
+
     pub fn get_system_cpu_usage(&self) -> f32 {
         self.system.global_cpu_info().cpu_usage()
     }
+
+    pub fn get_system_stats(&self) -> SystemStats {
+        let load = System::load_average();
+        // Each process's tasks are its threads other than the main one
+        let tasks: Vec<usize> = self
+            .system
+            .processes()
+            .values()
+            .filter(|process| process.thread_kind().is_none())
+            .filter_map(|process| process.tasks().map(|tasks| tasks.len() + 1))
+            .collect();
+        let threads = (!tasks.is_empty()).then(|| tasks.iter().sum());
+        SystemStats {
+            total_memory: self.system.total_memory(),
+            used_memory: self.system.used_memory(),
+            total_swap: self.system.total_swap(),
+            used_swap: self.system.used_swap(),
+            load_average: [load.one, load.five, load.fifteen],
+            uptime: System::uptime(),
+            threads,
+        }
+    }
+
+    /// Map of numeric user IDs to login names for every known user.
+    pub fn get_user_names() -> HashMap<u32, String> {
+        Users::new_with_refreshed_list()
+            .list()
+            .iter()
+            .filter_map(|user| {
+                let uid = user.id().to_string().parse::<u32>().ok()?;
+                Some((uid, user.name().to_string()))
+            })
+            .collect()
+    }
+}
+
+/// Parse `ps -o pid=,ni=,pri=` output. Linux prints `-` as the nice value
+/// of real-time processes, which have none.
+pub fn parse_priorities(output: &str) -> HashMap<u32, (Option<i32>, Option<i32>)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let pid = fields.next()?.parse::<u32>().ok()?;
+            let nice = fields.next().and_then(|nice| nice.parse::<i32>().ok());
+            let priority = fields.next().and_then(|pri| pri.parse::<i32>().ok());
+            Some((pid, (nice, priority)))
+        })
+        .collect()
 }
 
 impl Default for ProcessManager {
@@ -139,11 +286,14 @@ mod tests {
             start_time: 1234567890,
             user_id: Some(1000),
             executable_path: Some("/usr/bin/test_process".to_string()),
+            cwd: None,
             command_line: vec![
                 "test_process".to_string(),
                 "--arg1".to_string(),
                 "value".to_string(),
             ],
+            nice: Some(0),
+            priority: Some(20),
         }
     }
 
@@ -209,6 +359,16 @@ mod tests {
         assert!(!process.matches_search(">1GB")); // 512MB < 1GB
     }
 
+    #[test]
+    fn test_search_alternation() {
+        let process = create_test_process();
+        assert!(process.matches_search("node|test"));
+        assert!(process.matches_search("node | #1234"));
+        assert!(process.matches_search(">90%|>500MB"));
+        assert!(!process.matches_search("node|python|java|ruby"));
+        assert!(!process.matches_search("|"));
+    }
+
     #[test]
     fn test_invalid_search_patterns() {
         let process = create_test_process();
@@ -223,6 +383,34 @@ mod tests {
         assert!(!process.matches_search(">abcMB"));
     }
 
+    #[test]
+    fn test_parse_priorities() {
+        let output = "    1   0  19\n  812 -20  39\n   15   -  139\n garbage\n 2301  10\n";
+        let priorities = parse_priorities(output);
+        assert_eq!(priorities.len(), 4);
+        assert_eq!(priorities[&1], (Some(0), Some(19)));
+        assert_eq!(priorities[&812], (Some(-20), Some(39)));
+        assert_eq!(priorities[&15], (None, Some(139)));
+        assert_eq!(priorities[&2301], (Some(10), None));
+    }
+
+    #[test]
+    fn test_system_stats() {
+        let stats = ProcessManager::new().get_system_stats();
+        assert!(stats.total_memory > 0);
+        assert!(stats.used_memory <= stats.total_memory);
+        assert!(stats.uptime > 0);
+
+        let stats = SystemStats {
+            total_memory: 8 << 30,
+            used_memory: 6 << 30,
+            ..SystemStats::default()
+        };
+        assert_eq!(stats.memory_percent(), 75.0);
+        // No swap configured
+        assert_eq!(stats.swap_percent(), 0.0);
+    }
+
     #[test]
     fn test_process_manager_creation() {
         let manager = ProcessManager::new();
@@ -0,0 +1,251 @@
+use crate::process::ProcessInfo;
+use anyhow::{anyhow, Result};
+use clap::ValueEnum;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashSet;
+use sysinfo::{ProcessRefreshKind, System, UpdateKind};
+
+/// How a `kill-process` pattern is compared against running processes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchMode {
+    /// Process name (or executable file name) equals the pattern
+    #[default]
+    Exact,
+    /// Process name contains the pattern, ignoring case
+    Substring,
+    /// Process name matches the pattern as a regular expression
+    Regex,
+    /// Full command line matches the pattern as a regular expression, like `pgrep -f`
+    Cmdline,
+}
+
+/// Selects processes by pattern, optionally scoped to the current user.
+///
+/// The calling process and all of its ancestors (the shell, terminal, …)
+/// are never matched, so a pattern can't take down bossy-rust itself. When
+/// the current user can't be determined, a user-scoped matcher matches
+/// nothing.
+#[derive(Debug, Clone)]
+pub struct ProcessMatcher {
+    pattern: String,
+    mode: MatchMode,
+    regex: Option<Regex>,
+    current_user_only: bool,
+}
+
+impl ProcessMatcher {
+    pub fn new(pattern: &str, mode: MatchMode) -> Result<Self> {
+        if pattern.is_empty() {
+            return Err(anyhow!("Process pattern must not be empty"));
+        }
+
+        let regex = match mode {
+            MatchMode::Regex | MatchMode::Cmdline => {
+                Some(Regex::new(pattern).map_err(|e| anyhow!("Invalid pattern '{pattern}': {e}"))?)
+            }
+            MatchMode::Exact | MatchMode::Substring => None,
+        };
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            mode,
+            regex,
+            current_user_only: true,
+        })
+    }
+
+    /// Match processes of every user instead of only the current one.
+    pub fn all_users(mut self, all_users: bool) -> Self {
+        self.current_user_only = !all_users;
+        self
+    }
+
+    pub fn matches(&self, process: &ProcessInfo) -> bool {
+        match self.mode {
+            MatchMode::Exact => {
+                process.name == self.pattern
+                    || process
+                        .executable_path
+                        .as_deref()
+                        .and_then(|path| path.rsplit('/').next())
+                        .is_some_and(|file| file == self.pattern)
+            }
+            MatchMode::Substring => process
+                .name
+                .to_lowercase()
+                .contains(&self.pattern.to_lowercase()),
+            MatchMode::Regex => self
+                .regex
+                .as_ref()
+                .is_some_and(|re| re.is_match(&process.name)),
+            MatchMode::Cmdline => self
+                .regex
+                .as_ref()
+                .is_some_and(|re| re.is_match(&process.command_line.join(" "))),
+        }
+    }
+
+    /// Matching processes from `processes`, sorted by PID.
+    pub fn select(&self, processes: &[ProcessInfo]) -> Vec<ProcessInfo> {
+        self.select_for(processes, std::process::id(), current_uid())
+    }
+
+    fn select_for(
+        &self,
+        processes: &[ProcessInfo],
+        self_pid: u32,
+        current_user: Option<u32>,
+    ) -> Vec<ProcessInfo> {
+        let lineage = lineage_of(self_pid, processes);
+
+        let mut matched: Vec<ProcessInfo> = processes
+            .iter()
+            .filter(|p| !lineage.contains(&p.pid))
+            .filter(|p| {
+                !self.current_user_only || (current_user.is_some() && p.user_id == current_user)
+            })
+            .filter(|p| self.matches(p))
+            .cloned()
+            .collect();
+
+        matched.sort_by_key(|p| p.pid);
+        matched
+    }
+}
+
+/// The user ID bossy-rust runs as, asked of the OS rather than looked up
+/// in a snapshot that may not contain our own process.
+pub fn current_uid() -> Option<u32> {
+    let pid = sysinfo::get_current_pid().ok()?;
+    let mut system = System::new();
+    system.refresh_process_specifics(pid, ProcessRefreshKind::new().with_user(UpdateKind::Always));
+    system
+        .process(pid)?
+        .user_id()?
+        .to_string()
+        .parse::<u32>()
+        .ok()
+}
+
+/// `pid` and every ancestor of it found in `processes`.
+pub fn lineage_of(pid: u32, processes: &[ProcessInfo]) -> HashSet<u32> {
+    let mut lineage = HashSet::from([pid]);
+    let mut current = pid;
+
+    while let Some(parent) = processes
+        .iter()
+        .find(|p| p.pid == current)
+        .and_then(|p| p.parent_pid)
+    {
+        // Guard against cycles from PID reuse between samples
+        if !lineage.insert(parent) {
+            break;
+        }
+        current = parent;
+    }
+
+    lineage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::create_test_process;
+
+    fn process(pid: u32, parent: u32, name: &str, uid: u32, cmd: &[&str]) -> ProcessInfo {
+        ProcessInfo {
+            parent_pid: Some(parent),
+            user_id: Some(uid),
+            command_line: cmd.iter().map(|s| s.to_string()).collect(),
+            ..create_test_process(pid, name, 0.0, 0)
+        }
+    }
+
+    fn fixture() -> Vec<ProcessInfo> {
+        vec![
+            process(1, 0, "launchd", 0, &["/sbin/launchd"]),
+            process(100, 1, "zsh", 501, &["-zsh"]),
+            process(
+                200,
+                100,
+                "bossy-rust",
+                501,
+                &["bossy-rust", "kill-process", "node"],
+            ),
+            process(300, 1, "node", 501, &["node", "server.js"]),
+            process(301, 1, "nodemon", 501, &["nodemon", "app.js"]),
+            process(302, 1, "vim", 501, &["vim", "node_modules/x/index.js"]),
+            process(400, 1, "node", 0, &["node", "/opt/root-service.js"]),
+        ]
+    }
+
+    fn pids(matcher: &ProcessMatcher) -> Vec<u32> {
+        matcher
+            .select_for(&fixture(), 200, Some(501))
+            .iter()
+            .map(|p| p.pid)
+            .collect()
+    }
+
+    #[test]
+    fn test_exact_mode_ignores_arguments() {
+        let matcher = ProcessMatcher::new("node", MatchMode::Exact).unwrap();
+        assert_eq!(pids(&matcher), vec![300]);
+    }
+
+    #[test]
+    fn test_substring_and_regex_modes() {
+        let substring = ProcessMatcher::new("NODE", MatchMode::Substring).unwrap();
+        assert_eq!(pids(&substring), vec![300, 301]);
+
+        let regex = ProcessMatcher::new("^node(mon)?$", MatchMode::Regex).unwrap();
+        assert_eq!(pids(&regex), vec![300, 301]);
+    }
+
+    #[test]
+    fn test_cmdline_mode_never_matches_own_lineage() {
+        let matcher = ProcessMatcher::new("node", MatchMode::Cmdline).unwrap();
+        // bossy-rust's own command line contains "node" but is excluded
+        assert_eq!(pids(&matcher), vec![300, 301, 302]);
+    }
+
+    #[test]
+    fn test_all_users() {
+        let matcher = ProcessMatcher::new("node", MatchMode::Exact)
+            .unwrap()
+            .all_users(true);
+        assert_eq!(pids(&matcher), vec![300, 400]);
+    }
+
+    #[test]
+    fn test_unknown_user_matches_nothing() {
+        let matcher = ProcessMatcher::new("node", MatchMode::Exact).unwrap();
+        assert!(matcher.select_for(&fixture(), 200, None).is_empty());
+
+        let matcher = matcher.all_users(true);
+        assert_eq!(matcher.select_for(&fixture(), 200, None).len(), 2);
+    }
+
+    #[test]
+    fn test_current_uid() {
+        assert!(current_uid().is_some());
+    }
+
+    #[test]
+    fn test_lineage_excludes_ancestors() {
+        let lineage = lineage_of(200, &fixture());
+        assert_eq!(lineage, HashSet::from([200, 100, 1, 0]));
+
+        let matcher = ProcessMatcher::new("zsh", MatchMode::Exact).unwrap();
+        assert!(pids(&matcher).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        assert!(ProcessMatcher::new("", MatchMode::Exact).is_err());
+        let error = ProcessMatcher::new("node(", MatchMode::Regex).unwrap_err();
+        assert!(error.to_string().contains("Invalid pattern"));
+    }
+}
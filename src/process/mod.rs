@@ -1,7 +1,19 @@
+pub mod actions;
+pub mod audit;
 pub mod info;
 pub mod killer;
+pub mod matcher;
 pub mod monitor;
+pub mod project;
+pub mod protection;
+pub mod signal;
 
+pub use actions::*;
+pub use audit::*;
 pub use info::*;
 pub use killer::*;
+pub use matcher::*;
 pub use monitor::*;
+pub use project::*;
+pub use protection::*;
+pub use signal::*;
@@ -1,4 +1,4 @@
-use crate::process::{ProcessInfo, ProcessManager};
+use crate::process::{ProcessInfo, ProcessManager, SystemStats};
 use std::time::{Duration, Instant};
 
 pub struct ProcessMonitor {
@@ -16,6 +16,20 @@ impl ProcessMonitor {
         }
     }
 
+    /// Monitor that refreshes at most once per `update_interval`.
+    pub fn with_update_interval(update_interval: Duration) -> Self {
+        Self {
+            update_interval,
+            ..Self::new()
+        }
+    }
+
+    /// Refresh now, regardless of the update interval.
+    pub fn refresh(&mut self) {
+        self.manager.refresh();
+        self.last_update = Instant::now();
+    }
+
     pub fn should_update(&self) -> bool {
         self.last_update.elapsed() >= self.update_interval
     }
@@ -28,6 +42,7 @@ impl ProcessMonitor {
         self.manager.get_processes()
     }
 
+    #[allow(dead_code)]
     pub fn get_top_cpu_processes(&mut self, limit: usize) -> Vec<ProcessInfo> {
         if self.should_update() {
             self.manager.refresh();
@@ -39,6 +54,7 @@ impl ProcessMonitor {
         processes
     }
 
+    #[allow(dead_code)]
     pub fn get_top_memory_processes(&mut self, limit: usize) -> Vec<ProcessInfo> {
         if self.should_update() {
             self.manager.refresh();
@@ -57,6 +73,14 @@ impl ProcessMonitor {
         }
         self.manager.get_system_cpu_usage()
     }
+
+    pub fn get_system_stats(&mut self) -> SystemStats {
+        if self.should_update() {
+            self.manager.refresh();
+            self.last_update = Instant::now();
+        }
+        self.manager.get_system_stats()
+    }
 }
 
 impl Default for ProcessMonitor {
@@ -0,0 +1,296 @@
+use crate::config::presets::QuickAction;
+use crate::config::project::{Project, Service};
+use crate::process::{
+    lineage_of, port_owners, ActionPlan, ActionTarget, ProcessInfo, ProcessManager,
+};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+
+/// Whether a service from `.bossy.toml` is up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceState {
+    Running,
+    Stopped,
+    /// The service's port is held by a process outside the project
+    Conflict,
+}
+
+impl ServiceState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServiceState::Running => "running",
+            ServiceState::Stopped => "stopped",
+            ServiceState::Conflict => "conflict",
+        }
+    }
+}
+
+/// A project service resolved against the running system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceStatus {
+    pub service: String,
+    pub state: ServiceState,
+    pub port: Option<u16>,
+    /// The project's processes for this service, sorted by PID
+    pub processes: Vec<ActionTarget>,
+    /// Foreign process listening on `port`
+    pub holder: Option<ActionTarget>,
+}
+
+/// Whether `process` belongs to `service`: it matches the service's process
+/// pattern (if any) and was started inside the project directory, unless the
+/// service is marked `anywhere`.
+pub fn owns(project: &Project, service: &Service, process: &ProcessInfo) -> bool {
+    let in_project = service.anywhere || project.contains(process.cwd.as_deref());
+    in_project && service.matcher().is_none_or(|m| m.matches(process))
+}
+
+/// Resolve every service of `project` from a process snapshot and the
+/// owner of each project port.
+pub fn service_statuses(
+    project: &Project,
+    processes: &[ProcessInfo],
+    port_owners: &[(u16, Option<u32>)],
+) -> Vec<ServiceStatus> {
+    let lineage = lineage_of(std::process::id(), processes);
+
+    project
+        .services
+        .iter()
+        .map(|(key, service)| {
+            let mut owned: BTreeMap<u32, ActionTarget> = BTreeMap::new();
+            if let Some(matcher) = service.matcher() {
+                for process in matcher.select(processes) {
+                    if owns(project, service, &process) {
+                        owned.insert(process.pid, target(process.pid, Some(&process)));
+                    }
+                }
+            }
+
+            let mut holder = None;
+            let listener = service.port.and_then(|port| {
+                port_owners
+                    .iter()
+                    .find(|(p, _)| *p == port)
+                    .and_then(|(_, pid)| *pid)
+                    .filter(|pid| !lineage.contains(pid))
+                    .map(|pid| (port, pid))
+            });
+            if let Some((port, pid)) = listener {
+                let process = processes.iter().find(|p| p.pid == pid);
+                if process.is_some_and(|p| owns(project, service, p)) {
+                    owned
+                        .entry(pid)
+                        .or_insert_with(|| target(pid, process))
+                        .ports
+                        .push(port);
+                } else {
+                    let mut foreign = target(pid, process);
+                    foreign.ports.push(port);
+                    holder = Some(foreign);
+                }
+            }
+
+            let state = if holder.is_some() {
+                ServiceState::Conflict
+            } else if owned.is_empty() {
+                ServiceState::Stopped
+            } else {
+                ServiceState::Running
+            };
+
+            ServiceStatus {
+                service: key.clone(),
+                state,
+                port: service.port,
+                processes: owned.into_values().collect(),
+                holder,
+            }
+        })
+        .collect()
+}
+
+fn target(pid: u32, process: Option<&ProcessInfo>) -> ActionTarget {
+    ActionTarget {
+        pid,
+        name: process.map(|p| p.name.clone()),
+        ports: Vec::new(),
+    }
+}
+
+/// PIDs of every process belonging to one of the project's services.
+pub fn project_pids(
+    project: &Project,
+    processes: &[ProcessInfo],
+    port_owners: &[(u16, Option<u32>)],
+) -> HashSet<u32> {
+    service_statuses(project, processes, port_owners)
+        .iter()
+        .flat_map(|status| status.processes.iter().map(|p| p.pid))
+        .collect()
+}
+
+/// Plan for `stop`: the project's own processes, leaving foreign holders
+/// of its ports alone.
+pub fn build_stop_plan(
+    project: &Project,
+    processes: &[ProcessInfo],
+    port_owners: &[(u16, Option<u32>)],
+) -> ActionPlan {
+    let statuses = service_statuses(project, processes, port_owners);
+    let mut targets: BTreeMap<u32, ActionTarget> = BTreeMap::new();
+    for process in statuses.iter().flat_map(|s| &s.processes) {
+        // A process serving several services is stopped once
+        targets
+            .entry(process.pid)
+            .or_insert_with(|| ActionTarget {
+                ports: Vec::new(),
+                ..process.clone()
+            })
+            .ports
+            .extend(&process.ports);
+    }
+
+    ActionPlan {
+        key: "stop".to_string(),
+        action: QuickAction {
+            name: format!("Stop {}", project.name),
+            description: "Terminate the project's services".to_string(),
+            processes: project
+                .services
+                .values()
+                .filter_map(|s| s.process.clone())
+                .collect(),
+            ports: project.ports(),
+            confirmation_required: true,
+        },
+        targets: targets.into_values().collect(),
+        free_ports: port_owners
+            .iter()
+            .filter(|(_, pid)| pid.is_none())
+            .map(|(port, _)| *port)
+            .collect(),
+        held_ports: Vec::new(),
+    }
+}
+
+/// Plan for `free`: whatever listens on the project's ports, whether it
+/// belongs to the project or not.
+pub fn build_free_plan(
+    project: &Project,
+    processes: &[ProcessInfo],
+    port_owners: &[(u16, Option<u32>)],
+) -> ActionPlan {
+    let action = QuickAction {
+        name: format!("Free {} ports", project.name),
+        description: "Terminate whatever listens on the project's ports".to_string(),
+        processes: Vec::new(),
+        ports: project.ports(),
+        confirmation_required: true,
+    };
+    ActionPlan::build("free", &action, processes, port_owners)
+}
+
+/// Current process snapshot and owners of the project's ports.
+fn snapshot(project: &Project) -> (Vec<ProcessInfo>, Vec<(u16, Option<u32>)>) {
+    (
+        ProcessManager::new().get_processes(),
+        port_owners(&project.ports()),
+    )
+}
+
+/// Resolve `project`'s services against the running system.
+pub fn project_status(project: &Project) -> Vec<ServiceStatus> {
+    let (processes, owners) = snapshot(project);
+    service_statuses(project, &processes, &owners)
+}
+
+pub fn plan_project_stop(project: &Project) -> ActionPlan {
+    let (processes, owners) = snapshot(project);
+    build_stop_plan(project, &processes, &owners)
+}
+
+pub fn plan_project_free(project: &Project) -> ActionPlan {
+    let (processes, owners) = snapshot(project);
+    build_free_plan(project, &processes, &owners)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::current_uid;
+    use crate::testing::fixtures::create_test_process;
+    use std::path::Path;
+
+    fn process(pid: u32, name: &str, cwd: &str) -> ProcessInfo {
+        ProcessInfo {
+            user_id: current_uid(),
+            cwd: Some(cwd.to_string()),
+            ..create_test_process(pid, name, 0.0, 0)
+        }
+    }
+
+    fn project() -> Project {
+        let source = r#"
+[services.web]
+port = 3000
+process = "node"
+
+[services.api]
+port = 8080
+
+[services.postgres]
+port = 5432
+process = "postgres"
+anywhere = true
+
+[services.worker]
+process = "sidekiq"
+"#;
+        Project::parse(source, Path::new("/work/shop")).unwrap()
+    }
+
+    fn fixture() -> Vec<ProcessInfo> {
+        vec![
+            process(10, "node", "/work/shop/web"),
+            process(11, "node", "/work/blog"),
+            process(20, "java", "/work/other"),
+            process(30, "postgres", "/usr/local/var"),
+        ]
+    }
+
+    fn owners() -> Vec<(u16, Option<u32>)> {
+        vec![(3000, Some(10)), (5432, Some(30)), (8080, Some(20))]
+    }
+
+    #[test]
+    fn test_service_statuses() {
+        let statuses = service_statuses(&project(), &fixture(), &owners());
+        let by_name = |name: &str| statuses.iter().find(|s| s.service == name).unwrap();
+
+        let web = by_name("web");
+        assert_eq!(web.state, ServiceState::Running);
+        // node from another project is not ours
+        assert_eq!(web.processes.len(), 1);
+        assert_eq!(web.processes[0].pid, 10);
+        assert_eq!(web.processes[0].ports, vec![3000]);
+
+        let api = by_name("api");
+        assert_eq!(api.state, ServiceState::Conflict);
+        assert_eq!(api.holder.as_ref().unwrap().name.as_deref(), Some("java"));
+
+        assert_eq!(by_name("postgres").state, ServiceState::Running);
+        assert_eq!(by_name("worker").state, ServiceState::Stopped);
+    }
+
+    #[test]
+    fn test_stop_and_free_plans() {
+        let stop = build_stop_plan(&project(), &fixture(), &owners());
+        assert_eq!(stop.pids(), vec![10, 30]);
+
+        let free = build_free_plan(&project(), &fixture(), &owners());
+        assert_eq!(free.pids(), vec![10, 20, 30]);
+        assert_eq!(free.targets[1].ports, vec![8080]);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::config::presets::{Presets, ProtectedProcesses};
+use crate::process::{lineage_of, ProcessInfo};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::RwLock;
+
+/// Which processes `ProcessKiller` refuses to signal.
+#[derive(Debug, Clone)]
+pub struct ProtectionPolicy {
+    pub rules: ProtectedProcesses,
+    /// Signal protected processes anyway (`--allow-protected`)
+    pub allow_protected: bool,
+}
+
+impl Default for ProtectionPolicy {
+    fn default() -> Self {
+        Self {
+            rules: Presets::bundled().protected_processes,
+            allow_protected: false,
+        }
+    }
+}
+
+/// Policy used by every kill. Set once at startup from the presets; until
+/// then the bundled rules apply.
+static POLICY: RwLock<Option<ProtectionPolicy>> = RwLock::new(None);
+
+pub fn set_protection_policy(policy: ProtectionPolicy) {
+    *POLICY.write().unwrap_or_else(|e| e.into_inner()) = Some(policy);
+}
+
+pub fn protection_policy() -> ProtectionPolicy {
+    POLICY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Why a process is protected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProtectionReason {
+    InitProcess,
+    Itself,
+    /// bossy-rust was started from this process (shell, terminal, …)
+    Ancestor,
+    Name(String),
+    Path(String),
+    User(String),
+}
+
+impl fmt::Display for ProtectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProtectionReason::InitProcess => write!(f, "PID 1 is the init process"),
+            ProtectionReason::Itself => write!(f, "it is bossy-rust itself"),
+            ProtectionReason::Ancestor => write!(f, "bossy-rust is running inside it"),
+            ProtectionReason::Name(name) => write!(f, "'{name}' is a protected process name"),
+            ProtectionReason::Path(prefix) => write!(f, "its executable is under {prefix}"),
+            ProtectionReason::User(user) => write!(f, "it is owned by protected user '{user}'"),
+        }
+    }
+}
+
+/// Checks processes against a `ProtectedProcesses` list plus the built-in
+/// rules (PID 1, this process and its ancestors).
+pub struct ProtectionCheck<'a> {
+    rules: &'a ProtectedProcesses,
+    lineage: HashSet<u32>,
+    users: &'a HashMap<u32, String>,
+}
+
+impl<'a> ProtectionCheck<'a> {
+    pub fn new(
+        rules: &'a ProtectedProcesses,
+        processes: &[ProcessInfo],
+        users: &'a HashMap<u32, String>,
+    ) -> Self {
+        Self {
+            rules,
+            lineage: lineage_of(std::process::id(), processes),
+            users,
+        }
+    }
+
+    /// Why `pid` is protected, if it is. `process` is `None` when the PID
+    /// isn't in the snapshot, in which case only the PID rules apply.
+    pub fn reason(&self, pid: u32, process: Option<&ProcessInfo>) -> Option<ProtectionReason> {
+        if pid <= 1 {
+            return Some(ProtectionReason::InitProcess);
+        }
+        if pid == std::process::id() {
+            return Some(ProtectionReason::Itself);
+        }
+        if self.lineage.contains(&pid) {
+            return Some(ProtectionReason::Ancestor);
+        }
+
+        let process = process?;
+        if let Some(name) = self.rules.names.iter().find(|n| **n == process.name) {
+            return Some(ProtectionReason::Name(name.clone()));
+        }
+        if let Some(path) = &process.executable_path {
+            if let Some(prefix) = self
+                .rules
+                .paths
+                .iter()
+                .find(|p| path.starts_with(p.as_str()))
+            {
+                return Some(ProtectionReason::Path(prefix.clone()));
+            }
+        }
+        if let Some(uid) = process.user_id {
+            let login = self.users.get(&uid);
+            if let Some(user) = self
+                .rules
+                .users
+                .iter()
+                .find(|u| **u == uid.to_string() || Some(*u) == login)
+            {
+                return Some(ProtectionReason::User(user.clone()));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::create_test_process;
+
+    fn process(pid: u32, name: &str, path: Option<&str>, uid: Option<u32>) -> ProcessInfo {
+        ProcessInfo {
+            user_id: uid,
+            executable_path: path.map(str::to_string),
+            ..create_test_process(pid, name, 0.0, 0)
+        }
+    }
+
+    fn rules() -> ProtectedProcesses {
+        ProtectedProcesses {
+            names: vec!["sshd".to_string()],
+            paths: vec!["/System/".to_string()],
+            users: vec!["root".to_string(), "501".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_builtin_rules() {
+        let rules = ProtectedProcesses::default();
+        let own_pid = std::process::id();
+        let mut processes = vec![
+            process(own_pid, "bossy-rust", None, None),
+            process(4242, "zsh", None, None),
+        ];
+        // Started from the shell
+        processes[0].parent_pid = Some(4242);
+        let users = HashMap::new();
+        let check = ProtectionCheck::new(&rules, &processes, &users);
+
+        assert_eq!(check.reason(1, None), Some(ProtectionReason::InitProcess));
+        assert_eq!(
+            check.reason(own_pid, Some(&processes[0])),
+            Some(ProtectionReason::Itself)
+        );
+        assert_eq!(
+            check.reason(4242, Some(&processes[1])),
+            Some(ProtectionReason::Ancestor)
+        );
+        assert_eq!(check.reason(999_999, None), None);
+    }
+
+    #[test]
+    fn test_configured_rules() {
+        let rules = rules();
+        let users = HashMap::from([(0, "root".to_string()), (1000, "dev".to_string())]);
+        let check = ProtectionCheck::new(&rules, &[], &users);
+        let reason = |p: &ProcessInfo| check.reason(p.pid, Some(p));
+
+        assert_eq!(
+            reason(&process(10, "sshd", None, Some(1000))),
+            Some(ProtectionReason::Name("sshd".to_string()))
+        );
+        assert_eq!(
+            reason(&process(
+                11,
+                "Dock",
+                Some("/System/Library/Dock"),
+                Some(1000)
+            )),
+            Some(ProtectionReason::Path("/System/".to_string()))
+        );
+        assert_eq!(
+            reason(&process(12, "cron", None, Some(0))),
+            Some(ProtectionReason::User("root".to_string()))
+        );
+        assert_eq!(
+            reason(&process(13, "node", None, Some(501))),
+            Some(ProtectionReason::User("501".to_string()))
+        );
+        assert_eq!(
+            reason(&process(
+                14,
+                "node",
+                Some("/usr/local/bin/node"),
+                Some(1000)
+            )),
+            None
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+/// A signal that can be sent to a process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(into = "String")]
+pub enum Signal {
+    Hup,
+    Int,
+    Quit,
+    Kill,
+    Usr1,
+    Usr2,
+    Term,
+    Stop,
+    Cont,
+    /// Any other signal, by one of the `OTHER_SIGNALS` names
+    Other(&'static str),
+}
+
+/// Names, without the `SIG` prefix, of the standard signals not listed in
+/// `Signal::ALL`. A few exist on only one of macOS (`EMT`, `INFO`) and
+/// Linux (`PWR`, `STKFLT`); `kill` rejects those on the other.
+pub const OTHER_SIGNALS: [&str; 24] = [
+    "ABRT", "ALRM", "BUS", "CHLD", "EMT", "FPE", "ILL", "INFO", "IO", "PIPE", "PROF", "PWR",
+    "SEGV", "STKFLT", "SYS", "TRAP", "TSTP", "TTIN", "TTOU", "URG", "VTALRM", "WINCH", "XCPU",
+    "XFSZ",
+];
+
+impl Signal {
+    /// In the order the signal picker lists them.
+    pub const ALL: [Signal; 9] = [
+        Signal::Term,
+        Signal::Kill,
+        Signal::Stop,
+        Signal::Cont,
+        Signal::Hup,
+        Signal::Int,
+        Signal::Quit,
+        Signal::Usr1,
+        Signal::Usr2,
+    ];
+
+    /// Name without the `SIG` prefix, as `kill -s` takes it.
+    pub fn short_name(self) -> &'static str {
+        match self {
+            Signal::Hup => "HUP",
+            Signal::Int => "INT",
+            Signal::Quit => "QUIT",
+            Signal::Kill => "KILL",
+            Signal::Usr1 => "USR1",
+            Signal::Usr2 => "USR2",
+            Signal::Term => "TERM",
+            Signal::Stop => "STOP",
+            Signal::Cont => "CONT",
+            Signal::Other(name) => name,
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Signal::Hup => "Hang up; many daemons reload their config",
+            Signal::Int => "Interrupt, like Ctrl+C",
+            Signal::Quit => "Quit and dump core, like Ctrl+\\",
+            Signal::Kill => "Kill immediately; cannot be caught",
+            Signal::Usr1 => "User-defined 1 (diagnostics, log reopening)",
+            Signal::Usr2 => "User-defined 2 (diagnostics, reloads)",
+            Signal::Term => "Ask to terminate gracefully",
+            Signal::Stop => "Pause the process; cannot be caught",
+            Signal::Cont => "Resume a stopped process",
+            Signal::Other("TSTP") => "Pause the process, like Ctrl+Z",
+            Signal::Other(_) => "See `man signal` for what it does",
+        }
+    }
+
+    /// Stopping and resuming are undone by each other; every other signal
+    /// ends the process unless it handles the signal.
+    pub fn is_reversible(self) -> bool {
+        matches!(
+            self,
+            Signal::Stop | Signal::Cont | Signal::Other("TSTP" | "TTIN" | "TTOU")
+        )
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SIG{}", self.short_name())
+    }
+}
+
+impl From<Signal> for String {
+    fn from(signal: Signal) -> Self {
+        signal.to_string()
+    }
+}
+
+impl FromStr for Signal {
+    type Err = anyhow::Error;
+
+    /// Accepts `STOP`, `SIGSTOP` or `stop` for any standard signal, and
+    /// the numbers that are the same on every Unix (1, 2, 3, 9, 15). Other
+    /// numbers differ between macOS and Linux, so those signals must be
+    /// named.
+    fn from_str(value: &str) -> Result<Self> {
+        let upper = value.trim().to_ascii_uppercase();
+        let name = upper.strip_prefix("SIG").unwrap_or(&upper);
+        let by_number = match name {
+            "1" => Some(Signal::Hup),
+            "2" => Some(Signal::Int),
+            "3" => Some(Signal::Quit),
+            "9" => Some(Signal::Kill),
+            "15" => Some(Signal::Term),
+            _ => None,
+        };
+        by_number
+            .or_else(|| Self::ALL.into_iter().find(|s| s.short_name() == name))
+            .or_else(|| {
+                OTHER_SIGNALS
+                    .into_iter()
+                    .find(|&other| other == name)
+                    .map(Signal::Other)
+            })
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|s| s.short_name()).collect();
+                anyhow!(
+                    "unknown signal '{value}' (expected one of {}, or another name such as WINCH)",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_signals() {
+        for value in ["STOP", "SIGSTOP", "stop", "SigStop"] {
+            assert_eq!(value.parse::<Signal>().unwrap(), Signal::Stop);
+        }
+        assert_eq!("9".parse::<Signal>().unwrap(), Signal::Kill);
+        assert_eq!("sigusr1".parse::<Signal>().unwrap(), Signal::Usr1);
+        // STOP is 17 on macOS and 19 on Linux
+        assert!("19".parse::<Signal>().is_err());
+        let error = "SIGBOGUS".parse::<Signal>().unwrap_err().to_string();
+        assert!(error.contains("unknown signal 'SIGBOGUS'"));
+        assert!(error.contains("CONT"));
+    }
+
+    #[test]
+    fn test_parse_other_signals() {
+        for name in ["WINCH", "sigtstp", "SIGALRM"] {
+            let signal = name.parse::<Signal>().unwrap();
+            assert!(matches!(signal, Signal::Other(_)));
+            assert_eq!(
+                signal.to_string(),
+                format!("SIG{}", name.to_ascii_uppercase().trim_start_matches("SIG"))
+            );
+        }
+        assert!("TSTP".parse::<Signal>().unwrap().is_reversible());
+        assert!(!"ALRM".parse::<Signal>().unwrap().is_reversible());
+    }
+
+    #[test]
+    fn test_signal_names() {
+        assert_eq!(Signal::Hup.to_string(), "SIGHUP");
+        assert_eq!(serde_json::to_string(&Signal::Cont).unwrap(), "\"SIGCONT\"");
+        for signal in Signal::ALL {
+            assert_eq!(signal.to_string().parse::<Signal>().unwrap(), signal);
+        }
+        assert!(Signal::Stop.is_reversible());
+        assert!(!Signal::Hup.is_reversible());
+    }
+}
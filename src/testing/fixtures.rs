@@ -14,7 +14,10 @@ pub fn create_test_process(pid: u32, name: &str, cpu: f32, memory: u64) -> Proce
         start_time: 1000,
         user_id: Some(501),
         executable_path: Some(format!("/usr/bin/{}", name)),
+        cwd: None,
         command_line: vec![name.to_string()],
+        nice: None,
+        priority: None,
     }
 }
 
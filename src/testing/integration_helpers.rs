@@ -131,10 +131,10 @@ impl IntegrationTestHelper {
     pub async fn test_cleanup_commands(&self) -> Result<()> {
         // Test cleanup without flags (should require --dev)
         let output = self.execute_cli_command(&["cleanup"]).await?;
-        assert!(output.status.success());
+        assert_eq!(output.status.code(), Some(2));
         
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        assert!(stdout.contains("--dev") || stdout.contains("specify"));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("--dev") || stderr.contains("specify"));
         
         // Test cleanup with --dev flag (safe on test system)
         let output = self.execute_cli_command(&["cleanup", "--dev"]).await?;
@@ -1,7 +1,8 @@
-use crate::tui::{AppState, AppMode};
 use crate::testing::fixtures::*;
 use crate::testing::mocks::tui_mocks::MockBackend;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::tui::mouse::ClickTarget;
+use crate::tui::{AppEvent, AppMode, AppState};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::Terminal;
 // use ratatui::layout::Rect;  // Unused import
 
@@ -59,6 +60,27 @@ impl TUITestHelper {
         self.app.handle_key_event(key_event).await
     }
 
+    /// Simulate a left click on a cell of the last rendered frame
+    pub async fn click(&mut self, column: u16, row: u16) -> anyhow::Result<bool> {
+        let mouse = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        self.app.handle_event(AppEvent::Mouse(mouse)).await
+    }
+
+    /// Click wherever `target` was drawn in the last rendered frame
+    pub async fn click_target(&mut self, target: &ClickTarget) -> anyhow::Result<bool> {
+        let (column, row) = self
+            .app
+            .hit_map
+            .position_of(target)
+            .ok_or_else(|| anyhow::anyhow!("{target:?} is not on screen"))?;
+        self.click(column, row).await
+    }
+
     /// Render the current app state and capture output
     pub fn render(&mut self) -> anyhow::Result<()> {
         self.terminal.draw(|f| {
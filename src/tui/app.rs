@@ -1,9 +1,26 @@
-use crate::config::settings::{load_settings, save_settings, UserSettings};
-use crate::network::{ConnectionInfo, PortInfo, PortManager};
-use crate::process::{ProcessInfo, ProcessMonitor};
+use crate::config::presets::{load_presets, Presets, QuickAction, SearchPattern};
+use crate::config::project::{load_project, Project};
+use crate::config::settings::{
+    apply_env_overrides, load_settings, save_settings, ConfirmationLevel, StartView, UserSettings,
+};
+use crate::network::remote::{reverse_lookup, RemoteSummary};
+use crate::network::{ConnectionInfo, ConnectionState, PortInfo};
+use crate::process::{
+    plan_action, project_pids, run_as_rule, ActionPlan, AuditEntry, AuditLog, KillOutcome,
+    ProcessInfo, ProcessManager, ProcessMonitor, Signal, SystemStats, NICE_MAX, NICE_MIN,
+};
+use crate::tui::collector::{Collector, Snapshot};
+use crate::tui::keymap::{Action, Keymap};
+use crate::tui::mouse::{ClickTarget, HitMap, LastClick, SCROLL_LINES};
+use crate::tui::selection::{connection_label, port_label, process_label, ItemKey, SelectedItem};
+use crate::tui::settings_editor::SettingsField;
 use crate::tui::themes::{Theme, ThemeManager};
+use crate::tui::{AppEvent, EventSender};
 use anyhow::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::IpAddr;
 use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +30,18 @@ pub enum AppMode {
     PortView,
     ConnectionView,
     ThemeSelector,
+    Settings,
+}
+
+impl From<StartView> for AppMode {
+    fn from(view: StartView) -> Self {
+        match view {
+            StartView::Dashboard => AppMode::Dashboard,
+            StartView::Processes => AppMode::ProcessView,
+            StartView::Ports => AppMode::PortView,
+            StartView::Connections => AppMode::ConnectionView,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -30,10 +59,11 @@ pub enum LoadingState {
     RefreshingData,
     KillingProcess(u32),
     KillingPort(u16),
-    SearchingData,
+    RunningAction(String),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SortBy {
     Name,
     Pid,
@@ -44,7 +74,8 @@ pub enum SortBy {
     RemoteAddress,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SortOrder {
     Ascending,
     Descending,
@@ -74,22 +105,131 @@ pub struct AppState {
     pub connections: Vec<ConnectionInfo>,
     pub filtered_connections: Vec<ConnectionInfo>,
 
-    // Monitoring
-    pub process_monitor: ProcessMonitor,
+    // Monitoring; `collector` is None when there is no runtime to run it on
+    pub collector: Option<Collector>,
     pub last_refresh: Instant,
+    // Refresh age and status message visibility as of the last tick, to
+    // redraw only when the footer would change
+    pub screen_clock: (u64, bool),
     pub refresh_interval: Duration,
     pub auto_refresh: bool,
 
     // Multi-selection
     pub selected_items: Vec<usize>,
     pub multi_select_mode: bool,
+    // The multi-selection by identity; `selected_items` are the rows of
+    // these still in the current list
+    pub selection: Vec<SelectedItem>,
 
     // CPU History for sparkline
     pub cpu_history: Vec<u64>,
+    // Used memory as a percentage, kept like the CPU history
+    pub memory_history: Vec<u64>,
+    // Memory, swap, load and uptime from the latest snapshot
+    pub system_stats: SystemStats,
 
     // Theming
     pub themes: Vec<Theme>,
     pub current_theme_index: usize,
+
+    // Presets (ports, process groups, UI settings)
+    pub presets: Presets,
+
+    // Quick actions
+    pub quick_actions_menu: Option<usize>, // Selected row while the popup is open
+    pub running_action: Option<RunningAction>,
+
+    // Remote address popup of the connection view
+    pub remote_details: Option<RemoteDetails>,
+
+    // Signal picker of the process view
+    pub signal_picker: Option<usize>, // Selected row of `Signal::ALL` while open
+
+    // Audit log popup
+    pub history: Option<HistoryPane>,
+
+    // Persisted preferences, including saved searches
+    pub settings: UserSettings,
+    pub search_picker: Option<usize>, // Selected row while the picker is open
+    pub save_search_name: Option<String>, // Name being typed for the current query
+
+    // Key bindings for the list views
+    pub keymap: Keymap,
+
+    // Login names by UID, for the process view's user column
+    pub user_names: HashMap<u32, String>,
+
+    // Project profile (.bossy.toml) for the working directory, and whether
+    // the lists are narrowed to it
+    pub project: Option<Project>,
+    pub project_filter: bool,
+
+    // Background tasks (collection, kills) report back through this
+    pub events: EventSender,
+
+    // Clickable areas of the last frame, and the previous click
+    pub hit_map: HitMap,
+    pub last_click: Option<LastClick>,
+}
+
+/// Result of a kill started from the TUI, sent back by its task.
+#[derive(Debug, Clone)]
+pub enum KillReport {
+    Process {
+        pid: u32,
+        error: Option<String>,
+    },
+    Port {
+        port: u16,
+        result: Result<u32, String>,
+    },
+    Processes {
+        killed: usize,
+    },
+    Signal {
+        signal: Signal,
+        outcomes: Vec<KillOutcome>,
+    },
+    /// Nice values set with +/-, each outcome with the value it asked for
+    Renice(Vec<(KillOutcome, i32)>),
+    /// One target of the running quick action
+    ActionStep(KillOutcome),
+}
+
+/// The connection shown in the remote address popup, with what is known
+/// about its remote end.
+#[derive(Debug, Clone)]
+pub struct RemoteDetails {
+    pub connection: ConnectionInfo,
+    pub summary: RemoteSummary,
+    /// The reverse lookup hasn't answered yet
+    pub resolving: bool,
+}
+
+/// The audit log as shown in the history popup.
+#[derive(Debug, Clone)]
+pub struct HistoryPane {
+    /// Newest first
+    pub entries: Vec<AuditEntry>,
+    /// Index of the first entry shown
+    pub scroll: usize,
+    /// Why the log couldn't be read
+    pub error: Option<String>,
+}
+
+impl HistoryPane {
+    fn scroll_by(&mut self, lines: isize) {
+        let last = self.entries.len().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(lines).min(last);
+    }
+}
+
+/// A confirmed quick action, terminated one target per tick so progress can
+/// be drawn in between.
+#[derive(Debug, Clone)]
+pub struct RunningAction {
+    pub plan: ActionPlan,
+    pub outcomes: Vec<KillOutcome>,
 }
 
 #[derive(Debug, Clone)]
@@ -114,23 +254,45 @@ pub enum DialogAction {
     Process(u32),
     Processes(Vec<u32>),
     Port(u16),
+    QuickAction(ActionPlan),
+    /// Terminate the owners of the selected ports or connections
+    Bulk(ActionPlan),
+    Signal(Vec<u32>, Signal),
 }
 
 impl AppState {
-    pub fn new() -> Result<Self> {
+    pub fn new(events: EventSender) -> Result<Self> {
+        let presets = load_presets()?;
+        let keymap = Keymap::from_config(&presets.keyboard_shortcuts)?;
+        // The first snapshot is collected up front so the UI opens with data
         let mut process_monitor = ProcessMonitor::new();
-        let processes = process_monitor.get_processes();
-        let ports = PortManager::get_all_ports()?;
-        let connections = PortManager::get_active_connections()?;
-        let themes = ThemeManager::get_themes();
-        let settings = load_settings().unwrap_or_default();
+        let Snapshot {
+            processes,
+            ports,
+            connections,
+            system,
+            ..
+        } = Snapshot::collect(&mut process_monitor)?;
+        let (themes, theme_warnings) = ThemeManager::load_themes();
+        let (mut settings, settings_error) = match load_settings() {
+            Ok(settings) => (settings, None),
+            Err(e) => (UserSettings::default(), Some(e)),
+        };
+        let env_error = apply_env_overrides(&mut settings).err();
+        let project = std::env::current_dir()
+            .map_err(anyhow::Error::from)
+            .and_then(|dir| load_project(&dir));
         let current_theme_index = themes
             .iter()
             .position(|t| t.name == settings.theme_name)
             .unwrap_or(0);
+        let refresh_interval = settings
+            .refresh_interval_ms
+            .map(Duration::from_millis)
+            .unwrap_or_else(|| presets.ui_settings.refresh_interval());
 
-        Ok(Self {
-            mode: AppMode::Dashboard,
+        let mut app = Self {
+            mode: settings.start_view.into(),
             should_quit: false,
             search_query: String::new(),
             search_active: false,
@@ -152,22 +314,105 @@ impl AppState {
             connections: connections.clone(),
             filtered_connections: connections,
 
-            process_monitor,
+            collector: Some(Collector::spawn(process_monitor, events.clone())),
             last_refresh: Instant::now(),
-            refresh_interval: Duration::from_secs(2),
-            auto_refresh: true,
+            screen_clock: (0, false),
+            refresh_interval,
+            auto_refresh: settings.auto_refresh,
 
             selected_items: Vec::new(),
             multi_select_mode: false,
+            selection: Vec::new(),
 
             cpu_history: vec![0; 100], // Store last 100 CPU usage points
+            memory_history: vec![0; 100],
+            system_stats: system,
 
             themes,
             current_theme_index,
-        })
+
+            presets,
+
+            quick_actions_menu: None,
+            remote_details: None,
+            signal_picker: None,
+            history: None,
+            running_action: None,
+
+            settings,
+            search_picker: None,
+            save_search_name: None,
+
+            keymap,
+
+            user_names: ProcessManager::get_user_names(),
+
+            project: project.as_ref().ok().cloned().flatten(),
+            project_filter: false,
+
+            events,
+
+            hit_map: HitMap::default(),
+            last_click: None,
+        };
+
+        app.apply_view_sort();
+        app.reset_filters();
+        if let Some(warning) = theme_warnings.first() {
+            let more = match theme_warnings.len() {
+                1 => String::new(),
+                n => format!(" (+{} more)", n - 1),
+            };
+            app.set_status_message(format!("Skipped theme: {warning}{more}"));
+        }
+        if let Err(e) = project {
+            app.set_status_message(format!("Ignoring project profile: {e:#}"));
+        }
+        if let Some(e) = env_error {
+            app.set_status_message(format!("Ignoring environment overrides: {e:#}"));
+        }
+        if let Some(e) = settings_error {
+            app.set_status_message(format!(
+                "Using default settings: {e:#} (run `bossy-rust config check`)"
+            ));
+        }
+        Ok(app)
     }
 
     pub async fn handle_key_event(&mut self, key: KeyEvent) -> Result<()> {
+        self.dispatch_key_event(key).await?;
+        self.skip_relaxed_confirmation();
+        Ok(())
+    }
+
+    /// With relaxed confirmations, low and medium risk kills skip the dialog.
+    fn skip_relaxed_confirmation(&mut self) {
+        if self
+            .confirmation_dialog
+            .as_ref()
+            .is_some_and(|dialog| self.skips_confirmation(dialog))
+        {
+            self.execute_dialog_action();
+        }
+    }
+
+    pub fn requires_typed_confirmation(&self, dialog: &ConfirmationDialog) -> bool {
+        dialog.danger_level == DangerLevel::Critical
+            || self.settings.confirmations == ConfirmationLevel::Strict
+    }
+
+    fn skips_confirmation(&self, dialog: &ConfirmationDialog) -> bool {
+        // Quick actions follow their own `confirmation_required`, and bulk
+        // actions are always previewed
+        self.settings.confirmations == ConfirmationLevel::Relaxed
+            && matches!(dialog.danger_level, DangerLevel::Low | DangerLevel::Medium)
+            && !matches!(
+                dialog.confirm_action,
+                DialogAction::QuickAction(_) | DialogAction::Bulk(_)
+            )
+    }
+
+    async fn dispatch_key_event(&mut self, key: KeyEvent) -> Result<()> {
         // Handle global keys first
         if key.modifiers.contains(KeyModifiers::CONTROL) {
             match key.code {
@@ -176,7 +421,7 @@ impl AppState {
                     return Ok(());
                 }
                 KeyCode::Char('r') => {
-                    self.refresh_data()?;
+                    self.request_refresh();
                     return Ok(());
                 }
                 _ => {}
@@ -185,47 +430,90 @@ impl AppState {
 
         // Handle confirmation dialog
         if let Some(dialog) = &self.confirmation_dialog {
-            match dialog.danger_level {
-                DangerLevel::Critical => {
-                    // Critical operations require typing "YES"
-                    match key.code {
-                        KeyCode::Char(c) if c == 'n' || c == 'N' => {
-                            self.confirmation_dialog = None;
+            if self.requires_typed_confirmation(dialog) {
+                // Critical operations, or every kill with strict confirmations,
+                // require typing "YES"
+                match key.code {
+                    KeyCode::Char(c) if c == 'n' || c == 'N' => {
+                        self.confirmation_dialog = None;
+                        self.critical_confirmation_buffer.clear();
+                    }
+                    KeyCode::Char(c) => {
+                        self.critical_confirmation_buffer
+                            .push(c.to_ascii_uppercase());
+                        if self.critical_confirmation_buffer == "YES" {
                             self.critical_confirmation_buffer.clear();
-                        }
-                        KeyCode::Char(c) => {
-                            self.critical_confirmation_buffer.push(c.to_ascii_uppercase());
-                            if self.critical_confirmation_buffer == "YES" {
-                                self.critical_confirmation_buffer.clear();
-                                self.execute_dialog_action().await?;
-                            } else if !self.critical_confirmation_buffer.is_empty() && !"YES".starts_with(&self.critical_confirmation_buffer) {
-                                // Invalid input, clear buffer
-                                self.critical_confirmation_buffer.clear();
-                                self.set_status_message("Type 'YES' to confirm critical operation".to_string());
-                            }
-                        }
-                        KeyCode::Backspace => {
-                            self.critical_confirmation_buffer.pop();
-                        }
-                        KeyCode::Esc => {
-                            self.confirmation_dialog = None;
+                            self.execute_dialog_action();
+                        } else if !self.critical_confirmation_buffer.is_empty()
+                            && !"YES".starts_with(&self.critical_confirmation_buffer)
+                        {
+                            // Invalid input, clear buffer
                             self.critical_confirmation_buffer.clear();
+                            self.set_status_message(
+                                "Type 'YES' to confirm critical operation".to_string(),
+                            );
                         }
-                        _ => {}
                     }
+                    KeyCode::Backspace => {
+                        self.critical_confirmation_buffer.pop();
+                    }
+                    KeyCode::Esc => {
+                        self.confirmation_dialog = None;
+                        self.critical_confirmation_buffer.clear();
+                    }
+                    _ => {}
                 }
-                _ => {
-                    // Standard confirmation for non-critical operations
-                    match key.code {
-                        KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
-                            self.execute_dialog_action().await?;
-                        }
-                        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                            self.confirmation_dialog = None;
-                        }
-                        _ => {}
+            } else {
+                // Standard confirmation for non-critical operations
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                        self.execute_dialog_action();
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.confirmation_dialog = None;
+                    }
+                    _ => {}
+                }
+            }
+            return Ok(());
+        }
+
+        // Handle naming a search before saving it
+        if let Some(name) = self.save_search_name.as_mut() {
+            match key.code {
+                KeyCode::Enter => {
+                    let name = name.trim().to_string();
+                    self.save_search_name = None;
+                    self.save_current_search(&name);
+                }
+                KeyCode::Esc => self.save_search_name = None,
+                KeyCode::Backspace => {
+                    name.pop();
+                }
+                KeyCode::Char(c) => name.push(c),
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // Handle the saved search picker
+        if let Some(selected) = self.search_picker {
+            let count = self.search_patterns().len();
+            match key.code {
+                KeyCode::Up => self.search_picker = Some(selected.saturating_sub(1)),
+                KeyCode::Down | KeyCode::Tab => {
+                    self.search_picker = Some((selected + 1).min(count.saturating_sub(1)));
+                }
+                KeyCode::Enter => {
+                    self.search_picker = None;
+                    if let Some((_, pattern, _)) = self.search_patterns().get(selected) {
+                        self.search_query = pattern.pattern.clone();
                     }
+                    self.apply_search_filter();
+                    self.search_active = false;
                 }
+                KeyCode::Esc => self.search_picker = None,
+                _ => {}
             }
             return Ok(());
         }
@@ -233,6 +521,14 @@ impl AppState {
         // Handle search mode
         if self.search_active {
             match key.code {
+                KeyCode::Tab => self.open_search_picker(),
+                KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    if self.search_query.trim().is_empty() {
+                        self.set_status_message("Type a search before saving it".to_string());
+                    } else {
+                        self.save_search_name = Some(String::new());
+                    }
+                }
                 KeyCode::Enter => {
                     self.apply_search_filter();
                     self.search_active = false;
@@ -255,6 +551,89 @@ impl AppState {
             return Ok(());
         }
 
+        // Handle the quick actions popup
+        if let Some(selected) = self.quick_actions_menu {
+            let count = self.presets.quick_actions.len();
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.quick_actions_menu = Some(selected.saturating_sub(1));
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.quick_actions_menu = Some((selected + 1).min(count.saturating_sub(1)));
+                }
+                KeyCode::Enter => {
+                    self.quick_actions_menu = None;
+                    if let Some(key) = self.presets.quick_actions.keys().nth(selected).cloned() {
+                        self.prepare_quick_action(&key);
+                    }
+                }
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('a') => {
+                    self.quick_actions_menu = None;
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // Handle the signal picker
+        if let Some(selected) = self.signal_picker {
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.signal_picker = Some(selected.saturating_sub(1));
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.signal_picker = Some((selected + 1).min(Signal::ALL.len() - 1));
+                }
+                KeyCode::Enter => {
+                    self.signal_picker = None;
+                    self.prepare_signal(Signal::ALL[selected]);
+                }
+                KeyCode::Esc | KeyCode::Char('q') => self.signal_picker = None,
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // Handle the remote address popup, from which the connection
+        // actions still work
+        if let Some(details) = &self.remote_details {
+            let connection = details.connection.clone();
+            match self.keymap.action_for(&self.mode, &key) {
+                Some(Action::Kill | Action::PrimaryAction) => {
+                    self.remote_details = None;
+                    self.kill_connection_owner(&connection);
+                }
+                Some(Action::ShowOwner) => {
+                    self.remote_details = None;
+                    self.go_to_owner(connection.pid);
+                }
+                Some(Action::ShowListener) => {
+                    self.remote_details = None;
+                    self.go_to_listener(&connection);
+                }
+                Some(Action::Back | Action::Quit | Action::RemoteDetails) => {
+                    self.remote_details = None;
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // Handle the history popup
+        if let Some(history) = &mut self.history {
+            match self.keymap.action_for(&self.mode, &key) {
+                Some(Action::MoveUp) => history.scroll_by(-1),
+                Some(Action::MoveDown) => history.scroll_by(1),
+                Some(Action::PageUp) => history.scroll_by(-10),
+                Some(Action::PageDown) => history.scroll_by(10),
+                Some(Action::GoToTop) => history.scroll = 0,
+                Some(Action::GoToBottom) => history.scroll_by(isize::MAX),
+                Some(Action::Back | Action::Quit | Action::ShowHistory) => self.history = None,
+                _ => {}
+            }
+            return Ok(());
+        }
+
         // Handle normal mode keys
         if self.mode == AppMode::ThemeSelector {
             match key.code {
@@ -266,16 +645,7 @@ impl AppState {
                         self.selected_index += 1;
                     }
                 }
-                KeyCode::Enter => {
-                    self.current_theme_index = self.selected_index;
-                    let settings = UserSettings {
-                        theme_name: self.themes[self.current_theme_index].name.clone(),
-                    };
-                    if let Err(e) = save_settings(&settings) {
-                        self.set_status_message(format!("Error saving settings: {e}"));
-                    }
-                    self.mode = AppMode::Dashboard; // Go back to dashboard after selection
-                }
+                KeyCode::Enter => self.apply_selected_theme(),
                 KeyCode::Esc | KeyCode::Char('q') => {
                     self.mode = AppMode::Dashboard;
                 }
@@ -284,157 +654,516 @@ impl AppState {
             return Ok(());
         }
 
-        match key.code {
+        if self.mode == AppMode::Settings {
+            let fields = SettingsField::all();
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.selected_index = self.selected_index.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') if self.selected_index + 1 < fields.len() => {
+                    self.selected_index += 1;
+                }
+                KeyCode::Left | KeyCode::Char('h') => {
+                    self.adjust_setting(fields[self.selected_index], false);
+                }
+                KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter | KeyCode::Char(' ') => {
+                    self.adjust_setting(fields[self.selected_index], true);
+                }
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.switch_to_mode(AppMode::Dashboard);
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        let Some(action) = self.keymap.action_for(&self.mode, &key) else {
+            return Ok(());
+        };
+
+        match action {
             // Navigation (Consistent across all modes)
-            KeyCode::Up | KeyCode::Char('k') => self.move_up(),
-            KeyCode::Down | KeyCode::Char('j') => self.move_down(),
-            KeyCode::PageUp | KeyCode::Char('u') => self.page_up(),
-            KeyCode::PageDown | KeyCode::Char('d') => self.page_down(),
-            KeyCode::Home | KeyCode::Char('g') => self.go_to_top(),
-            KeyCode::End | KeyCode::Char('G') => self.go_to_bottom(),
-
-            // Mode switching (Numbers for consistency)
-            KeyCode::Char('1') => self.switch_to_mode(AppMode::Dashboard),
-            KeyCode::Char('2') => self.switch_to_mode(AppMode::ProcessView),
-            KeyCode::Char('3') => self.switch_to_mode(AppMode::PortView),
-            KeyCode::Char('4') => self.switch_to_mode(AppMode::ConnectionView),
-            KeyCode::Char('5') => self.switch_to_mode(AppMode::ThemeSelector),
-            
-            // Legacy F-keys for compatibility
-            KeyCode::F(1) => self.switch_to_mode(AppMode::ProcessView),
-            KeyCode::F(2) => self.switch_to_mode(AppMode::PortView),
-            KeyCode::F(3) => self.switch_to_mode(AppMode::ConnectionView),
-            KeyCode::F(4) | KeyCode::Char('?') | KeyCode::Char('h') => self.toggle_help(),
-
-            // Actions (Consistent mnemonics)
-            KeyCode::Char('/') => self.enter_search_mode(),
-            KeyCode::Char('r') => self.refresh_data()?,
-            KeyCode::Char(' ') => self.toggle_selection(),
-            KeyCode::Enter => self.primary_action().await?,
-            KeyCode::Delete | KeyCode::Char('x') => self.kill_action(),
-
-            // Sorting
-            KeyCode::Char('s') => self.cycle_sort(),
-            
-            // Clear/Reset actions
-            KeyCode::Char('c') => self.clear_selection(),
+            Action::MoveUp => self.move_up(),
+            Action::MoveDown => self.move_down(),
+            Action::PageUp => self.page_up(),
+            Action::PageDown => self.page_down(),
+            Action::GoToTop => self.go_to_top(),
+            Action::GoToBottom => self.go_to_bottom(),
+
+            // Mode switching
+            Action::ShowDashboard => self.switch_to_mode(AppMode::Dashboard),
+            Action::ShowProcesses => self.switch_to_mode(AppMode::ProcessView),
+            Action::ShowPorts => self.switch_to_mode(AppMode::PortView),
+            Action::ShowConnections => self.switch_to_mode(AppMode::ConnectionView),
+            Action::ShowThemes => self.switch_to_mode(AppMode::ThemeSelector),
+            Action::ShowSettings => self.switch_to_mode(AppMode::Settings),
+            Action::Help => self.toggle_help(),
+
+            // Actions
+            Action::Search => self.enter_search_mode(),
+            Action::Refresh => self.request_refresh(),
+            Action::ToggleAutoRefresh => self.toggle_auto_refresh(),
+            Action::ToggleProjectFilter => self.toggle_project_filter(),
+            Action::ToggleSelection => self.toggle_selection(),
+            Action::PrimaryAction => self.primary_action().await?,
+            Action::Kill => self.kill_action(),
+            Action::QuickActions => self.open_quick_actions(),
+            Action::SendSignal => self.open_signal_picker(),
+            Action::RaisePriority => self.adjust_priority(-1),
+            Action::LowerPriority => self.adjust_priority(1),
+            Action::ShowOwner => self.show_owner(),
+            Action::ShowListener => self.show_listener(),
+            Action::RemoteDetails => self.open_remote_details(),
+            Action::ShowHistory => self.open_history(),
+            Action::CycleSort => self.cycle_sort(),
+            Action::ClearSelection => self.clear_selection(),
 
             // Smart Escape handling
-            KeyCode::Esc => self.handle_escape(),
-            
-            // Quit (only 'q' for simplicity)
-            KeyCode::Char('q') => {
+            Action::Back => self.handle_escape(),
+
+            Action::Quit => {
                 if self.show_help {
                     self.show_help = false;
                 } else {
                     self.should_quit = true;
                 }
             }
-
-            _ => {}
         }
 
         Ok(())
     }
 
-    pub fn should_refresh(&self) -> bool {
-        self.auto_refresh && self.last_refresh.elapsed() >= self.refresh_interval
+    /// Switch to the highlighted theme, save it and go back to the dashboard.
+    fn apply_selected_theme(&mut self) {
+        self.current_theme_index = self.selected_index;
+        self.settings.theme_name = self.themes[self.current_theme_index].name.clone();
+        if let Err(e) = save_settings(&self.settings) {
+            self.set_status_message(format!("Error saving settings: {e}"));
+        }
+        self.mode = AppMode::Dashboard; // Go back to dashboard after selection
     }
 
-    pub fn refresh_data(&mut self) -> Result<()> {
-        // Set loading state
-        self.loading_state = LoadingState::RefreshingData;
-        self.app_status = AppStatus::Loading("Refreshing system data...".to_string());
-        
-        // Refresh data
-        self.processes = self.process_monitor.get_processes();
-        self.ports = PortManager::get_all_ports()?;
-        self.connections = PortManager::get_active_connections()?;
+    /// Change a setting from the settings screen, apply it right away and
+    /// save it.
+    fn adjust_setting(&mut self, field: SettingsField, forward: bool) {
+        let default_refresh_ms = self.presets.ui_settings.refresh_interval_ms;
+        if let Err(message) = field.adjust(&mut self.settings, default_refresh_ms, forward) {
+            self.set_status_message(message);
+            return;
+        }
 
-        // Update CPU history with actual system CPU usage (0-100%)
-        let system_cpu_usage = self.process_monitor.get_system_cpu_usage() as u64;
-        self.cpu_history.remove(0);
-        self.cpu_history.push(system_cpu_usage);
+        if let Some(ms) = self.settings.refresh_interval_ms {
+            self.refresh_interval = Duration::from_millis(ms);
+        }
+        self.auto_refresh = self.settings.auto_refresh;
+
+        match save_settings(&self.settings) {
+            Ok(()) => self.set_status_message(format!(
+                "{}: {}",
+                field.label(),
+                field.value(&self.settings, default_refresh_ms)
+            )),
+            Err(e) => self.set_status_message(format!("Error saving settings: {e}")),
+        }
+    }
 
-        self.apply_current_filters();
-        self.last_refresh = Instant::now();
-        
-        // Reset loading state and show success
-        self.loading_state = LoadingState::Idle;
-        self.app_status = AppStatus::Success(format!(
-            "Refreshed {} processes, {} ports, {} connections",
-            self.processes.len(),
-            self.ports.len(),
-            self.connections.len()
-        ));
-        self.set_status_message("Data refreshed successfully".to_string());
-        Ok(())
+    /// Load the sort for the current view from the settings.
+    fn apply_view_sort(&mut self) {
+        let sort = match self.mode {
+            AppMode::Dashboard | AppMode::ProcessView => &self.settings.process_sort,
+            AppMode::PortView => &self.settings.port_sort,
+            AppMode::ConnectionView => &self.settings.connection_sort,
+            AppMode::ThemeSelector | AppMode::Settings => return,
+        };
+        self.sort_by = sort.by.clone();
+        self.sort_order = sort.order.clone();
     }
 
-    pub fn apply_search_filter(&mut self) {
-        if self.search_query.is_empty() {
-            self.reset_filters();
+    fn toggle_auto_refresh(&mut self) {
+        self.auto_refresh = !self.auto_refresh;
+        let state = if self.auto_refresh { "on" } else { "off" };
+        self.set_status_message(format!("Auto-refresh {state}"));
+    }
+
+    fn toggle_project_filter(&mut self) {
+        let Some(project) = &self.project else {
+            self.set_status_message("No .bossy.toml found for this directory".to_string());
             return;
-        }
+        };
 
-        // Set loading state for search
-        self.loading_state = LoadingState::SearchingData;
+        self.project_filter = !self.project_filter;
+        let message = if self.project_filter {
+            format!("Showing only project {}", project.name)
+        } else {
+            "Showing all processes".to_string()
+        };
+        self.apply_current_filters();
+        self.set_status_message(message);
+    }
 
-        match self.mode {
-            AppMode::ProcessView => {
-                self.filtered_processes = self
-                    .processes
-                    .iter()
-                    .filter(|p| p.matches_search(&self.search_query))
-                    .cloned()
-                    .collect();
-                self.sort_processes();
-            }
-            AppMode::PortView => {
-                self.filtered_ports = self
+    /// Keep only the project's processes, plus anything on its ports or
+    /// connected from them.
+    fn apply_project_filter(&mut self) {
+        let Some(project) = self.project.as_ref().filter(|_| self.project_filter) else {
+            return;
+        };
+
+        let ports = project.ports();
+        let port_owners: Vec<(u16, Option<u32>)> = ports
+            .iter()
+            .map(|&port| {
+                let owner = self
                     .ports
                     .iter()
-                    .filter(|p| p.matches_search(&self.search_query))
-                    .cloned()
-                    .collect();
-                self.sort_ports();
+                    .find(|p| p.port == port && p.state == ConnectionState::Listen)
+                    .and_then(|p| p.pid);
+                (port, owner)
+            })
+            .collect();
+        let pids = project_pids(project, &self.processes, &port_owners);
+        let ours = |pid: Option<u32>| pid.is_some_and(|pid| pids.contains(&pid));
+
+        self.filtered_processes.retain(|p| pids.contains(&p.pid));
+        self.filtered_ports
+            .retain(|p| ports.contains(&p.port) || ours(p.pid));
+        self.filtered_connections
+            .retain(|c| ports.contains(&c.local_address.port()) || ours(c.pid));
+    }
+
+    /// Handle one event from the event loop. Returns whether the screen
+    /// needs redrawing.
+    pub async fn handle_event(&mut self, event: AppEvent) -> Result<bool> {
+        match event {
+            AppEvent::Key(key) => self.handle_key_event(key).await?,
+            AppEvent::Paste(text) => {
+                if !self.search_active {
+                    return Ok(false);
+                }
+                // Pasted line breaks would end up in the query
+                self.search_query
+                    .extend(text.chars().filter(|c| !c.is_control()));
+                self.apply_search_filter();
             }
-            AppMode::ConnectionView => {
-                self.filtered_connections = self
-                    .connections
-                    .iter()
-                    .filter(|c| c.matches_search(&self.search_query))
-                    .cloned()
-                    .collect();
-                // TODO: Add sorting for connections if needed
+            // Data may be stale after the terminal sat in the background
+            AppEvent::FocusGained => self.request_refresh(),
+            AppEvent::FocusLost => return Ok(false),
+            AppEvent::Mouse(mouse) => return self.handle_mouse_event(mouse).await,
+            AppEvent::Resize(..) => {}
+            AppEvent::Refresh => return Ok(self.tick()),
+            AppEvent::Snapshot(received) => self.receive_snapshot(received),
+            AppEvent::Kill(report) => self.receive_kill_report(report),
+            AppEvent::ReverseLookup(address, name) => {
+                return Ok(self.receive_reverse_lookup(address, name))
             }
-            _ => {}
         }
-
-        // Reset loading state after search completes
-        self.loading_state = LoadingState::Idle;
-        self.selected_index = 0;
+        Ok(true)
     }
 
-    fn reset_filters(&mut self) {
-        self.filtered_processes = self.processes.clone();
-        self.filtered_ports = self.ports.clone();
-        self.filtered_connections = self.connections.clone();
-        self.apply_current_sorts();
-        if self.mode != AppMode::ThemeSelector {
-            self.selected_index = 0;
+    /// Handle a mouse event against the hit map of the last frame. Returns
+    /// whether anything changed.
+    async fn handle_mouse_event(&mut self, mouse: MouseEvent) -> Result<bool> {
+        match mouse.kind {
+            MouseEventKind::ScrollUp => Ok(self.scroll(false)),
+            MouseEventKind::ScrollDown => Ok(self.scroll(true)),
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(target) = self.hit_map.target_at(mouse.column, mouse.row).cloned() else {
+                    self.last_click = None;
+                    return Ok(false);
+                };
+                let double = self
+                    .last_click
+                    .take()
+                    .is_some_and(|last| last.is_double(&target));
+                if !double {
+                    self.last_click = Some(LastClick {
+                        target: target.clone(),
+                        at: Instant::now(),
+                    });
+                }
+                self.click(target, double).await?;
+                self.skip_relaxed_confirmation();
+                Ok(true)
+            }
+            _ => Ok(false),
         }
     }
 
-    fn apply_current_filters(&mut self) {
-        if self.search_query.is_empty() {
-            self.reset_filters();
-        } else {
-            self.apply_search_filter();
+    async fn click(&mut self, target: ClickTarget, double: bool) -> Result<()> {
+        match target {
+            ClickTarget::View(mode) => {
+                if mode != self.mode {
+                    self.switch_to_mode(mode);
+                }
+            }
+            ClickTarget::Sort(by) => self.sort_by_column(by),
+            ClickTarget::Row(index) => {
+                self.selected_index = index;
+                if double {
+                    self.activate_row().await?;
+                }
+            }
+            ClickTarget::ConfirmDialog => self.execute_dialog_action(),
+            ClickTarget::CancelDialog => {
+                self.confirmation_dialog = None;
+                self.critical_confirmation_buffer.clear();
+            }
         }
+        Ok(())
     }
 
-    fn apply_current_sorts(&mut self) {
+    /// What Enter does on the selected row, for double clicks.
+    async fn activate_row(&mut self) -> Result<()> {
+        match self.mode {
+            AppMode::ThemeSelector => self.apply_selected_theme(),
+            AppMode::Settings => {
+                if let Some(&field) = SettingsField::all().get(self.selected_index) {
+                    self.adjust_setting(field, true);
+                }
+            }
+            _ => self.primary_action().await?,
+        }
+        Ok(())
+    }
+
+    /// Move the selection by one scroll wheel step. Popups don't scroll,
+    /// except the history.
+    fn scroll(&mut self, down: bool) -> bool {
+        if let Some(history) = &mut self.history {
+            let lines = SCROLL_LINES as isize;
+            history.scroll_by(if down { lines } else { -lines });
+            return true;
+        }
+        if self.show_help
+            || self.confirmation_dialog.is_some()
+            || self.quick_actions_menu.is_some()
+            || self.remote_details.is_some()
+            || self.signal_picker.is_some()
+            || self.search_picker.is_some()
+            || self.save_search_name.is_some()
+        {
+            return false;
+        }
+
+        let last = self.list_len().saturating_sub(1);
+        let index = if down {
+            (self.selected_index + SCROLL_LINES).min(last)
+        } else {
+            self.selected_index.saturating_sub(SCROLL_LINES)
+        };
+        let changed = index != self.selected_index;
+        self.selected_index = index;
+        changed
+    }
+
+    /// Number of selectable rows in the current view.
+    fn list_len(&self) -> usize {
+        match self.mode {
+            AppMode::Dashboard => 0,
+            AppMode::ProcessView => self.filtered_processes.len(),
+            AppMode::PortView => self.filtered_ports.len(),
+            AppMode::ConnectionView => self.filtered_connections.len(),
+            AppMode::ThemeSelector => self.themes.len(),
+            AppMode::Settings => SettingsField::all().len(),
+        }
+    }
+
+    /// Handle a timer tick: start a refresh when one is due. Returns whether
+    /// anything on screen changed.
+    pub fn tick(&mut self) -> bool {
+        let mut changed = false;
+        if self.should_refresh() {
+            self.request_refresh();
+            changed = true;
+        }
+
+        // "Last refresh: Ns ago" and the status message timeout change on their own
+        let clock = (
+            self.last_refresh.elapsed().as_secs(),
+            self.get_status_message().is_some(),
+        );
+        if clock != self.screen_clock {
+            self.screen_clock = clock;
+            changed = true;
+        }
+        changed
+    }
+
+    pub fn should_refresh(&self) -> bool {
+        // Refreshing mid-action would reset its progress state
+        self.auto_refresh
+            && self.running_action.is_none()
+            && !self.collector.as_ref().is_some_and(Collector::is_busy)
+            && self.last_refresh.elapsed() >= self.refresh_interval
+    }
+
+    /// Ask the collector for fresh data. Returns immediately; the snapshot is
+    /// applied by `receive_snapshot` once it arrives.
+    pub fn request_refresh(&mut self) {
+        let Some(collector) = self.collector.as_mut() else {
+            return;
+        };
+        if let Err(e) = collector.request() {
+            self.report_collector_failure(e);
+            return;
+        }
+        if self.loading_state == LoadingState::Idle {
+            self.loading_state = LoadingState::RefreshingData;
+            self.app_status = AppStatus::Loading("Refreshing system data...".to_string());
+        }
+    }
+
+    /// Handle a snapshot from the collector. A failed collection keeps the
+    /// previous data on screen.
+    pub fn receive_snapshot(&mut self, received: Result<Snapshot>) {
+        let next = self.collector.as_mut().map_or(Ok(()), Collector::finished);
+        match received {
+            Ok(snapshot) => self.apply_snapshot(snapshot),
+            Err(e) => {
+                // Retry after the usual interval rather than on every tick
+                self.last_refresh = Instant::now();
+                self.finish_refresh(AppStatus::Error(format!("Refresh failed: {e:#}")));
+                self.set_status_message(format!("Refresh failed: {e:#}"));
+            }
+        }
+        if let Err(e) = next {
+            self.report_collector_failure(e);
+        }
+    }
+
+    fn report_collector_failure(&mut self, error: anyhow::Error) {
+        // Retry after the usual interval rather than on every tick
+        self.last_refresh = Instant::now();
+        self.app_status = AppStatus::Error(format!("Refresh failed: {error:#}"));
+        self.set_status_message(format!("Refresh failed: {error:#}"));
+    }
+
+    pub fn apply_snapshot(&mut self, snapshot: Snapshot) {
+        let elapsed = snapshot.elapsed;
+        self.processes = snapshot.processes;
+        self.ports = snapshot.ports;
+        self.connections = snapshot.connections;
+
+        // Update CPU history with actual system CPU usage (0-100%)
+        self.cpu_history.remove(0);
+        self.cpu_history.push(snapshot.system_cpu as u64);
+        self.memory_history.remove(0);
+        self.memory_history
+            .push(snapshot.system.memory_percent().round() as u64);
+        self.system_stats = snapshot.system;
+
+        let cursor = self.cursor_key();
+        let exited_before: Vec<bool> = self.selection.iter().map(|item| item.exited).collect();
+        self.apply_current_filters();
+        self.follow_cursor(cursor);
+        self.last_refresh = Instant::now();
+
+        self.finish_refresh(AppStatus::Success(format!(
+            "Refreshed {} processes, {} ports, {} connections in {}ms",
+            self.processes.len(),
+            self.ports.len(),
+            self.connections.len(),
+            elapsed.as_millis()
+        )));
+        self.set_status_message("Data refreshed successfully".to_string());
+
+        let exited: Vec<&str> = self
+            .selection
+            .iter()
+            .zip(exited_before)
+            .filter(|(item, before)| item.exited && !before)
+            .map(|(item, _)| item.label.as_str())
+            .collect();
+        if !exited.is_empty() {
+            let items = if exited.len() == 1 { "item" } else { "items" };
+            self.set_status_message(format!("Selected {items} exited: {}", exited.join(", ")));
+        }
+    }
+
+    fn finish_refresh(&mut self, status: AppStatus) {
+        // Leave the state of a kill or action started meanwhile alone
+        if self.loading_state != LoadingState::RefreshingData {
+            return;
+        }
+        self.app_status = status;
+        // A queued request is already being collected
+        if !self.collector.as_ref().is_some_and(Collector::is_busy) {
+            self.loading_state = LoadingState::Idle;
+        }
+    }
+
+    pub fn apply_search_filter(&mut self) {
+        if self.search_query.is_empty() {
+            self.reset_filters();
+            return;
+        }
+
+        match self.mode {
+            AppMode::ProcessView => {
+                self.filtered_processes = self
+                    .processes
+                    .iter()
+                    .filter(|p| p.matches_search(&self.search_query))
+                    .cloned()
+                    .collect();
+                self.sort_processes();
+            }
+            AppMode::PortView => {
+                self.filtered_ports = self
+                    .ports
+                    .iter()
+                    .filter(|p| p.matches_search(&self.search_query))
+                    .cloned()
+                    .collect();
+                self.sort_ports();
+            }
+            AppMode::ConnectionView => {
+                self.filtered_connections = self
+                    .connections
+                    .iter()
+                    .filter(|c| c.matches_search(&self.search_query))
+                    .cloned()
+                    .collect();
+                // TODO: Add sorting for connections if needed
+            }
+            _ => {}
+        }
+        self.apply_project_filter();
+        self.apply_display_limits();
+
+        self.selected_index = 0;
+        self.sync_selection();
+    }
+
+    fn reset_filters(&mut self) {
+        self.filtered_processes = self.processes.clone();
+        self.filtered_ports = self.ports.clone();
+        self.filtered_connections = self.connections.clone();
+        self.apply_current_sorts();
+        self.apply_project_filter();
+        self.apply_display_limits();
+        if !matches!(self.mode, AppMode::ThemeSelector | AppMode::Settings) {
+            self.selected_index = 0;
+        }
+        self.sync_selection();
+    }
+
+    fn apply_current_filters(&mut self) {
+        if self.search_query.is_empty() {
+            self.reset_filters();
+        } else {
+            self.apply_search_filter();
+        }
+    }
+
+    /// Cap the lists to `max_process_display` / `max_port_display` from the
+    /// presets, after sorting so the most relevant rows are kept.
+    fn apply_display_limits(&mut self) {
+        let ui = &self.presets.ui_settings;
+        self.filtered_processes.truncate(ui.max_process_display);
+        self.filtered_ports.truncate(ui.max_port_display);
+    }
+
+    fn apply_current_sorts(&mut self) {
         self.sort_processes();
         self.sort_ports();
         self.sort_connections();
@@ -594,20 +1323,121 @@ impl AppState {
     }
 
     fn toggle_selection(&mut self) {
-        if !self.multi_select_mode {
-            self.multi_select_mode = true;
-            self.selected_items.clear();
-        }
+        let Some((key, label)) = self.row_identity(self.selected_index) else {
+            return;
+        };
 
-        if self.selected_items.contains(&self.selected_index) {
-            self.selected_items.retain(|&x| x != self.selected_index);
+        if let Some(position) = self.selection.iter().position(|item| item.key == key) {
+            self.selection.remove(position);
         } else {
-            self.selected_items.push(self.selected_index);
+            self.selection.push(SelectedItem {
+                key,
+                label,
+                exited: false,
+            });
+        }
+        self.sync_selection();
+    }
+
+    /// Identity and label of a row of the current view's list. The dashboard
+    /// shares the process list.
+    fn row_identity(&self, index: usize) -> Option<(ItemKey, String)> {
+        match self.mode {
+            AppMode::Dashboard | AppMode::ProcessView => self
+                .filtered_processes
+                .get(index)
+                .map(|p| (ItemKey::process(p), process_label(p))),
+            AppMode::PortView => self
+                .filtered_ports
+                .get(index)
+                .map(|p| (ItemKey::port(p), port_label(p))),
+            AppMode::ConnectionView => self
+                .filtered_connections
+                .get(index)
+                .map(|c| (ItemKey::connection(c), connection_label(c))),
+            AppMode::ThemeSelector | AppMode::Settings => None,
+        }
+    }
+
+    /// Keys of the current view's rows, in display order.
+    fn row_keys(&self) -> Vec<ItemKey> {
+        match self.mode {
+            AppMode::Dashboard | AppMode::ProcessView => self
+                .filtered_processes
+                .iter()
+                .map(ItemKey::process)
+                .collect(),
+            AppMode::PortView => self.filtered_ports.iter().map(ItemKey::port).collect(),
+            AppMode::ConnectionView => self
+                .filtered_connections
+                .iter()
+                .map(ItemKey::connection)
+                .collect(),
+            AppMode::ThemeSelector | AppMode::Settings => Vec::new(),
+        }
+    }
+
+    /// Whether an item is still in the collected data, filtered out or not.
+    fn item_exists(&self, key: &ItemKey) -> bool {
+        match key {
+            ItemKey::Process { .. } => self.processes.iter().any(|p| &ItemKey::process(p) == key),
+            ItemKey::Port { .. } => self.ports.iter().any(|p| &ItemKey::port(p) == key),
+            ItemKey::Connection { .. } => self
+                .connections
+                .iter()
+                .any(|c| &ItemKey::connection(c) == key),
         }
+    }
+
+    /// Recompute `selected_items` and the exited marks after the lists
+    /// changed.
+    fn sync_selection(&mut self) {
+        let keys = self.row_keys();
+        self.selected_items = self
+            .selection
+            .iter()
+            .filter_map(|item| keys.iter().position(|key| key == &item.key))
+            .collect();
 
-        if self.selected_items.is_empty() {
-            self.multi_select_mode = false;
+        let exited: Vec<bool> = self
+            .selection
+            .iter()
+            .map(|item| !self.item_exists(&item.key))
+            .collect();
+        for (item, exited) in self.selection.iter_mut().zip(exited) {
+            item.exited = exited;
         }
+        self.multi_select_mode = !self.selection.is_empty();
+    }
+
+    /// The item under the cursor, to find again after the list changes.
+    fn cursor_key(&self) -> Option<ItemKey> {
+        self.row_identity(self.selected_index).map(|(key, _)| key)
+    }
+
+    /// Put the cursor back on `key` if it is still listed. Otherwise it
+    /// stays at the same position, on whatever moved up into it.
+    fn follow_cursor(&mut self, key: Option<ItemKey>) {
+        let keys = self.row_keys();
+        if let Some(index) = key.and_then(|key| keys.iter().position(|k| k == &key)) {
+            self.selected_index = index;
+        } else {
+            self.selected_index = self.selected_index.min(keys.len().saturating_sub(1));
+        }
+    }
+
+    /// Re-sort the lists, keeping the cursor and selection on their items.
+    fn resort(&mut self) {
+        let cursor = self.cursor_key();
+        self.apply_current_sorts();
+        self.follow_cursor(cursor);
+        self.sync_selection();
+    }
+
+    fn reset_selection(&mut self) {
+        self.selection.clear();
+        self.selected_items.clear();
+        self.multi_select_mode = false;
     }
 
     fn cycle_sort(&mut self) {
@@ -638,7 +1468,31 @@ impl AppState {
             _ => {}
         }
 
-        self.apply_current_sorts();
+        self.resort();
+        self.announce_sort();
+    }
+
+    /// Sort by a clicked column. A new column starts in its natural order;
+    /// clicking the sorted column again flips it.
+    fn sort_by_column(&mut self, by: SortBy) {
+        if self.sort_by == by {
+            self.sort_order = match self.sort_order {
+                SortOrder::Ascending => SortOrder::Descending,
+                SortOrder::Descending => SortOrder::Ascending,
+            };
+        } else {
+            self.sort_order = match by {
+                SortBy::Cpu | SortBy::Memory => SortOrder::Descending,
+                _ => SortOrder::Ascending,
+            };
+            self.sort_by = by;
+        }
+
+        self.resort();
+        self.announce_sort();
+    }
+
+    fn announce_sort(&mut self) {
         self.set_status_message(format!(
             "Sorted by {:?} ({})",
             self.sort_by,
@@ -662,14 +1516,24 @@ impl AppState {
                     self.show_kill_port_dialog(port.port);
                 }
             }
+            AppMode::ConnectionView => {
+                if let Some(connection) =
+                    self.filtered_connections.get(self.selected_index).cloned()
+                {
+                    self.kill_connection_owner(&connection);
+                }
+            }
             _ => {}
         }
         Ok(())
     }
 
     fn kill_action(&mut self) {
-        if self.multi_select_mode && !self.selected_items.is_empty() {
-            self.show_kill_multiple_dialog();
+        if !self.selection.is_empty() {
+            match self.mode {
+                AppMode::PortView | AppMode::ConnectionView => self.show_kill_owners_dialog(),
+                _ => self.show_kill_multiple_dialog(),
+            }
         } else {
             match self.mode {
                 AppMode::ProcessView => {
@@ -682,18 +1546,148 @@ impl AppState {
                         self.show_kill_port_dialog(port.port);
                     }
                 }
+                AppMode::ConnectionView => {
+                    if let Some(connection) =
+                        self.filtered_connections.get(self.selected_index).cloned()
+                    {
+                        self.kill_connection_owner(&connection);
+                    }
+                }
                 _ => {}
             }
         }
     }
 
+    /// Ask to terminate the process that owns a connection.
+    fn kill_connection_owner(&mut self, connection: &ConnectionInfo) {
+        match connection.pid {
+            Some(pid) => self.show_kill_process_dialog(pid),
+            None => self.set_status_message(format!(
+                "No owning process known for {}",
+                connection_label(connection)
+            )),
+        }
+    }
+
+    /// Jump from the selected port or connection to its owning process.
+    fn show_owner(&mut self) {
+        let owner = match self.mode {
+            AppMode::PortView => self.filtered_ports.get(self.selected_index).map(|p| p.pid),
+            AppMode::ConnectionView => self
+                .filtered_connections
+                .get(self.selected_index)
+                .map(|c| c.pid),
+            _ => None,
+        };
+        if let Some(pid) = owner {
+            self.go_to_owner(pid);
+        }
+    }
+
+    /// Open the process view with the cursor on `pid`.
+    fn go_to_owner(&mut self, pid: Option<u32>) {
+        let Some(pid) = pid else {
+            self.set_status_message("No owning process known".to_string());
+            return;
+        };
+        let Some(process) = self.processes.iter().find(|p| p.pid == pid) else {
+            self.set_status_message(format!("Process {pid} is no longer running"));
+            return;
+        };
+        let (key, label) = (ItemKey::process(process), process_label(process));
+
+        self.switch_to_mode(AppMode::ProcessView);
+        self.reveal(key);
+        self.set_status_message(format!("Owner: {label}"));
+    }
+
+    /// Jump from the selected connection to the listening port it belongs to.
+    fn show_listener(&mut self) {
+        if self.mode != AppMode::ConnectionView {
+            return;
+        }
+        if let Some(connection) = self.filtered_connections.get(self.selected_index).cloned() {
+            self.go_to_listener(&connection);
+        }
+    }
+
+    /// Open the port view with the cursor on the listener for `connection`.
+    fn go_to_listener(&mut self, connection: &ConnectionInfo) {
+        let Some(listener) = listener_for(&self.ports, connection) else {
+            self.set_status_message(format!(
+                "No listening port for {}",
+                connection_label(connection)
+            ));
+            return;
+        };
+        let (key, label) = (ItemKey::port(listener), port_label(listener));
+
+        self.switch_to_mode(AppMode::PortView);
+        self.reveal(key);
+        self.set_status_message(format!("Listener: {label}"));
+    }
+
+    /// Put the cursor on `key`, dropping the search and project filter if
+    /// they hide it.
+    fn reveal(&mut self, key: ItemKey) {
+        if !self.row_keys().contains(&key) {
+            self.search_active = false;
+            self.search_query.clear();
+            self.project_filter = false;
+            self.reset_filters();
+        }
+        self.follow_cursor(Some(key));
+    }
+
+    /// Show what is known about the selected connection's remote address.
+    /// The reverse lookup can take a while, so it runs on a blocking task
+    /// and comes back as `AppEvent::ReverseLookup`.
+    fn open_remote_details(&mut self) {
+        if self.mode != AppMode::ConnectionView {
+            return;
+        }
+        let Some(connection) = self.filtered_connections.get(self.selected_index).cloned() else {
+            return;
+        };
+
+        let address = connection.remote_address.ip();
+        let resolving = !address.is_unspecified();
+        if resolving {
+            let events = self.events.clone();
+            tokio::task::spawn_blocking(move || {
+                let name = reverse_lookup(address);
+                let _ = events.send(AppEvent::ReverseLookup(address, name));
+            });
+        }
+
+        self.remote_details = Some(RemoteDetails {
+            connection,
+            summary: RemoteSummary::local(address),
+            resolving,
+        });
+    }
+
+    /// Fill in the reverse lookup if its popup is still open. Returns
+    /// whether the popup changed.
+    pub fn receive_reverse_lookup(&mut self, address: IpAddr, name: Option<String>) -> bool {
+        match self
+            .remote_details
+            .as_mut()
+            .filter(|details| details.resolving && details.summary.address == address)
+        {
+            Some(details) => {
+                details.summary.reverse_name = name;
+                details.resolving = false;
+                true
+            }
+            None => false,
+        }
+    }
+
     fn show_kill_process_dialog(&mut self, pid: u32) {
         // Create dialog regardless of whether process exists (for testing)
-        let process_info = self
-            .processes
-            .iter()
-            .find(|p| p.pid == pid);
-            
+        let process_info = self.processes.iter().find(|p| p.pid == pid);
+
         let process_name = process_info
             .map(|p| p.name.clone())
             .unwrap_or_else(|| format!("PID {pid}"));
@@ -772,13 +1766,28 @@ impl AppState {
     }
 
     fn show_kill_multiple_dialog(&mut self) {
-        let count = self.selected_items.len();
-        let process_names: Vec<String> = self.selected_items
+        // Exited items stay selected to show they are gone, but are skipped
+        let targets: Vec<(u32, &str)> = self
+            .selection
             .iter()
-            .filter_map(|&index| match self.mode {
-                AppMode::ProcessView => self.filtered_processes.get(index).map(|p| p.name.clone()),
+            .filter(|item| !item.exited)
+            .filter_map(|item| match item.key {
+                ItemKey::Process { pid, .. } => Some((pid, item.label.as_str())),
                 _ => None,
             })
+            .collect();
+        let exited = self.selection.iter().filter(|item| item.exited).count();
+        if targets.is_empty() {
+            if exited > 0 {
+                self.set_status_message("The selected processes have exited".to_string());
+            }
+            return;
+        }
+
+        let count = targets.len();
+        let process_names: Vec<&str> = targets
+            .iter()
+            .map(|&(_, label)| label)
             .take(5) // Show max 5 names
             .collect();
             
@@ -787,6 +1796,11 @@ impl AppState {
         } else {
             process_names.join(", ")
         };
+        let skipped = match exited {
+            0 => String::new(),
+            1 => "\n\n1 selected process has already exited and will be skipped.".to_string(),
+            n => format!("\n\n{n} selected processes have already exited and will be skipped."),
+        };
         
         let danger_level = if count > 10 {
             DangerLevel::Critical
@@ -799,82 +1813,630 @@ impl AppState {
         self.confirmation_dialog = Some(ConfirmationDialog {
             title: "Terminate Multiple Processes".to_string(),
             message: format!(
-                "Are you sure you want to terminate {} processes?\n\nProcesses: {}\n\nThis is a bulk operation and cannot be undone.",
-                count, process_list
-            ),
-            confirm_action: DialogAction::Processes(
-                self.selected_items
-                    .iter()
-                    .filter_map(|&index| match self.mode {
-                        AppMode::ProcessView => self.filtered_processes.get(index).map(|p| p.pid),
-                        _ => None,
-                    })
-                    .collect(),
+                "Are you sure you want to terminate {} processes?\n\nProcesses: {}{}\n\nThis is a bulk operation and cannot be undone.",
+                count, process_list, skipped
             ),
+            confirm_action: DialogAction::Processes(targets.iter().map(|&(pid, _)| pid).collect()),
             danger_level,
             context_info: Some(format!("Total processes: {}", count)),
         });
     }
 
-    async fn execute_dialog_action(&mut self) -> Result<()> {
-        if let Some(dialog) = self.confirmation_dialog.take() {
-            match dialog.confirm_action {
-                DialogAction::Process(pid) => {
-                    self.loading_state = LoadingState::KillingProcess(pid);
-                    self.app_status = AppStatus::Processing(format!("Terminating process {}...", pid));
-                    
-                    match crate::process::ProcessKiller::kill_process_by_pid(pid, false).await {
-                        Ok(()) => {
-                            self.loading_state = LoadingState::Idle;
-                            self.app_status = AppStatus::Success(format!("Successfully killed process {}", pid));
-                            self.set_status_message(format!("Successfully killed process {pid}"));
-                            self.refresh_data()?;
-                        }
-                        Err(e) => {
-                            self.loading_state = LoadingState::Idle;
-                            self.app_status = AppStatus::Error(format!("Failed to kill process {}: {}", pid, e));
-                            self.set_status_message(format!("Failed to kill process {pid}: {e}"));
-                        }
-                    }
-                }
-                DialogAction::Port(port) => {
-                    self.loading_state = LoadingState::KillingPort(port);
-                    self.app_status = AppStatus::Processing(format!("Killing process on port {}...", port));
-                    
-                    match crate::process::ProcessKiller::kill_process_by_port(port).await {
-                        Ok(pid) => {
-                            self.loading_state = LoadingState::Idle;
-                            self.app_status = AppStatus::Success(format!("Successfully killed process {} using port {}", pid, port));
-                            self.set_status_message(format!(
-                                "Successfully killed process {pid} using port {port}"
-                            ));
-                            self.refresh_data()?;
-                        }
-                        Err(e) => {
-                            self.loading_state = LoadingState::Idle;
-                            self.app_status = AppStatus::Error(format!("Failed to kill port {}: {}", port, e));
-                            self.set_status_message(format!("Failed to kill port {port}: {e}"));
-                        }
-                    }
-                }
-                DialogAction::Processes(pids) => {
-                    let mut success_count = 0;
+    /// Preview terminating the processes behind the selected ports or
+    /// connections, each owning process once.
+    fn show_kill_owners_dialog(&mut self) {
+        let mut owners: Vec<(u16, Option<u32>)> = self
+            .selection
+            .iter()
+            .filter(|item| !item.exited)
+            .filter_map(|item| match &item.key {
+                ItemKey::Port { .. } => self
+                    .ports
+                    .iter()
+                    .find(|p| ItemKey::port(p) == item.key)
+                    .map(|p| (p.port, p.pid)),
+                ItemKey::Connection { local_address, .. } => self
+                    .connections
+                    .iter()
+                    .find(|c| ItemKey::connection(c) == item.key)
+                    .map(|c| (local_address.port(), c.pid)),
+                ItemKey::Process { .. } => None,
+            })
+            .collect();
+        // The same port is often listed for IPv4 and IPv6
+        owners.sort();
+        owners.dedup();
+
+        let (noun, key, name, title) = match self.mode {
+            AppMode::PortView => (
+                "port",
+                "selected_ports",
+                "Free selected ports",
+                "Free Selected Ports",
+            ),
+            _ => (
+                "connection",
+                "selected_connections",
+                "Kill connection owners",
+                "Kill Connection Owners",
+            ),
+        };
+        let selected = self.selection.len();
+        let exited = self.selection.iter().filter(|item| item.exited).count();
+        let action = QuickAction {
+            name: name.to_string(),
+            description: format!("{selected} selected {noun}(s)"),
+            processes: Vec::new(),
+            ports: Vec::new(),
+            confirmation_required: true,
+        };
+        let plan = ActionPlan::build(key, &action, &self.processes, &owners);
+        if plan.is_empty() {
+            self.set_status_message(format!("No owning process found for the selected {noun}s"));
+            return;
+        }
+
+        let mut message = format!(
+            "Terminate {} process(es) owning the selected {noun}s?\n\nPIDs: {}",
+            plan.targets.len(),
+            describe_targets(&plan).join(", ")
+        );
+        if !plan.free_ports.is_empty() {
+            let unowned: Vec<String> = plan.free_ports.iter().map(|p| format!(":{p}")).collect();
+            message.push_str(&format!("\nNo owner found: {}", unowned.join(" ")));
+        }
+        if !plan.held_ports.is_empty() {
+            let held: Vec<String> = plan.held_ports.iter().map(|p| format!(":{p}")).collect();
+            message.push_str(&format!(
+                "\nSkipped, held by bossy-rust: {}",
+                held.join(" ")
+            ));
+        }
+        if exited > 0 {
+            message.push_str(&format!(
+                "\n{exited} selected {noun}(s) already gone, skipped"
+            ));
+        }
+
+        self.confirmation_dialog = Some(ConfirmationDialog {
+            title: title.to_string(),
+            message,
+            danger_level: plan_danger(&plan),
+            context_info: Some(action.description),
+            confirm_action: DialogAction::Bulk(plan),
+        });
+    }
+
+    /// Start the confirmed kill on its own task; the outcome comes back as
+    /// `AppEvent::Kill`.
+    fn execute_dialog_action(&mut self) {
+        let Some(dialog) = self.confirmation_dialog.take() else {
+            return;
+        };
+        let events = self.events.clone();
+
+        match dialog.confirm_action {
+            DialogAction::Process(pid) => {
+                self.loading_state = LoadingState::KillingProcess(pid);
+                self.app_status = AppStatus::Processing(format!("Terminating process {}...", pid));
+                tokio::spawn(async move {
+                    let error = crate::process::ProcessKiller::kill_process_by_pid(pid, false)
+                        .await
+                        .err()
+                        .map(|e| e.to_string());
+                    let _ = events.send(AppEvent::Kill(KillReport::Process { pid, error }));
+                });
+            }
+            DialogAction::Port(port) => {
+                self.loading_state = LoadingState::KillingPort(port);
+                self.app_status =
+                    AppStatus::Processing(format!("Killing process on port {}...", port));
+                tokio::spawn(async move {
+                    let result = crate::process::ProcessKiller::kill_process_by_port(port)
+                        .await
+                        .map_err(|e| e.to_string());
+                    let _ = events.send(AppEvent::Kill(KillReport::Port { port, result }));
+                });
+            }
+            DialogAction::Processes(pids) => {
+                self.reset_selection();
+                tokio::spawn(async move {
+                    let mut killed = 0;
                     for pid in pids {
                         if crate::process::ProcessKiller::kill_process_by_pid(pid, false)
                             .await
                             .is_ok()
                         {
-                            success_count += 1;
+                            killed += 1;
                         }
                     }
-                    self.set_status_message(format!("Killed {success_count} processes"));
-                    self.multi_select_mode = false;
-                    self.selected_items.clear();
-                    self.refresh_data()?;
+                    let _ = events.send(AppEvent::Kill(KillReport::Processes { killed }));
+                });
+            }
+            DialogAction::Signal(pids, signal) => self.start_signal(pids, signal),
+            DialogAction::QuickAction(plan) => self.start_quick_action(plan, true),
+            DialogAction::Bulk(plan) => {
+                self.reset_selection();
+                self.start_quick_action(plan, false);
+            }
+        }
+    }
+
+    /// Show the outcome of a kill and refresh the lists.
+    pub fn receive_kill_report(&mut self, report: KillReport) {
+        match report {
+            KillReport::Process { pid, error: None } => {
+                self.loading_state = LoadingState::Idle;
+                self.app_status =
+                    AppStatus::Success(format!("Successfully killed process {}", pid));
+                self.set_status_message(format!("Successfully killed process {pid}"));
+                self.request_refresh();
+            }
+            KillReport::Process {
+                pid,
+                error: Some(e),
+            } => {
+                self.loading_state = LoadingState::Idle;
+                self.app_status =
+                    AppStatus::Error(format!("Failed to kill process {}: {}", pid, e));
+                self.set_status_message(format!("Failed to kill process {pid}: {e}"));
+            }
+            KillReport::Port {
+                port,
+                result: Ok(pid),
+            } => {
+                self.loading_state = LoadingState::Idle;
+                self.app_status = AppStatus::Success(format!(
+                    "Successfully killed process {} using port {}",
+                    pid, port
+                ));
+                self.set_status_message(format!(
+                    "Successfully killed process {pid} using port {port}"
+                ));
+                self.request_refresh();
+            }
+            KillReport::Port {
+                port,
+                result: Err(e),
+            } => {
+                self.loading_state = LoadingState::Idle;
+                self.app_status = AppStatus::Error(format!("Failed to kill port {}: {}", port, e));
+                self.set_status_message(format!("Failed to kill port {port}: {e}"));
+            }
+            KillReport::Processes { killed } => {
+                self.set_status_message(format!("Killed {killed} processes"));
+                self.request_refresh();
+            }
+            KillReport::Signal { signal, outcomes } => {
+                self.loading_state = LoadingState::Idle;
+                let sent: Vec<String> = outcomes
+                    .iter()
+                    .filter(|o| o.success)
+                    .map(outcome_label)
+                    .collect();
+                let failures: Vec<String> = outcomes
+                    .iter()
+                    .filter(|o| !o.success)
+                    .map(|o| {
+                        let error = o.error.as_deref().unwrap_or("failed").trim_end();
+                        format!("{}: {error}", outcome_label(o))
+                    })
+                    .collect();
+                let message = match (sent.len(), failures.is_empty()) {
+                    (1, true) => format!("Sent {signal} to {}", sent[0]),
+                    (0, false) if outcomes.len() == 1 => failures[0].clone(),
+                    (count, true) => format!("Sent {signal} to {count} process(es)"),
+                    (count, false) => format!(
+                        "Sent {signal} to {count} of {} process(es); failed: {}",
+                        outcomes.len(),
+                        failures.join(", ")
+                    ),
+                };
+                self.app_status = if failures.is_empty() {
+                    AppStatus::Success(message.clone())
+                } else {
+                    AppStatus::Error(message.clone())
+                };
+                self.set_status_message(message);
+                self.request_refresh();
+            }
+            KillReport::Renice(results) => self.receive_renice(results),
+            KillReport::ActionStep(outcome) => self.advance_quick_action(outcome),
+        }
+    }
+
+    /// Move the nice value of the selected processes (or the one under the
+    /// cursor) by `delta`. A negative delta raises their priority.
+    fn adjust_priority(&mut self, delta: i32) {
+        if self.mode != AppMode::ProcessView {
+            return;
+        }
+        let mut changes = Vec::new();
+        for (pid, label) in self.signal_targets() {
+            let nice = self
+                .processes
+                .iter()
+                .chain(&self.filtered_processes)
+                .find(|p| p.pid == pid)
+                .and_then(|p| p.nice);
+            match nice {
+                Some(nice) if (NICE_MIN..=NICE_MAX).contains(&(nice + delta)) => {
+                    changes.push((pid, nice + delta))
+                }
+                Some(nice) => self.set_status_message(format!("{label} is already at nice {nice}")),
+                None => self.set_status_message(format!("Nice value of {label} is unknown")),
+            }
+        }
+        if changes.is_empty() {
+            return;
+        }
+
+        self.reset_selection();
+        self.app_status = AppStatus::Processing("Changing priority...".to_string());
+        let events = self.events.clone();
+        tokio::spawn(async move {
+            let mut results = Vec::with_capacity(changes.len());
+            for (pid, nice) in changes {
+                let outcome = crate::process::ProcessKiller::renice_pids(&[pid], nice).await;
+                results.extend(outcome.into_iter().map(|o| (o, nice)));
+            }
+            let _ = events.send(AppEvent::Kill(KillReport::Renice(results)));
+        });
+    }
+
+    /// Show the outcome of +/- and reflect the new nice values right away,
+    /// ahead of the refresh.
+    fn receive_renice(&mut self, results: Vec<(KillOutcome, i32)>) {
+        for (outcome, nice) in results.iter().filter(|(o, _)| o.success) {
+            for process in self
+                .processes
+                .iter_mut()
+                .chain(self.filtered_processes.iter_mut())
+                .filter(|p| p.pid == outcome.pid)
+            {
+                process.nice = Some(*nice);
+            }
+        }
+
+        let failures: Vec<String> = results
+            .iter()
+            .filter(|(o, _)| !o.success)
+            .map(|(o, _)| {
+                o.error
+                    .as_deref()
+                    .unwrap_or("failed")
+                    .trim_end()
+                    .to_string()
+            })
+            .collect();
+        let changed = results.len() - failures.len();
+        let message = match (changed, failures.is_empty()) {
+            (1, true) => format!(
+                "Set nice {} for {}",
+                results[0].1,
+                outcome_label(&results[0].0)
+            ),
+            (0, false) if results.len() == 1 => failures[0].clone(),
+            (count, true) => format!("Changed the priority of {count} process(es)"),
+            (count, false) => format!(
+                "Changed the priority of {count} of {} process(es); failed: {}",
+                results.len(),
+                failures.join(", ")
+            ),
+        };
+        self.app_status = if failures.is_empty() {
+            AppStatus::Success(message.clone())
+        } else {
+            AppStatus::Error(message.clone())
+        };
+        self.set_status_message(message);
+        self.request_refresh();
+    }
+
+    fn open_history(&mut self) {
+        self.load_history(AuditLog::default_path().map(AuditLog::new));
+    }
+
+    /// Open the history popup on `log`, newest entry first.
+    fn load_history(&mut self, log: Option<AuditLog>) {
+        let (mut entries, error) = match log.map(|log| log.read()) {
+            Some(Ok(entries)) => (entries, None),
+            Some(Err(e)) => (
+                Vec::new(),
+                Some(format!("Could not read the audit log: {e}")),
+            ),
+            None => (
+                Vec::new(),
+                Some("Could not find the data directory for the audit log".to_string()),
+            ),
+        };
+        entries.reverse();
+        self.history = Some(HistoryPane {
+            entries,
+            scroll: 0,
+            error,
+        });
+    }
+
+    fn open_signal_picker(&mut self) {
+        if self.mode != AppMode::ProcessView {
+            return;
+        }
+        if self.signal_targets().is_empty() {
+            self.set_status_message("No process to signal".to_string());
+        } else {
+            self.signal_picker = Some(0);
+        }
+    }
+
+    /// Processes the signal picker acts on: the live multi-selected ones,
+    /// or the one under the cursor.
+    fn signal_targets(&self) -> Vec<(u32, String)> {
+        if !self.selection.is_empty() {
+            return self
+                .selection
+                .iter()
+                .filter(|item| !item.exited)
+                .filter_map(|item| match item.key {
+                    ItemKey::Process { pid, .. } => Some((pid, item.label.clone())),
+                    _ => None,
+                })
+                .collect();
+        }
+        self.filtered_processes
+            .get(self.selected_index)
+            .map(|process| vec![(process.pid, process_label(process))])
+            .unwrap_or_default()
+    }
+
+    /// Send a picked signal. Stopping and resuming happen right away; any
+    /// signal that can end a process is confirmed first.
+    fn prepare_signal(&mut self, signal: Signal) {
+        let targets = self.signal_targets();
+        if targets.is_empty() {
+            return;
+        }
+        let pids: Vec<u32> = targets.iter().map(|(pid, _)| *pid).collect();
+        if signal.is_reversible() {
+            self.start_signal(pids, signal);
+            return;
+        }
+
+        let labels: Vec<&str> = targets.iter().map(|(_, label)| label.as_str()).collect();
+        let danger_level = if signal == Signal::Kill || pids.len() > 1 {
+            DangerLevel::High
+        } else {
+            DangerLevel::Medium
+        };
+        self.confirmation_dialog = Some(ConfirmationDialog {
+            title: format!("Send {signal}"),
+            message: format!(
+                "Send {signal} to {}?\n\n{}",
+                labels.join(", "),
+                signal.description()
+            ),
+            confirm_action: DialogAction::Signal(pids, signal),
+            danger_level,
+            context_info: None,
+        });
+    }
+
+    /// Send `signal` on its own task; the outcome comes back as
+    /// `AppEvent::Kill`.
+    fn start_signal(&mut self, pids: Vec<u32>, signal: Signal) {
+        self.reset_selection();
+        self.app_status = AppStatus::Processing(format!("Sending {signal}..."));
+        let events = self.events.clone();
+        tokio::spawn(async move {
+            let outcomes = crate::process::ProcessKiller::signal_pids(&pids, signal).await;
+            let _ = events.send(AppEvent::Kill(KillReport::Signal { signal, outcomes }));
+        });
+    }
+
+    /// Preset search patterns followed by saved searches, keyed by name.
+    /// A saved search replaces a preset pattern with the same key. The flag
+    /// marks saved searches.
+    pub fn search_patterns(&self) -> Vec<(&str, &SearchPattern, bool)> {
+        let presets = self
+            .presets
+            .search_patterns
+            .iter()
+            .filter(|(key, _)| !self.settings.search_patterns.contains_key(*key))
+            .map(|(key, pattern)| (key.as_str(), pattern, false));
+        let saved = self
+            .settings
+            .search_patterns
+            .iter()
+            .map(|(key, pattern)| (key.as_str(), pattern, true));
+        presets.chain(saved).collect()
+    }
+
+    fn open_search_picker(&mut self) {
+        if self.search_patterns().is_empty() {
+            self.set_status_message("No saved search patterns".to_string());
+        } else {
+            self.search_picker = Some(0);
+        }
+    }
+
+    /// Save the current query under `name` in the user settings file.
+    fn save_current_search(&mut self, name: &str) {
+        let key: String = name
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect();
+        let key = key.trim_matches('_').to_string();
+        if key.is_empty() {
+            self.set_status_message("Search name must contain letters or digits".to_string());
+            return;
+        }
+
+        self.settings.search_patterns.insert(
+            key,
+            SearchPattern {
+                name: name.to_string(),
+                pattern: self.search_query.clone(),
+                description: String::new(),
+            },
+        );
+        match save_settings(&self.settings) {
+            Ok(()) => self.set_status_message(format!("Saved search '{name}'")),
+            Err(e) => self.set_status_message(format!("Error saving settings: {e}")),
+        }
+    }
+
+    fn open_quick_actions(&mut self) {
+        if self.running_action.is_some() {
+            self.set_status_message("A quick action is already running".to_string());
+        } else if self.presets.quick_actions.is_empty() {
+            self.set_status_message("No quick actions configured".to_string());
+        } else {
+            self.quick_actions_menu = Some(0);
+        }
+    }
+
+    /// Resolve a quick action against the running system and ask for
+    /// confirmation with the exact PIDs and ports it will affect.
+    fn prepare_quick_action(&mut self, key: &str) {
+        let Some(action) = self.presets.quick_actions.get(key) else {
+            return;
+        };
+        let plan = plan_action(key, action);
+        if plan.is_empty() {
+            self.set_status_message(format!(
+                "{}: nothing to do, no matching processes are running",
+                action.name
+            ));
+            return;
+        }
+
+        if !action.confirmation_required && !self.presets.ui_settings.confirm_destructive_actions {
+            self.start_quick_action(plan, true);
+            return;
+        }
+        self.confirmation_dialog = Some(Self::quick_action_dialog(plan));
+    }
+
+    fn quick_action_dialog(plan: ActionPlan) -> ConfirmationDialog {
+        let count = plan.targets.len();
+        let targets = describe_targets(&plan);
+        let danger_level = plan_danger(&plan);
+
+        let mut message = format!(
+            "Run '{}' and terminate {} process(es)?\n\nPIDs: {}",
+            plan.action.name,
+            count,
+            targets.join(", ")
+        );
+        if !plan.free_ports.is_empty() {
+            let free: Vec<String> = plan.free_ports.iter().map(u16::to_string).collect();
+            message.push_str(&format!("\nAlready free: {}", free.join(", ")));
+        }
+        if !plan.held_ports.is_empty() {
+            let held: Vec<String> = plan.held_ports.iter().map(u16::to_string).collect();
+            message.push_str(&format!(
+                "\nSkipped, held by bossy-rust: {}",
+                held.join(", ")
+            ));
+        }
+
+        ConfirmationDialog {
+            title: "Run Quick Action".to_string(),
+            message,
+            context_info: Some(plan.action.description.clone()),
+            confirm_action: DialogAction::QuickAction(plan),
+            danger_level,
+        }
+    }
+
+    /// Run a plan's kills on their own task. A preset quick action is
+    /// audited as a rule; a bulk kill of the selection as the user's own.
+    fn start_quick_action(&mut self, plan: ActionPlan, is_rule: bool) {
+        self.loading_state = LoadingState::RunningAction(plan.action.name.clone());
+        self.app_status = AppStatus::Processing(format!("Running {}...", plan.action.name));
+        self.operation_progress = Some(0.0);
+
+        // Targets are terminated one at a time so progress can be drawn in between
+        let pids = plan.pids();
+        let rule = is_rule.then(|| plan.key.clone());
+        let events = self.events.clone();
+        tokio::spawn(async move {
+            for pid in pids {
+                let target = [pid];
+                let kill = crate::process::ProcessKiller::kill_pids(&target, false);
+                let outcomes = match &rule {
+                    Some(rule) => run_as_rule(rule, kill).await,
+                    None => kill.await,
+                };
+                for outcome in outcomes {
+                    let _ = events.send(AppEvent::Kill(KillReport::ActionStep(outcome)));
                 }
             }
+        });
+
+        self.running_action = Some(RunningAction {
+            plan,
+            outcomes: Vec::new(),
+        });
+        // An action without targets is already done
+        self.finish_quick_action();
+    }
+
+    /// Record one terminated target of the running quick action.
+    fn advance_quick_action(&mut self, outcome: KillOutcome) {
+        let Some(running) = self.running_action.as_mut() else {
+            return;
+        };
+        let name = outcome.name.as_deref().unwrap_or("unknown");
+        let step = match &outcome.error {
+            None => format!("Terminated {name} ({})", outcome.pid),
+            Some(e) => format!("Failed to terminate {name} ({}): {e}", outcome.pid),
+        };
+        running.outcomes.push(outcome);
+
+        let total = running.plan.targets.len();
+        self.operation_progress = Some(running.outcomes.len() as f32 / total.max(1) as f32);
+        self.set_status_message(step);
+        self.finish_quick_action();
+    }
+
+    fn finish_quick_action(&mut self) {
+        let Some(running) = self
+            .running_action
+            .take_if(|running| running.outcomes.len() >= running.plan.targets.len())
+        else {
+            return;
+        };
+
+        let total = running.plan.targets.len();
+        let killed = running.outcomes.iter().filter(|o| o.success).count();
+        let failed = total - killed;
+        let summary = format!(
+            "{}: terminated {killed} of {total} process(es)",
+            running.plan.action.name
+        );
+        let failures: Vec<String> = running
+            .outcomes
+            .iter()
+            .filter(|o| !o.success)
+            .map(|o| {
+                let name = o.name.as_deref().unwrap_or("unknown");
+                let error = o.error.as_deref().unwrap_or("failed");
+                format!("{name} ({}): {error}", o.pid)
+            })
+            .collect();
+        self.operation_progress = None;
+        self.loading_state = LoadingState::Idle;
+        self.request_refresh();
+        if failed == 0 {
+            self.app_status = AppStatus::Success(summary.clone());
+            self.set_status_message(summary);
+        } else {
+            let summary = format!("{summary}; failed: {}", failures.join(", "));
+            self.app_status = AppStatus::Error(summary.clone());
+            self.set_status_message(summary);
         }
-        Ok(())
     }
 
     pub fn set_status_message(&mut self, message: String) {
@@ -903,13 +2465,13 @@ impl AppState {
             LoadingState::RefreshingData => Some("Refreshing system data...".to_string()),
             LoadingState::KillingProcess(pid) => Some(format!("Terminating process {}...", pid)),
             LoadingState::KillingPort(port) => Some(format!("Killing process on port {}...", port)),
-            LoadingState::SearchingData => Some("Searching...".to_string()),
+            LoadingState::RunningAction(name) => Some(format!("Running {}...", name)),
         }
     }
 
     pub fn switch_to_mode(&mut self, mode: AppMode) {
         // Clear search when switching modes unless going to search-related mode
-        if !matches!(mode, AppMode::ThemeSelector) && self.search_active {
+        if !matches!(mode, AppMode::ThemeSelector | AppMode::Settings) && self.search_active {
             self.search_active = false;
             self.search_query.clear();
             self.reset_filters();
@@ -922,21 +2484,28 @@ impl AppState {
             AppMode::PortView => "Port View", 
             AppMode::ConnectionView => "Connection View",
             AppMode::ThemeSelector => "Theme Selector",
+            AppMode::Settings => "Settings",
         };
         
+        // The theme selector starts on the current theme
+        self.selected_index = match mode {
+            AppMode::ThemeSelector => self.current_theme_index,
+            _ => 0,
+        };
         self.mode = mode;
-        self.selected_index = 0;
-        self.selected_items.clear();
-        self.multi_select_mode = false;
+        self.reset_selection();
         self.show_help = false; // Auto-close help when switching modes
+
+        // Each view starts with its default sort from the settings
+        self.apply_view_sort();
+        self.apply_current_sorts();
         
         // Set appropriate status message
         self.set_status_message(format!("Switched to {}", mode_name));
     }
     
     fn clear_selection(&mut self) {
-        self.selected_items.clear();
-        self.multi_select_mode = false;
+        self.reset_selection();
         self.set_status_message("Selection cleared".to_string());
     }
     
@@ -971,15 +2540,78 @@ impl AppState {
     }
 }
 
+/// "name (pid)" for a kill or signal outcome.
+fn outcome_label(outcome: &KillOutcome) -> String {
+    let name = outcome.name.as_deref().unwrap_or("unknown");
+    format!("{name} ({})", outcome.pid)
+}
+
+/// The listening socket a connection was accepted on or, for a connection
+/// to a server on this machine, the one it connects to.
+fn listener_for<'a>(ports: &'a [PortInfo], connection: &ConnectionInfo) -> Option<&'a PortInfo> {
+    let listening = |port: u16| {
+        ports.iter().find(|p| {
+            p.port == port
+                && p.protocol == connection.protocol
+                && (p.state == ConnectionState::Listen || p.remote_address.is_none())
+        })
+    };
+    listening(connection.local_address.port()).or_else(|| {
+        connection
+            .remote_address
+            .ip()
+            .is_loopback()
+            .then(|| listening(connection.remote_address.port()))
+            .flatten()
+    })
+}
+
+/// `PID (name :port ...)` for each target of a plan.
+fn describe_targets(plan: &ActionPlan) -> Vec<String> {
+    plan.targets
+        .iter()
+        .map(|t| {
+            let name = t.name.as_deref().unwrap_or("unknown");
+            if t.ports.is_empty() {
+                format!("{} ({})", t.pid, name)
+            } else {
+                let ports: Vec<String> = t.ports.iter().map(|p| format!(":{p}")).collect();
+                format!("{} ({} {})", t.pid, name, ports.join(" "))
+            }
+        })
+        .collect()
+}
+
+/// How risky running a plan is, from its size and whether it reaches
+/// system processes.
+fn plan_danger(plan: &ActionPlan) -> DangerLevel {
+    let count = plan.targets.len();
+    let critical_target = plan.targets.iter().any(|t| {
+        let name = t.name.as_deref().unwrap_or_default();
+        name.contains("system") || name.contains("kernel") || t.pid < 100
+    });
+    if critical_target || count > 10 {
+        DangerLevel::Critical
+    } else if count > 5 {
+        DangerLevel::High
+    } else {
+        DangerLevel::Medium
+    }
+}
+
 impl Default for AppState {
     fn default() -> Self {
-        Self::new().expect("Failed to create default AppState")
+        // Nothing listens for background events
+        Self::new(tokio::sync::mpsc::unbounded_channel().0)
+            .expect("Failed to create default AppState")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::presets::KeyboardShortcuts;
+    use crate::testing::fixtures::create_test_process;
     use crossterm::event::{KeyCode, KeyModifiers};
 
     fn create_test_app_state() -> AppState {
@@ -1014,17 +2646,45 @@ mod tests {
             connections: vec![],
             filtered_connections: vec![],
 
-            process_monitor: ProcessMonitor::new(),
+            collector: None,
             last_refresh: Instant::now(),
+            screen_clock: (0, false),
             refresh_interval: Duration::from_secs(2),
             auto_refresh: true,
 
             selected_items: Vec::new(),
             multi_select_mode: false,
+            selection: Vec::new(),
 
             cpu_history: vec![0; 100],
+            memory_history: vec![0; 100],
+            system_stats: SystemStats::default(),
             themes,
             current_theme_index,
+
+            presets: Presets::default(),
+
+            quick_actions_menu: None,
+            remote_details: None,
+            signal_picker: None,
+            history: None,
+            running_action: None,
+
+            settings,
+            search_picker: None,
+            save_search_name: None,
+
+            keymap: Keymap::default(),
+
+            user_names: HashMap::new(),
+
+            project: None,
+            project_filter: false,
+
+            events: tokio::sync::mpsc::unbounded_channel().0,
+
+            hit_map: HitMap::default(),
+            last_click: None,
         }
     }
 
@@ -1064,6 +2724,121 @@ mod tests {
         assert!(app.should_quit);
     }
 
+    #[tokio::test]
+    async fn test_rebound_kill_key_replaces_global_keys() {
+        let mut app = create_test_app_state();
+        let mut shortcuts = KeyboardShortcuts::new();
+        shortcuts
+            .entry("process_view".to_string())
+            .or_default()
+            .insert("kill".to_string(), vec!["K".to_string()]);
+        app.keymap = Keymap::from_config(&shortcuts).unwrap();
+        app.mode = AppMode::ProcessView;
+        app.apply_snapshot(snapshot_of(vec![test_process(4242, 0.0, 0)]));
+
+        // The global kill key no longer kills where the help says 'K' does
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE))
+            .await
+            .unwrap();
+        assert!(app.confirmation_dialog.is_none());
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('K'), KeyModifiers::NONE))
+            .await
+            .unwrap();
+        assert!(app.confirmation_dialog.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_quick_actions_menu_navigation() {
+        let mut app = create_test_app_state();
+        app.presets = Presets::bundled();
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE))
+            .await
+            .unwrap();
+        assert_eq!(app.quick_actions_menu, Some(0));
+
+        for _ in 0..5 {
+            app.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE))
+                .await
+                .unwrap();
+        }
+        assert_eq!(app.quick_actions_menu, Some(2)); // Clamped to the last action
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+            .await
+            .unwrap();
+        assert_eq!(app.quick_actions_menu, None);
+        assert!(!app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_search_picker_applies_pattern() {
+        let mut app = create_test_app_state();
+        app.presets = Presets::bundled();
+        app.settings.search_patterns.insert(
+            "high_cpu".to_string(),
+            SearchPattern {
+                name: "Busy".to_string(),
+                pattern: ">80%".to_string(),
+                description: String::new(),
+            },
+        );
+        app.processes = vec![create_test_process(7, "python", 90.0, 0)];
+        app.mode = AppMode::ProcessView;
+
+        // The saved search replaces the preset with the same key and is listed last
+        let patterns = app.search_patterns();
+        assert_eq!(patterns.len(), 4);
+        assert_eq!(patterns[3].0, "high_cpu");
+        assert!(patterns[3].2);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE))
+            .await
+            .unwrap();
+        app.handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE))
+            .await
+            .unwrap();
+        assert_eq!(app.search_picker, Some(0));
+
+        // Down then back Up to dev_processes: node|python|java|ruby
+        app.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE))
+            .await
+            .unwrap();
+        assert_eq!(app.search_picker, Some(1));
+        app.handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE))
+            .await
+            .unwrap();
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .await
+            .unwrap();
+        assert_eq!(app.search_query, "node|python|java|ruby");
+        assert!(!app.search_active);
+        assert_eq!(app.filtered_processes.len(), 1);
+    }
+
+    #[test]
+    fn test_quick_action_dialog_preview() {
+        let presets = Presets::bundled();
+        let action = &presets.quick_actions["free_dev_ports"];
+        let processes = vec![create_test_process(4242, "node", 0.0, 0)];
+        let owners: Vec<(u16, Option<u32>)> = action
+            .ports
+            .iter()
+            .map(|&port| (port, (port == 3000).then_some(4242)))
+            .collect();
+        let plan = ActionPlan::build("free_dev_ports", action, &processes, &owners);
+
+        let dialog = AppState::quick_action_dialog(plan);
+        assert_eq!(dialog.danger_level, DangerLevel::Medium);
+        assert!(dialog.message.contains("4242 (node :3000)"));
+        assert!(dialog.message.contains("Already free: 3001, 4200"));
+        assert!(matches!(
+            dialog.confirm_action,
+            DialogAction::QuickAction(_)
+        ));
+    }
+
     #[tokio::test]
     async fn test_key_event_handling_navigation() {
         let mut app = create_test_app_state();
@@ -1080,7 +2855,10 @@ mod tests {
                 start_time: 0,
                 user_id: None,
                 executable_path: None,
+                cwd: None,
                 command_line: vec![],
+                nice: None,
+                priority: None,
             },
             ProcessInfo {
                 pid: 2,
@@ -1092,7 +2870,10 @@ mod tests {
                 start_time: 0,
                 user_id: None,
                 executable_path: None,
+                cwd: None,
                 command_line: vec![],
+                nice: None,
+                priority: None,
             },
         ];
 
@@ -1140,9 +2921,29 @@ mod tests {
         assert!(app.search_query.is_empty());
     }
 
+    fn test_process(pid: u32, cpu_usage: f32, start_time: u64) -> ProcessInfo {
+        ProcessInfo {
+            start_time,
+            ..create_test_process(pid, &format!("proc{pid}"), cpu_usage, 0)
+        }
+    }
+
+    fn snapshot_of(processes: Vec<ProcessInfo>) -> Snapshot {
+        Snapshot {
+            processes,
+            ports: vec![],
+            connections: vec![],
+            system_cpu: 0.0,
+            system: SystemStats::default(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
     #[test]
     fn test_multi_selection() {
         let mut app = create_test_app_state();
+        app.processes = vec![test_process(1, 0.0, 0), test_process(2, 0.0, 0)];
+        app.filtered_processes = app.processes.clone();
 
         // Start multi-selection
         app.toggle_selection();
@@ -1245,6 +3046,493 @@ mod tests {
         assert!(!app.should_refresh());
     }
 
+    #[test]
+    fn test_apply_snapshot() {
+        let mut app = create_test_app_state();
+        app.loading_state = LoadingState::RefreshingData;
+        app.last_refresh = Instant::now() - Duration::from_secs(5);
+
+        let process = |pid: u32| create_test_process(pid, &format!("proc{pid}"), 0.0, 0);
+        app.apply_snapshot(Snapshot {
+            processes: vec![process(1), process(2)],
+            ports: vec![],
+            connections: vec![],
+            system_cpu: 42.0,
+            system: SystemStats {
+                total_memory: 16 << 30,
+                used_memory: 4 << 30,
+                load_average: [1.5, 1.0, 0.5],
+                uptime: 3_600,
+                ..SystemStats::default()
+            },
+            elapsed: Duration::from_millis(250),
+        });
+
+        assert_eq!(app.processes.len(), 2);
+        assert_eq!(app.filtered_processes.len(), 2);
+        assert_eq!(app.cpu_history.last(), Some(&42));
+        assert_eq!(app.memory_history.last(), Some(&25));
+        assert_eq!(app.memory_history.len(), 100);
+        assert_eq!(app.system_stats.load_average[0], 1.5);
+        assert_eq!(app.loading_state, LoadingState::Idle);
+        assert!(!app.should_refresh());
+
+        // A snapshot landing mid-kill leaves the kill's state alone
+        app.loading_state = LoadingState::KillingProcess(1);
+        app.apply_snapshot(Snapshot {
+            processes: vec![],
+            ports: vec![],
+            connections: vec![],
+            system_cpu: 0.0,
+            system: SystemStats::default(),
+            elapsed: Duration::ZERO,
+        });
+        assert_eq!(app.loading_state, LoadingState::KillingProcess(1));
+    }
+
+    #[test]
+    fn test_apply_snapshot_with_search_finishes_refresh() {
+        let mut app = create_test_app_state();
+        app.mode = AppMode::ProcessView;
+        app.search_query = "node".to_string();
+        app.loading_state = LoadingState::RefreshingData;
+        app.app_status = AppStatus::Loading("Refreshing system data...".to_string());
+
+        app.apply_snapshot(Snapshot {
+            processes: vec![
+                create_test_process(1, "node", 0.0, 0),
+                create_test_process(2, "python", 0.0, 0),
+            ],
+            ports: vec![],
+            connections: vec![],
+            system_cpu: 0.0,
+            system: SystemStats::default(),
+            elapsed: Duration::ZERO,
+        });
+
+        assert_eq!(app.filtered_processes.len(), 1);
+        assert_eq!(app.loading_state, LoadingState::Idle);
+        assert!(matches!(app.app_status, AppStatus::Success(_)));
+    }
+
+    #[test]
+    fn test_selection_follows_items() {
+        let mut app = create_test_app_state();
+        app.mode = AppMode::ProcessView;
+        app.apply_snapshot(snapshot_of(vec![
+            test_process(10, 30.0, 100),
+            test_process(20, 20.0, 100),
+            test_process(30, 10.0, 100),
+        ]));
+
+        // Cursor on PID 20, PIDs 20 and 30 selected
+        app.selected_index = 1;
+        app.toggle_selection();
+        app.selected_index = 2;
+        app.toggle_selection();
+        app.selected_index = 1;
+
+        // CPU usage changes reorder the list; everything follows its process
+        app.apply_snapshot(snapshot_of(vec![
+            test_process(10, 1.0, 100),
+            test_process(20, 50.0, 100),
+            test_process(30, 40.0, 100),
+        ]));
+        assert_eq!(app.filtered_processes[app.selected_index].pid, 20);
+        let selected: Vec<u32> = app
+            .selected_items
+            .iter()
+            .map(|&i| app.filtered_processes[i].pid)
+            .collect();
+        assert_eq!(selected, vec![20, 30]);
+
+        // So does re-sorting
+        app.sort_by_column(SortBy::Pid);
+        assert_eq!(app.filtered_processes[app.selected_index].pid, 20);
+        assert_eq!(app.selected_items, vec![1, 2]);
+
+        // PID 30 exits and PID 20 is reused by a new process: both stay
+        // selected but are marked, and nothing else is selected in their place
+        app.apply_snapshot(snapshot_of(vec![
+            test_process(10, 1.0, 100),
+            test_process(20, 5.0, 200),
+        ]));
+        assert!(app.selected_items.is_empty());
+        assert!(app.selection.iter().all(|item| item.exited));
+        assert!(app.multi_select_mode);
+        assert!(app
+            .get_status_message()
+            .unwrap()
+            .contains("proc30 (PID 30)"));
+
+        // ... and a bulk kill has nothing left to act on
+        app.kill_action();
+        assert!(app.confirmation_dialog.is_none());
+
+        app.clear_selection();
+        assert!(app.selection.is_empty());
+        assert!(!app.multi_select_mode);
+    }
+
+    #[test]
+    fn test_bulk_kill_owners_dialog() {
+        use crate::network::Protocol;
+        use crate::testing::fixtures::{create_test_connection, create_test_port};
+
+        let mut app = create_test_app_state();
+        app.processes = vec![test_process(500, 0.0, 0), test_process(600, 0.0, 0)];
+        let mut ipv6 = create_test_port(3000, Protocol::Tcp, Some(500));
+        ipv6.local_address = "[::1]:3000".parse().unwrap();
+        app.ports = vec![
+            create_test_port(3000, Protocol::Tcp, Some(500)),
+            ipv6,
+            create_test_port(3001, Protocol::Tcp, Some(500)),
+            create_test_port(8080, Protocol::Tcp, None),
+        ];
+        app.connections = vec![
+            create_test_connection(50000, 443, Some(600)),
+            create_test_connection(50001, 443, Some(600)),
+        ];
+        app.switch_to_mode(AppMode::PortView);
+        app.reset_filters();
+
+        for index in 0..app.filtered_ports.len() {
+            app.selected_index = index;
+            app.toggle_selection();
+        }
+        app.kill_action();
+
+        // Four ports, one owner, previewed once with all of its ports
+        let dialog = app.confirmation_dialog.take().unwrap();
+        assert!(dialog.message.contains("500 (proc500 :3000 :3001)"));
+        assert!(dialog.message.contains("No owner found: :8080"));
+        let DialogAction::Bulk(plan) = dialog.confirm_action else {
+            panic!("expected a bulk action");
+        };
+        assert_eq!(plan.pids(), vec![500]);
+        assert!(!app.skips_confirmation(&ConfirmationDialog {
+            title: String::new(),
+            message: String::new(),
+            confirm_action: DialogAction::Bulk(plan),
+            danger_level: DangerLevel::Low,
+            context_info: None,
+        }));
+
+        // Both connections belong to the same process
+        app.switch_to_mode(AppMode::ConnectionView);
+        app.selected_index = 0;
+        app.toggle_selection();
+        app.selected_index = 1;
+        app.toggle_selection();
+        app.kill_action();
+        let dialog = app.confirmation_dialog.take().unwrap();
+        assert_eq!(dialog.title, "Kill Connection Owners");
+        let DialogAction::Bulk(plan) = dialog.confirm_action else {
+            panic!("expected a bulk action");
+        };
+        assert_eq!(plan.pids(), vec![600]);
+
+        // Each outcome is reported, and failures are named in the summary
+        app.running_action = Some(RunningAction {
+            plan: ActionPlan {
+                targets: vec![
+                    crate::process::ActionTarget {
+                        pid: 500,
+                        name: Some("proc500".to_string()),
+                        ports: vec![3000],
+                    },
+                    crate::process::ActionTarget {
+                        pid: 600,
+                        name: Some("proc600".to_string()),
+                        ports: vec![],
+                    },
+                ],
+                ..plan
+            },
+            outcomes: Vec::new(),
+        });
+        app.advance_quick_action(KillOutcome {
+            pid: 500,
+            name: Some("proc500".to_string()),
+            success: true,
+            failure: None,
+            error: None,
+        });
+        assert_eq!(app.get_status_message(), Some("Terminated proc500 (500)"));
+        app.advance_quick_action(KillOutcome {
+            pid: 600,
+            name: Some("proc600".to_string()),
+            success: false,
+            failure: None,
+            error: Some("Permission denied".to_string()),
+        });
+        assert!(app.running_action.is_none());
+        assert!(app
+            .get_status_message()
+            .unwrap()
+            .ends_with("failed: proc600 (600): Permission denied"));
+    }
+
+    #[tokio::test]
+    async fn test_connection_view_actions() {
+        use crate::network::remote::AddressScope;
+        use crate::network::Protocol;
+        use crate::testing::fixtures::{create_test_connection, create_test_port};
+
+        let press = |c: char| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        let mut app = create_test_app_state();
+        app.processes = vec![test_process(500, 0.0, 0), test_process(600, 0.0, 0)];
+        app.ports = vec![
+            create_test_port(8080, Protocol::Tcp, Some(600)),
+            create_test_port(3000, Protocol::Tcp, Some(500)),
+        ];
+        // A client of the local server on :3000, then an unowned connection
+        let mut client = create_test_connection(51000, 3000, Some(600));
+        client.remote_address = "127.0.0.1:3000".parse().unwrap();
+        app.connections = vec![client, create_test_connection(51001, 443, None)];
+        app.switch_to_mode(AppMode::ConnectionView);
+        app.reset_filters();
+
+        // Enter and x both ask to kill the owning process
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .await
+            .unwrap();
+        let dialog = app.confirmation_dialog.take().unwrap();
+        assert!(matches!(dialog.confirm_action, DialogAction::Process(600)));
+        app.selected_index = 1;
+        app.handle_key_event(press('x')).await.unwrap();
+        assert!(app.confirmation_dialog.is_none());
+        assert!(app
+            .get_status_message()
+            .unwrap()
+            .starts_with("No owning process known"));
+
+        // o jumps to the owner, even past a search that hides it
+        app.selected_index = 0;
+        app.search_query = "proc500".to_string();
+        app.filtered_processes.retain(|p| p.pid == 500);
+        app.handle_key_event(press('o')).await.unwrap();
+        assert_eq!(app.mode, AppMode::ProcessView);
+        assert_eq!(app.filtered_processes[app.selected_index].pid, 600);
+        assert!(app.search_query.is_empty());
+
+        // l finds the server the client is connected to
+        app.switch_to_mode(AppMode::ConnectionView);
+        app.handle_key_event(press('l')).await.unwrap();
+        assert_eq!(app.mode, AppMode::PortView);
+        assert_eq!(app.filtered_ports[app.selected_index].port, 3000);
+
+        // i shows the remote address; the lookup answer fills it in
+        app.switch_to_mode(AppMode::ConnectionView);
+        app.handle_key_event(press('i')).await.unwrap();
+        let details = app.remote_details.as_ref().unwrap();
+        assert_eq!(details.summary.scope, AddressScope::Loopback);
+        let address = details.summary.address;
+        assert!(app.receive_reverse_lookup(address, Some("localhost".to_string())));
+        let details = app.remote_details.as_ref().unwrap();
+        assert_eq!(details.summary.reverse_name.as_deref(), Some("localhost"));
+        assert!(!details.resolving);
+        // A late answer for another popup is ignored
+        assert!(!app.receive_reverse_lookup("10.0.0.1".parse().unwrap(), None));
+
+        // The connection actions work from the popup, which closes
+        app.handle_key_event(press('x')).await.unwrap();
+        assert!(app.remote_details.is_none());
+        assert!(app.confirmation_dialog.is_some());
+        app.confirmation_dialog = None;
+        app.handle_key_event(press('i')).await.unwrap();
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+            .await
+            .unwrap();
+        assert!(app.remote_details.is_none());
+        assert_eq!(app.mode, AppMode::ConnectionView);
+    }
+
+    #[tokio::test]
+    async fn test_signal_picker() {
+        let press = |code: KeyCode| KeyEvent::new(code, KeyModifiers::NONE);
+        let mut app = create_test_app_state();
+        // PIDs past any pid_max, so nothing real is signalled
+        app.processes = vec![
+            test_process(99_999_998, 0.0, 0),
+            test_process(99_999_999, 0.0, 0),
+        ];
+        app.switch_to_mode(AppMode::ProcessView);
+        app.reset_filters();
+
+        app.handle_key_event(press(KeyCode::Char('S')))
+            .await
+            .unwrap();
+        assert_eq!(app.signal_picker, Some(0));
+        assert_eq!(Signal::ALL[4], Signal::Hup);
+        for _ in 0..4 {
+            app.handle_key_event(press(KeyCode::Down)).await.unwrap();
+        }
+        app.handle_key_event(press(KeyCode::Enter)).await.unwrap();
+
+        // Signals that can end the process are confirmed first
+        assert!(app.signal_picker.is_none());
+        let dialog = app.confirmation_dialog.take().unwrap();
+        assert_eq!(dialog.title, "Send SIGHUP");
+        assert!(matches!(
+            dialog.confirm_action,
+            DialogAction::Signal(ref pids, Signal::Hup) if pids == &[99_999_998]
+        ));
+
+        // The multi-selection is signalled together
+        app.toggle_selection();
+        app.selected_index = 1;
+        app.toggle_selection();
+        assert_eq!(app.signal_targets().len(), 2);
+        app.handle_key_event(press(KeyCode::Char('S')))
+            .await
+            .unwrap();
+        app.handle_key_event(press(KeyCode::Esc)).await.unwrap();
+        assert!(app.signal_picker.is_none());
+
+        // Other views have no picker
+        app.switch_to_mode(AppMode::PortView);
+        app.handle_key_event(press(KeyCode::Char('S')))
+            .await
+            .unwrap();
+        assert!(app.signal_picker.is_none());
+
+        let outcome = |pid: u32, error: Option<&str>| KillOutcome {
+            pid,
+            name: Some(format!("proc{pid}")),
+            success: error.is_none(),
+            failure: None,
+            error: error.map(str::to_string),
+        };
+        app.receive_kill_report(KillReport::Signal {
+            signal: Signal::Stop,
+            outcomes: vec![outcome(1, None)],
+        });
+        assert_eq!(app.get_status_message(), Some("Sent SIGSTOP to proc1 (1)"));
+        app.receive_kill_report(KillReport::Signal {
+            signal: Signal::Cont,
+            outcomes: vec![outcome(1, None), outcome(2, Some("No such process\n"))],
+        });
+        assert_eq!(
+            app.get_status_message(),
+            Some("Sent SIGCONT to 1 of 2 process(es); failed: proc2 (2): No such process")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_adjust_priority() {
+        let press = |code: KeyCode| KeyEvent::new(code, KeyModifiers::NONE);
+        let mut app = create_test_app_state();
+        let mut lowest = test_process(99_999_998, 0.0, 0);
+        lowest.nice = Some(19);
+        app.processes = vec![lowest, test_process(99_999_999, 0.0, 0)];
+        app.switch_to_mode(AppMode::ProcessView);
+        app.reset_filters();
+
+        // Nothing is started past the range or without a known value
+        app.handle_key_event(press(KeyCode::Char('+')))
+            .await
+            .unwrap();
+        assert_eq!(
+            app.get_status_message(),
+            Some("proc99999998 (PID 99999998) is already at nice 19")
+        );
+        app.selected_index = 1;
+        app.handle_key_event(press(KeyCode::Char('-')))
+            .await
+            .unwrap();
+        assert_eq!(
+            app.get_status_message(),
+            Some("Nice value of proc99999999 (PID 99999999) is unknown")
+        );
+
+        // Other views ignore the keys
+        app.switch_to_mode(AppMode::PortView);
+        app.handle_key_event(press(KeyCode::Char('-')))
+            .await
+            .unwrap();
+        assert_eq!(app.get_status_message(), Some("Switched to Port View"));
+        app.switch_to_mode(AppMode::ProcessView);
+
+        let outcome = |pid: u32, error: Option<&str>| KillOutcome {
+            pid,
+            name: Some(format!("proc{pid}")),
+            success: error.is_none(),
+            failure: None,
+            error: error.map(str::to_string),
+        };
+        app.receive_kill_report(KillReport::Renice(vec![(outcome(99_999_998, None), 18)]));
+        assert_eq!(app.processes[0].nice, Some(18));
+        assert_eq!(
+            app.get_status_message(),
+            Some("Set nice 18 for proc99999998 (99999998)")
+        );
+        app.receive_kill_report(KillReport::Renice(vec![
+            (outcome(99_999_998, None), 17),
+            (outcome(99_999_999, Some("Permission denied")), -1),
+        ]));
+        assert_eq!(app.processes[0].nice, Some(17));
+        assert_eq!(app.processes[1].nice, None);
+        assert_eq!(
+            app.get_status_message(),
+            Some("Changed the priority of 1 of 2 process(es); failed: Permission denied")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_history_pane() {
+        let press = |code: KeyCode| KeyEvent::new(code, KeyModifiers::NONE);
+        let dir = tempfile::TempDir::new().unwrap();
+        let log = AuditLog::new(dir.path().join("audit.log"));
+        for pid in 1..=3 {
+            log.append(&AuditEntry {
+                timestamp: 1_760_000_000 + u64::from(pid),
+                pid,
+                name: Some(format!("proc{pid}")),
+                command_line: None,
+                action: "SIGTERM".to_string(),
+                initiator: crate::process::Initiator::Tui,
+                rule: None,
+                success: true,
+                failure: None,
+                error: None,
+            })
+            .unwrap();
+        }
+
+        let mut app = create_test_app_state();
+        app.load_history(Some(log));
+        let history = app.history.as_ref().unwrap();
+        let pids: Vec<u32> = history.entries.iter().map(|e| e.pid).collect();
+        assert_eq!(pids, vec![3, 2, 1]);
+        assert!(history.error.is_none());
+
+        // Scrolling stays within the entries, and other keys are swallowed
+        app.handle_key_event(press(KeyCode::End)).await.unwrap();
+        assert_eq!(app.history.as_ref().unwrap().scroll, 2);
+        app.handle_key_event(press(KeyCode::Down)).await.unwrap();
+        assert_eq!(app.history.as_ref().unwrap().scroll, 2);
+        app.handle_key_event(press(KeyCode::Up)).await.unwrap();
+        app.handle_key_event(press(KeyCode::Char('2')))
+            .await
+            .unwrap();
+        assert_eq!(app.history.as_ref().unwrap().scroll, 1);
+        assert_eq!(app.mode, AppMode::Dashboard);
+        app.handle_key_event(press(KeyCode::Esc)).await.unwrap();
+        assert!(app.history.is_none());
+
+        app.load_history(None);
+        assert!(app.history.as_ref().unwrap().error.is_some());
+        app.handle_key_event(press(KeyCode::Char('H')))
+            .await
+            .unwrap();
+        assert!(app.history.is_none());
+        app.handle_key_event(press(KeyCode::Char('H')))
+            .await
+            .unwrap();
+        assert!(app.history.is_some());
+    }
+
     #[test]
     fn test_navigation_bounds() {
         let mut app = create_test_app_state();
@@ -1261,7 +3549,10 @@ mod tests {
                 start_time: 0,
                 user_id: None,
                 executable_path: None,
+                cwd: None,
                 command_line: vec![],
+                nice: None,
+                priority: None,
             },
             ProcessInfo {
                 pid: 2,
@@ -1273,7 +3564,10 @@ mod tests {
                 start_time: 0,
                 user_id: None,
                 executable_path: None,
+                cwd: None,
                 command_line: vec![],
+                nice: None,
+                priority: None,
             },
         ];
 
@@ -1314,7 +3608,10 @@ mod tests {
                 start_time: 0,
                 user_id: None,
                 executable_path: None,
+                cwd: None,
                 command_line: vec![],
+                nice: None,
+                priority: None,
             },
             ProcessInfo {
                 pid: 2,
@@ -1326,7 +3623,10 @@ mod tests {
                 start_time: 0,
                 user_id: None,
                 executable_path: None,
+                cwd: None,
                 command_line: vec![],
+                nice: None,
+                priority: None,
             },
         ];
 
@@ -1348,6 +3648,21 @@ mod tests {
         assert_eq!(app.filtered_processes.len(), 2);
     }
 
+    #[test]
+    fn test_display_limits_from_presets() {
+        let mut app = create_test_app_state();
+        app.presets.ui_settings.max_process_display = 1;
+        app.processes = vec![
+            create_test_process(1, "node", 5.0, 0),
+            create_test_process(2, "python", 80.0, 0),
+        ];
+
+        // Sorted by CPU first, so the busiest process is the one kept
+        app.reset_filters();
+        assert_eq!(app.filtered_processes.len(), 1);
+        assert_eq!(app.filtered_processes[0].name, "python");
+    }
+
     #[test]
     fn test_connection_view_filtering() {
         use crate::network::Protocol;
@@ -1398,4 +3713,51 @@ mod tests {
         app.apply_search_filter();
         assert_eq!(app.filtered_connections.len(), 2);
     }
+
+    #[test]
+    fn test_project_filter() {
+        use crate::network::Protocol;
+        use crate::process::current_uid;
+
+        let mut app = create_test_app_state();
+        let process = |pid: u32, name: &str, cwd: &str| ProcessInfo {
+            user_id: current_uid(),
+            cwd: Some(cwd.to_string()),
+            ..create_test_process(pid, name, 0.0, 0)
+        };
+        app.processes = vec![
+            process(10, "node", "/work/shop"),
+            process(20, "node", "/work/blog"),
+        ];
+        app.connections = vec![ConnectionInfo {
+            protocol: Protocol::Tcp,
+            local_address: "127.0.0.1:5000".parse().unwrap(),
+            remote_address: "1.1.1.1:443".parse().unwrap(),
+            pid: Some(20),
+            process_name: Some("node".to_string()),
+        }];
+        app.reset_filters();
+
+        // Without a profile the toggle only explains why nothing happened
+        app.toggle_project_filter();
+        assert!(!app.project_filter);
+
+        let source = "[services.web]\nport = 3000\nprocess = \"node\"\n";
+        app.project = Some(Project::parse(source, std::path::Path::new("/work/shop")).unwrap());
+        app.mode = AppMode::ProcessView;
+        app.toggle_project_filter();
+        assert!(app.project_filter);
+        assert_eq!(app.filtered_processes.len(), 1);
+        assert_eq!(app.filtered_processes[0].pid, 10);
+        assert!(app.filtered_connections.is_empty());
+
+        // Search narrows within the project
+        app.search_query = "node".to_string();
+        app.apply_search_filter();
+        assert_eq!(app.filtered_processes.len(), 1);
+
+        app.search_query.clear();
+        app.toggle_project_filter();
+        assert_eq!(app.filtered_processes.len(), 2);
+    }
 }
@@ -0,0 +1,177 @@
+use crate::network::{ConnectionInfo, PortInfo, PortManager};
+use crate::process::{ProcessInfo, ProcessMonitor, SystemStats};
+use crate::tui::{AppEvent, EventSender};
+use anyhow::{anyhow, Result};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+
+/// Processes, ports and connections collected at one moment. Published by
+/// the collector and only read afterwards.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub processes: Vec<ProcessInfo>,
+    pub ports: Vec<PortInfo>,
+    pub connections: Vec<ConnectionInfo>,
+    /// Overall CPU usage, 0-100
+    pub system_cpu: f32,
+    /// Memory, swap, load and uptime
+    pub system: SystemStats,
+    /// How long collecting took
+    pub elapsed: Duration,
+}
+
+impl Snapshot {
+    /// Collect on the current thread. This runs netstat and lsof, so it can
+    /// take hundreds of milliseconds.
+    pub fn collect(monitor: &mut ProcessMonitor) -> Result<Self> {
+        let started = Instant::now();
+        let processes = monitor.get_processes();
+        let system_cpu = monitor.get_system_cpu_usage();
+        let system = monitor.get_system_stats();
+        let ports = PortManager::get_all_ports()?;
+        let connections = PortManager::get_active_connections()?;
+
+        Ok(Self {
+            processes,
+            ports,
+            connections,
+            system_cpu,
+            system,
+            elapsed: started.elapsed(),
+        })
+    }
+}
+
+/// Background task collecting snapshots on request, so the UI keeps
+/// handling input while netstat and lsof run. Snapshots arrive as
+/// `AppEvent::Snapshot`; pass each one through `finished`.
+pub struct Collector {
+    requests: mpsc::Sender<()>,
+    /// Kept to restart the task if it stops
+    events: EventSender,
+    busy: bool,
+    /// A request arrived while collecting; the snapshot in flight may
+    /// predate whatever prompted it
+    queued: bool,
+}
+
+impl Collector {
+    /// Start the collector task. Must be called from within a tokio runtime.
+    pub fn spawn(monitor: ProcessMonitor, events: EventSender) -> Self {
+        Self {
+            requests: Self::start(monitor, events.clone()),
+            events,
+            busy: false,
+            queued: false,
+        }
+    }
+
+    fn start(mut monitor: ProcessMonitor, events: EventSender) -> mpsc::Sender<()> {
+        let (requests, mut request_rx) = mpsc::channel::<()>(1);
+
+        tokio::spawn(async move {
+            while request_rx.recv().await.is_some() {
+                let collected = tokio::task::spawn_blocking(move || {
+                    let snapshot = Snapshot::collect(&mut monitor);
+                    (monitor, snapshot)
+                })
+                .await;
+                let Ok((returned, snapshot)) = collected else {
+                    let stopped = anyhow!("data collection stopped unexpectedly");
+                    let _ = events.send(AppEvent::Snapshot(Err(stopped)));
+                    break;
+                };
+                monitor = returned;
+                if events.send(AppEvent::Snapshot(snapshot)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        requests
+    }
+
+    /// Ask for a fresh snapshot. A request made while one is being collected
+    /// is served right after it. Fails if the collector task has stopped; a
+    /// new one is started to serve the next request.
+    pub fn request(&mut self) -> Result<()> {
+        if self.busy {
+            self.queued = true;
+            return Ok(());
+        }
+        match self.requests.try_send(()) {
+            Ok(()) => {
+                self.busy = true;
+                Ok(())
+            }
+            Err(TrySendError::Full(())) => Ok(()),
+            Err(TrySendError::Closed(())) => {
+                self.requests = Self::start(ProcessMonitor::new(), self.events.clone());
+                Err(anyhow!("data collection stopped and was restarted"))
+            }
+        }
+    }
+
+    /// Whether a snapshot is being collected.
+    pub fn is_busy(&self) -> bool {
+        self.busy
+    }
+
+    /// Record that a snapshot arrived, starting the next one if another
+    /// request came in meanwhile.
+    pub fn finished(&mut self) -> Result<()> {
+        self.busy = false;
+        if std::mem::take(&mut self.queued) {
+            self.request()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_collector_serves_queued_request() {
+        let (events, mut received) = mpsc::unbounded_channel();
+        let mut collector = Collector::spawn(ProcessMonitor::new(), events);
+        collector.request().unwrap();
+        collector.request().unwrap();
+        assert!(collector.is_busy());
+
+        for _ in 0..2 {
+            let event = tokio::time::timeout(Duration::from_secs(30), received.recv()).await;
+            let Ok(Some(AppEvent::Snapshot(snapshot))) = event else {
+                panic!("expected a snapshot");
+            };
+            let snapshot = snapshot.unwrap();
+            assert!(!snapshot.processes.is_empty());
+            assert!(snapshot.system.total_memory > 0);
+            collector.finished().unwrap();
+        }
+        assert!(!collector.is_busy());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_collector_restarts_stopped_task() {
+        let (events, mut received) = mpsc::unbounded_channel();
+        let (requests, _) = mpsc::channel(1);
+        let mut collector = Collector {
+            requests,
+            events,
+            busy: false,
+            queued: false,
+        };
+
+        let error = collector.request().unwrap_err();
+        assert!(error.to_string().contains("restarted"));
+        assert!(!collector.is_busy());
+
+        collector.request().unwrap();
+        assert!(collector.is_busy());
+        let event = tokio::time::timeout(Duration::from_secs(30), received.recv()).await;
+        assert!(matches!(event, Ok(Some(AppEvent::Snapshot(Ok(_))))));
+    }
+}
@@ -1,15 +1,30 @@
+use crate::commands::{command_label, format_elapsed, user_label, ProcessColumn};
+use crate::network::NetworkUtils;
+use crate::process::{format_timestamp, KillFailure, ProcessInfo, Signal};
+use crate::tui::keymap::{Action, HELP_SECTIONS};
+use crate::tui::mouse::{ClickTarget, HitMap};
+use crate::tui::settings_editor::SettingsField;
 use crate::tui::themes::Theme;
-use crate::tui::AppState;
+use crate::tui::{AppMode, AppState, HistoryPane, RemoteDetails, SortBy};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{BarChart, Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{
+        block::{Position, Title},
+        BarChart, Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph, Wrap,
+    },
     Frame,
 };
+use std::time::{SystemTime, UNIX_EPOCH};
 
 pub fn render_dashboard(f: &mut Frame, app: &mut AppState) {
-    let theme = app.themes[app.current_theme_index].clone();
+    // The theme selector previews the highlighted theme
+    let theme_index = match app.mode {
+        crate::tui::AppMode::ThemeSelector => app.selected_index.min(app.themes.len() - 1),
+        _ => app.current_theme_index,
+    };
+    let theme = app.themes[theme_index].clone();
     let size = f.size();
 
     // Set the background color for the entire frame
@@ -18,26 +33,61 @@ pub fn render_dashboard(f: &mut Frame, app: &mut AppState) {
         size,
     );
 
+    // Clickable areas, collected while drawing
+    let mut hits = HitMap::default();
+
     match app.mode {
-        crate::tui::AppMode::Dashboard => render_main_dashboard(f, app, &theme, size),
-        crate::tui::AppMode::ProcessView => render_process_view(f, app, &theme, size),
-        crate::tui::AppMode::PortView => render_port_view(f, app, &theme, size),
-        crate::tui::AppMode::ConnectionView => render_connection_view(f, app, &theme, size),
-        crate::tui::AppMode::ThemeSelector => render_theme_selector(f, app, &theme, size),
+        AppMode::Dashboard => render_main_dashboard(f, app, &theme, size, &mut hits),
+        AppMode::ProcessView => render_process_view(f, app, &theme, size, &mut hits),
+        AppMode::PortView => render_port_view(f, app, &theme, size, &mut hits),
+        AppMode::ConnectionView => render_connection_view(f, app, &theme, size, &mut hits),
+        AppMode::ThemeSelector => render_theme_selector(f, app, &theme, size, &mut hits),
+        AppMode::Settings => render_settings_view(f, app, &theme, size, &mut hits),
     }
 
     // Always render status bar
     render_status_bar(f, app, &theme, size);
 
-    // Render overlays
+    // Render overlays; the view underneath stops reacting to clicks
+    let overlay = app.show_help
+        || app.confirmation_dialog.is_some()
+        || app.quick_actions_menu.is_some()
+        || app.remote_details.is_some()
+        || app.signal_picker.is_some()
+        || app.history.is_some()
+        || app.search_picker.is_some()
+        || app.save_search_name.is_some();
+    if overlay {
+        hits.clear();
+    }
     if app.show_help {
-        render_help_dialog(f, &theme, size);
+        render_help_dialog(f, app, &theme, size);
     } else if app.confirmation_dialog.is_some() {
-        render_confirmation_dialog(f, app, &theme, size);
+        render_confirmation_dialog(f, app, &theme, size, &mut hits);
+    } else if app.quick_actions_menu.is_some() {
+        render_quick_actions_menu(f, app, &theme, size);
+    } else if app.signal_picker.is_some() {
+        render_signal_picker(f, app, &theme, size);
+    } else if let Some(details) = &app.remote_details {
+        render_remote_details(f, app, details, &theme, size);
+    } else if let Some(history) = &app.history {
+        render_history(f, history, &theme, size);
+    } else if app.search_picker.is_some() {
+        render_search_picker(f, app, &theme, size);
+    } else if let Some(name) = &app.save_search_name {
+        render_save_search_prompt(f, app, name, &theme, size);
     }
+
+    app.hit_map = hits;
 }
 
-fn render_main_dashboard(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect) {
+fn render_main_dashboard(
+    f: &mut Frame,
+    app: &AppState,
+    theme: &Theme,
+    area: Rect,
+    hits: &mut HitMap,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -58,28 +108,87 @@ fn render_main_dashboard(f: &mut Frame, app: &AppState, theme: &Theme, area: Rec
             ),
             Span::raw("- Mac Process Manager").style(Style::default().fg(theme.foreground)),
         ]),
-        Line::from("1: Dashboard | 2: Processes | 3: Ports | 4: Connections | 5: Themes | h: Help | q: Quit")
-            .style(Style::default().fg(theme.text_secondary)),
+        Line::from(view_hints(app)).style(Style::default().fg(theme.text_secondary)),
     ])
     .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.border)));
     f.render_widget(header, chunks[0]);
+    render_view_tabs(f, app, theme, chunks[0], hits);
+
+    // Main content area: two columns when there is room, stacked otherwise
+    let content = chunks[1];
+    if content.width >= 100 {
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(content);
+
+        let left_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(main_chunks[0]);
+        render_top_processes(f, app, theme, left_chunks[0], SortBy::Cpu);
+        render_top_processes(f, app, theme, left_chunks[1], SortBy::Memory);
+
+        let right_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Memory gauge
+                Constraint::Length(3), // Swap gauge
+                Constraint::Length(6), // System summary
+                Constraint::Min(0),    // History charts
+            ])
+            .split(main_chunks[1]);
+        render_memory_gauges(f, app, theme, right_chunks[0], right_chunks[1]);
+        render_system_summary(f, app, theme, right_chunks[2]);
+        render_history_charts(f, app, theme, right_chunks[3], Direction::Vertical);
+    } else {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Memory and swap gauges
+                Constraint::Length(6), // System summary
+                Constraint::Min(6),    // Top processes
+                // History charts, on tall terminals only
+                Constraint::Length(if content.height >= 30 { 10 } else { 0 }),
+            ])
+            .split(content);
+
+        let gauges = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[0]);
+        render_memory_gauges(f, app, theme, gauges[0], gauges[1]);
+        render_system_summary(f, app, theme, rows[1]);
+
+        if content.width >= 70 {
+            let lists = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(rows[2]);
+            render_top_processes(f, app, theme, lists[0], SortBy::Cpu);
+            render_top_processes(f, app, theme, lists[1], SortBy::Memory);
+        } else {
+            render_top_processes(f, app, theme, rows[2], SortBy::Cpu);
+        }
+        render_history_charts(f, app, theme, rows[3], Direction::Horizontal);
+    }
+}
 
-    // Main content area
-    let main_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(chunks[1]);
-
-    let left_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
-        .split(main_chunks[0]);
+/// The processes using the most CPU or memory, as many as fit.
+fn render_top_processes(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect, by: SortBy) {
+    let mut top: Vec<&ProcessInfo> = app.processes.iter().collect();
+    let title = if by == SortBy::Memory {
+        top.sort_by_key(|p| std::cmp::Reverse(p.memory));
+        "Top Processes (Memory)"
+    } else {
+        top.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
+        "Top Processes (CPU)"
+    };
 
-    // Left panel - Top processes
-    let top_processes: Vec<ListItem> = app
-        .processes
-        .iter()
-        .take(10)
+    let rows = usize::from(area.height.saturating_sub(2)).min(10);
+    let items: Vec<ListItem> = top
+        .into_iter()
+        .take(rows)
         .enumerate()
         .map(|(i, p)| {
             ListItem::new(Line::from(vec![
@@ -91,52 +200,203 @@ fn render_main_dashboard(f: &mut Frame, app: &AppState, theme: &Theme, area: Rec
                 ),
                 Span::raw(format!(" {:>6.1}%", p.cpu_usage))
                     .style(Style::default().fg(theme.accent)),
-                Span::raw(format!(" {:>8}", p.format_memory()))
-                    .style(Style::default().fg(theme.secondary)),
+                Span::raw(format!(
+                    " {:>8}",
+                    app.settings.memory_units.format(p.memory)
+                ))
+                .style(Style::default().fg(theme.secondary)),
             ]))
         })
         .collect();
 
-    let processes_list = List::new(top_processes)
-        .block(
-            Block::default()
-                .title("Top Processes (CPU)")
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(theme.border)),
-        )
-        .highlight_style(
-            Style::default()
-                .bg(theme.highlight)
-                .add_modifier(Modifier::BOLD),
-        )
-        .highlight_symbol("> ");
+    let list = List::new(items).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border)),
+    );
+    f.render_widget(list, area);
+}
+
+/// Yellow from 75% and red from 90%, so a filling gauge stands out.
+fn usage_color(percent: f64, theme: &Theme) -> Color {
+    if percent >= 90.0 {
+        Color::Red
+    } else if percent >= 75.0 {
+        Color::Yellow
+    } else {
+        theme.primary
+    }
+}
+
+fn render_memory_gauges(
+    f: &mut Frame,
+    app: &AppState,
+    theme: &Theme,
+    memory_area: Rect,
+    swap_area: Rect,
+) {
+    let stats = &app.system_stats;
+    let units = app.settings.memory_units;
+    let gauges = [
+        (
+            "Memory",
+            stats.used_memory,
+            stats.total_memory,
+            stats.memory_percent(),
+            memory_area,
+        ),
+        (
+            "Swap",
+            stats.used_swap,
+            stats.total_swap,
+            stats.swap_percent(),
+            swap_area,
+        ),
+    ];
+
+    for (title, used, total, percent, area) in gauges {
+        let label = if total == 0 {
+            "none".to_string()
+        } else {
+            format!(
+                "{} / {} ({percent:.0}%)",
+                units.format(used),
+                units.format(total)
+            )
+        };
+        let gauge = Gauge::default()
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.border)),
+            )
+            .gauge_style(
+                Style::default()
+                    .fg(usage_color(percent, theme))
+                    .bg(theme.background),
+            )
+            .label(Span::styled(label, Style::default().fg(theme.foreground)))
+            .ratio((percent / 100.0).clamp(0.0, 1.0));
+        f.render_widget(gauge, area);
+    }
+}
+
+/// Load average, uptime, process and thread counts, and the port summary.
+fn render_system_summary(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect) {
+    let stats = &app.system_stats;
+    let listening_ports = app
+        .ports
+        .iter()
+        .filter(|p| matches!(p.state, crate::network::ConnectionState::Listen))
+        .count();
+    let dev_ports = app
+        .ports
+        .iter()
+        .filter(|p| p.is_known_dev_port(&app.presets))
+        .count();
+
+    let label =
+        |text: &'static str| Span::raw(text).style(Style::default().fg(theme.text_secondary));
+    let value = |text: String, color: Color| Span::raw(text).style(Style::default().fg(color));
+    let [one, five, fifteen] = stats.load_average;
+    let threads = match stats.threads {
+        Some(threads) => format!(", {threads} threads"),
+        None => String::new(),
+    };
+
+    let items: Vec<ListItem> = vec![
+        ListItem::new(Line::from(vec![
+            label("Load:    "),
+            value(format!("{one:.2}  {five:.2}  {fifteen:.2}"), theme.accent),
+            label("  (1, 5, 15 min)"),
+        ])),
+        ListItem::new(Line::from(vec![
+            label("Uptime:  "),
+            value(format_elapsed(stats.uptime), theme.foreground),
+        ])),
+        ListItem::new(Line::from(vec![
+            label("Tasks:   "),
+            value(
+                format!("{} processes{threads}", app.processes.len()),
+                theme.foreground,
+            ),
+        ])),
+        ListItem::new(Line::from(vec![
+            label("Ports:   "),
+            value(app.ports.len().to_string(), theme.foreground),
+            label(" total, "),
+            value(listening_ports.to_string(), theme.secondary),
+            label(" listening, "),
+            value(dev_ports.to_string(), theme.primary),
+            label(" development"),
+        ])),
+    ];
+
+    let list = List::new(items).block(
+        Block::default()
+            .title("System")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border)),
+    );
+    f.render_widget(list, area);
+}
+
+/// CPU and memory history side by side or one above the other. Memory is
+/// dropped first when there isn't room for both.
+fn render_history_charts(
+    f: &mut Frame,
+    app: &AppState,
+    theme: &Theme,
+    area: Rect,
+    direction: Direction,
+) {
+    if area.height < 5 {
+        return;
+    }
+    let room = match direction {
+        Direction::Vertical => area.height >= 16,
+        Direction::Horizontal => area.width >= 60,
+    };
+    if !room {
+        render_usage_chart(f, theme, area, "⚡ CPU Usage", &app.cpu_history);
+        return;
+    }
 
-    f.render_widget(processes_list, left_chunks[0]);
+    let charts = Layout::default()
+        .direction(direction)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    render_usage_chart(f, theme, charts[0], "⚡ CPU Usage", &app.cpu_history);
+    render_usage_chart(f, theme, charts[1], "Memory Usage", &app.memory_history);
+}
 
-    // --- Enhanced CPU Usage Chart ---
-    let current_cpu_usage = app.cpu_history.last().cloned().unwrap_or(0);
-    let max_cpu_in_history = app.cpu_history.iter().max().cloned().unwrap_or(0).max(25);
+/// Bar chart of a 0-100% history, scaled to its peak.
+fn render_usage_chart(f: &mut Frame, theme: &Theme, area: Rect, title: &str, history: &[u64]) {
+    let current_usage = history.last().cloned().unwrap_or(0);
+    let max_in_history = history.iter().max().cloned().unwrap_or(0).max(25);
 
     // Use a more reasonable scale that shows actual data well
-    let y_max = if max_cpu_in_history <= 25 {
+    let y_max = if max_in_history <= 25 {
         25
-    } else if max_cpu_in_history <= 50 {
+    } else if max_in_history <= 50 {
         50
-    } else if max_cpu_in_history <= 75 {
+    } else if max_in_history <= 75 {
         75
     } else {
         100
     };
 
-    let chart_title = format!("⚡ CPU Usage ({current_cpu_usage}%)");
+    let chart_title = format!("{title} ({current_usage}%)");
 
     let chart_container = Block::default()
         .title(chart_title)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.accent));
 
-    let chart_area = chart_container.inner(left_chunks[1]);
-    f.render_widget(chart_container, left_chunks[1]);
+    let chart_area = chart_container.inner(area);
+    f.render_widget(chart_container, area);
 
     // Create a simple layout for the chart content
     let inner_layout = Layout::default()
@@ -163,13 +423,12 @@ fn render_main_dashboard(f: &mut Frame, app: &AppState, theme: &Theme, area: Rec
     f.render_widget(y_axis_labels, inner_layout[0]);
 
     // Chart area with proper scaling - ensure bars are visible
-    let chart_data: Vec<(&str, u64)> = app
-        .cpu_history
+    let chart_data: Vec<(&str, u64)> = history
         .iter()
         .enumerate()
         .map(|(i, &value)| {
             // Use index as label to help with spacing, ensure minimum height for visibility
-            let visible_value = if value == 0 && current_cpu_usage > 0 {
+            let visible_value = if value == 0 && current_usage > 0 {
                 1
             } else {
                 value.min(y_max)
@@ -191,41 +450,15 @@ fn render_main_dashboard(f: &mut Frame, app: &AppState, theme: &Theme, area: Rec
         .max(y_max);
 
     f.render_widget(barchart, inner_layout[1]);
-
-    // Right panel - Port summary
-    let listening_ports = app
-        .ports
-        .iter()
-        .filter(|p| matches!(p.state, crate::network::ConnectionState::Listen))
-        .count();
-    let dev_ports = app.ports.iter().filter(|p| p.is_development_port()).count();
-
-    let port_items: Vec<ListItem> = vec![
-        ListItem::new(Line::from(vec![
-            Span::raw("Total Ports: ").style(Style::default().fg(theme.text_secondary)),
-            Span::raw(app.ports.len().to_string()).style(Style::default().fg(theme.foreground)),
-        ])),
-        ListItem::new(Line::from(vec![
-            Span::raw("Listening: ").style(Style::default().fg(theme.text_secondary)),
-            Span::raw(listening_ports.to_string()).style(Style::default().fg(theme.secondary)),
-        ])),
-        ListItem::new(Line::from(vec![
-            Span::raw("Development: ").style(Style::default().fg(theme.text_secondary)),
-            Span::raw(dev_ports.to_string()).style(Style::default().fg(theme.primary)),
-        ])),
-    ];
-
-    let port_list = List::new(port_items).block(
-        Block::default()
-            .title("Port Summary")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(theme.border)),
-    );
-
-    f.render_widget(port_list, main_chunks[1]);
 }
 
-fn render_process_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: Rect) {
+fn render_process_view(
+    f: &mut Frame,
+    app: &mut AppState,
+    theme: &Theme,
+    area: Rect,
+    hits: &mut HitMap,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -243,16 +476,19 @@ fn render_process_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: R
 
     let header_text = if app.search_active {
         format!(
-            "Processes ({}) | Search: {} | Enter to confirm, Esc to cancel",
+            "Processes ({}) | Search: {} | Enter confirm | Tab patterns | Ctrl+S save | Esc cancel",
             app.filtered_processes.len(),
             app.search_query
         )
     } else {
         format!(
-            "Processes ({}) - Sorted by {:?} {} | / search | x kill | space select | s sort | Esc back",
+            "Processes ({}) - Sorted by {:?} {} | / search | x kill | {} signal | {}/{} nice | space select | s sort | Esc back",
             app.filtered_processes.len(),
             app.sort_by,
-            sort_indicator
+            sort_indicator,
+            app.keymap.hint(&app.mode, Action::SendSignal),
+            app.keymap.hint(&app.mode, Action::LowerPriority),
+            app.keymap.hint(&app.mode, Action::RaisePriority)
         )
     };
 
@@ -261,12 +497,18 @@ fn render_process_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: R
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Process Manager")
+                .title(view_title(app, "Process Manager"))
                 .border_style(Style::default().fg(theme.border)),
         );
     f.render_widget(header, chunks[0]);
-
-    // Process list
+    render_view_tabs(f, app, theme, chunks[0], hits);
+
+    // Process list, with the columns chosen in the settings
+    let columns = &app.settings.process_columns;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
     let items: Vec<ListItem> = app
         .filtered_processes
         .iter()
@@ -279,32 +521,41 @@ fn render_process_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: R
             } else {
                 Style::default().fg(theme.foreground)
             };
+            // Stopped processes are dimmed, with their status marked
+            let style = if p.is_stopped() {
+                style.add_modifier(Modifier::DIM)
+            } else {
+                style
+            };
 
-            ListItem::new(Line::from(vec![
-                Span::styled(format!("{:>8} ", p.pid), style),
-                Span::styled(
-                    format!("{:20} ", truncate_string(&p.name, 20)),
-                    style.fg(theme.primary),
-                ),
-                Span::styled(format!("{:>6.1}% ", p.cpu_usage), style.fg(theme.accent)),
-                Span::styled(
-                    format!("{:>10} ", p.format_memory()),
-                    style.fg(theme.secondary),
-                ),
-                Span::styled(
-                    truncate_string(&p.status, 10),
-                    style.fg(theme.text_secondary),
-                ),
-            ]))
+            let spans: Vec<Span> = columns
+                .iter()
+                .map(|&column| {
+                    let (text, color) = process_cell(app, theme, p, column, now);
+                    match color {
+                        Some(color) => Span::styled(text, style.fg(color)),
+                        None => Span::styled(text, style),
+                    }
+                })
+                .collect();
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
+    let headings: Vec<(String, Option<SortBy>)> = columns
+        .iter()
+        .map(|&column| (pad_cell(column, column.header()), column_sort(column)))
+        .collect();
+    let column_headers = column_headings(chunks[1], &headings, hits);
+
     let mut list_state = ListState::default();
     list_state.select(Some(app.selected_index));
 
     let list = List::new(items)
         .block(
             Block::default()
+                .title(column_headers)
+                .title(selection_title(app, theme))
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(theme.border)),
         )
@@ -316,9 +567,16 @@ fn render_process_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: R
         .highlight_symbol("> ");
 
     f.render_stateful_widget(list, chunks[1], &mut list_state);
+    hits.add_list(chunks[1], list_state.offset(), app.filtered_processes.len());
 }
 
-fn render_port_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: Rect) {
+fn render_port_view(
+    f: &mut Frame,
+    app: &mut AppState,
+    theme: &Theme,
+    area: Rect,
+    hits: &mut HitMap,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -331,13 +589,13 @@ fn render_port_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: Rect
     // Header
     let header_text = if app.search_active {
         format!(
-            "Ports ({}) | Search: {} | Enter to confirm, Esc to cancel",
+            "Ports ({}) | Search: {} | Enter confirm | Tab patterns | Ctrl+S save | Esc cancel",
             app.filtered_ports.len(),
             app.search_query
         )
     } else {
         format!(
-            "Ports ({}) | / search | x kill | :port pattern | s sort | Esc back",
+            "Ports ({}) | / search | x kill | space select | :port pattern | s sort | Esc back",
             app.filtered_ports.len()
         )
     };
@@ -347,10 +605,11 @@ fn render_port_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: Rect
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Port Manager")
+                .title(view_title(app, "Port Manager"))
                 .border_style(Style::default().fg(theme.border)),
         );
     f.render_widget(header, chunks[0]);
+    render_view_tabs(f, app, theme, chunks[0], hits);
 
     // Port list
     let items: Vec<ListItem> = app
@@ -369,16 +628,23 @@ fn render_port_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: Rect
             };
 
             let service_info = p
-                .get_service_suggestion()
+                .service_label(&app.presets)
                 .unwrap_or_else(|| format!("{:?}", p.state));
 
+            let port_style = if app.presets.ui_settings.highlight_dev_ports
+                && p.is_known_dev_port(&app.presets)
+            {
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.foreground)
+            };
+
             ListItem::new(Line::from(vec![
+                Span::styled(format!("{:>6} ", p.port), port_style),
                 Span::styled(
-                    format!("{:>6} ", p.port),
-                    Style::default().fg(theme.foreground),
-                ),
-                Span::styled(
-                    format!("{:4} ", format!("{:?}", p.protocol)),
+                    format!("{:5} ", format!("{:?}", p.protocol)),
                     Style::default().fg(protocol_color),
                 ),
                 Span::styled(
@@ -404,12 +670,24 @@ fn render_port_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: Rect
         })
         .collect();
 
+    let headings = [
+        (format!("{:>6} ", "Port"), Some(SortBy::Port)),
+        (format!("{:5} ", "Proto"), None),
+        (format!("{:12} ", "State"), None),
+        (format!("{:>8} ", "PID"), None),
+        (format!("{:20} ", "Process"), None),
+        ("Service".to_string(), None),
+    ];
+    let column_headers = column_headings(chunks[1], &headings, hits);
+
     let mut list_state = ListState::default();
     list_state.select(Some(app.selected_index));
 
     let list = List::new(items)
         .block(
             Block::default()
+                .title(column_headers)
+                .title(selection_title(app, theme))
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(theme.border)),
         )
@@ -421,9 +699,16 @@ fn render_port_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: Rect
         .highlight_symbol("> ");
 
     f.render_stateful_widget(list, chunks[1], &mut list_state);
+    hits.add_list(chunks[1], list_state.offset(), app.filtered_ports.len());
 }
 
-fn render_connection_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area: Rect) {
+fn render_connection_view(
+    f: &mut Frame,
+    app: &mut AppState,
+    theme: &Theme,
+    area: Rect,
+    hits: &mut HitMap,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -435,14 +720,17 @@ fn render_connection_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area
 
     let header_text = if app.search_active {
         format!(
-            "Active Connections ({}) | Search: {} | Enter to confirm, Esc to cancel",
+            "Active Connections ({}) | Search: {} | Enter confirm | Tab patterns | Ctrl+S save | Esc cancel",
             app.filtered_connections.len(),
             app.search_query
         )
     } else {
         format!(
-            "Active Connections ({}) | / search | s sort | Esc back",
-            app.filtered_connections.len()
+            "Active Connections ({}) | / search | space select | x kill | {} owner | {} listener | {} details | s sort | Esc back",
+            app.filtered_connections.len(),
+            app.keymap.hint(&app.mode, Action::ShowOwner),
+            app.keymap.hint(&app.mode, Action::ShowListener),
+            app.keymap.hint(&app.mode, Action::RemoteDetails)
         )
     };
 
@@ -451,10 +739,11 @@ fn render_connection_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Network Connections")
+                .title(view_title(app, "Network Connections"))
                 .border_style(Style::default().fg(theme.border)),
         );
     f.render_widget(header, chunks[0]);
+    render_view_tabs(f, app, theme, chunks[0], hits);
 
     if app.filtered_connections.is_empty() {
         // Show message when no connections are available
@@ -475,7 +764,7 @@ fn render_connection_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area
 
                 ListItem::new(Line::from(vec![
                     Span::styled(
-                        format!("{:4} ", format!("{:?}", c.protocol)),
+                        format!("{:5} ", format!("{:?}", c.protocol)),
                         Style::default().fg(protocol_color),
                     ),
                     Span::styled(
@@ -502,12 +791,24 @@ fn render_connection_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area
             })
             .collect();
 
+        let headings = [
+            (format!("{:5} ", "Proto"), None),
+            (format!("{:21} ", "Local"), Some(SortBy::LocalAddress)),
+            ("   ".to_string(), None),
+            (format!("{:21} ", "Remote"), Some(SortBy::RemoteAddress)),
+            (format!("{:>8} ", "PID"), Some(SortBy::Pid)),
+            ("Process".to_string(), None),
+        ];
+        let column_headers = column_headings(chunks[1], &headings, hits);
+
         let mut list_state = ListState::default();
         list_state.select(Some(app.selected_index));
 
         let list = List::new(items)
             .block(
                 Block::default()
+                    .title(column_headers)
+                    .title(selection_title(app, theme))
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(theme.border)),
             )
@@ -519,15 +820,26 @@ fn render_connection_view(f: &mut Frame, app: &mut AppState, theme: &Theme, area
             .highlight_symbol("> ");
 
         f.render_stateful_widget(list, chunks[1], &mut list_state);
+        hits.add_list(
+            chunks[1],
+            list_state.offset(),
+            app.filtered_connections.len(),
+        );
     }
 }
 
-fn render_theme_selector(f: &mut Frame, app: &mut AppState, theme: &Theme, area: Rect) {
+fn render_theme_selector(
+    f: &mut Frame,
+    app: &mut AppState,
+    theme: &Theme,
+    area: Rect,
+    hits: &mut HitMap,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Header
-            Constraint::Min(0),    // Theme list
+            Constraint::Min(0),    // Theme list and preview
         ])
         .split(area);
 
@@ -539,11 +851,126 @@ fn render_theme_selector(f: &mut Frame, app: &mut AppState, theme: &Theme, area:
                 .border_style(Style::default().fg(theme.border)),
         );
     f.render_widget(header, chunks[0]);
+    render_view_tabs(f, app, theme, chunks[0], hits);
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(chunks[1]);
 
     let items: Vec<ListItem> = app
         .themes
         .iter()
-        .map(|t| ListItem::new(t.name.clone()).style(Style::default().fg(theme.foreground)))
+        .enumerate()
+        .map(|(i, t)| {
+            let marker = if i == app.current_theme_index {
+                " (current)"
+            } else {
+                ""
+            };
+            ListItem::new(format!("{}{marker}", t.name))
+                .style(Style::default().fg(theme.foreground))
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(app.selected_index));
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    f.render_stateful_widget(list, body[0], &mut list_state);
+    hits.add_list(body[0], list_state.offset(), app.themes.len());
+
+    // Colour swatches for the highlighted theme, which the whole UI is
+    // already drawn with
+    let swatches: Vec<Line> = [
+        ("background", theme.background),
+        ("foreground", theme.foreground),
+        ("primary", theme.primary),
+        ("secondary", theme.secondary),
+        ("accent", theme.accent),
+        ("highlight", theme.highlight),
+        ("border", theme.border),
+        ("text_secondary", theme.text_secondary),
+    ]
+    .into_iter()
+    .map(|(name, color)| {
+        Line::from(vec![
+            Span::styled("      ", Style::default().bg(color)),
+            Span::raw(" "),
+            Span::styled(format!("{name:16}"), Style::default().fg(color)),
+            Span::styled(
+                format!("{color}"),
+                Style::default().fg(theme.text_secondary),
+            ),
+        ])
+    })
+    .collect();
+
+    let preview = Paragraph::new(swatches).block(
+        Block::default()
+            .title(format!(" Preview: {} ", theme.name))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border)),
+    );
+    f.render_widget(preview, body[1]);
+}
+
+fn render_settings_view(
+    f: &mut Frame,
+    app: &AppState,
+    theme: &Theme,
+    area: Rect,
+    hits: &mut HitMap,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Settings list
+        ])
+        .split(area);
+
+    let header = Paragraph::new(
+        "Settings (↑/↓ select, ←/→ or Enter change, Esc back) - changes are saved immediately",
+    )
+    .style(Style::default().fg(theme.text_secondary))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border)),
+    );
+    f.render_widget(header, chunks[0]);
+    render_view_tabs(f, app, theme, chunks[0], hits);
+
+    let default_refresh_ms = app.presets.ui_settings.refresh_interval_ms;
+    let fields = SettingsField::all();
+    let field_count = fields.len();
+    let items: Vec<ListItem> = fields
+        .into_iter()
+        .map(|field| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:28}", field.label()),
+                    Style::default().fg(theme.foreground),
+                ),
+                Span::styled(
+                    field.value(&app.settings, default_refresh_ms),
+                    Style::default().fg(theme.accent),
+                ),
+            ]))
+        })
         .collect();
 
     let mut list_state = ListState::default();
@@ -563,6 +990,7 @@ fn render_theme_selector(f: &mut Frame, app: &mut AppState, theme: &Theme, area:
         .highlight_symbol("> ");
 
     f.render_stateful_widget(list, chunks[1], &mut list_state);
+    hits.add_list(chunks[1], list_state.offset(), field_count);
 }
 
 fn render_status_bar(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect) {
@@ -613,17 +1041,27 @@ fn render_status_bar(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect) {
     f.render_widget(status, status_chunks[0]);
 
     // Loading indicator and progress
-    if app.is_loading() {
+    if let Some(progress) = app.operation_progress {
+        let label = app.get_loading_message().unwrap_or_default();
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(theme.accent).bg(theme.background))
+            .label(label)
+            .ratio(progress.clamp(0.0, 1.0) as f64);
+        f.render_widget(gauge, status_chunks[1]);
+    } else if app.is_loading() {
         let loading_text = match &app.loading_state {
             crate::tui::LoadingState::RefreshingData => "⟳ Refreshing...",
             crate::tui::LoadingState::KillingProcess(_) => "⚡ Killing...",
             crate::tui::LoadingState::KillingPort(_) => "⚡ Killing...",
-            crate::tui::LoadingState::SearchingData => "🔍 Searching...",
+            crate::tui::LoadingState::RunningAction(_) => "⚡ Running action...",
             _ => "⟳ Working...",
         };
-        
-        let loading_indicator = Paragraph::new(loading_text)
-            .style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        let loading_indicator = Paragraph::new(loading_text).style(
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        );
         f.render_widget(loading_indicator, status_chunks[1]);
     } else {
         // Show refresh timer or other info when not loading
@@ -635,7 +1073,13 @@ fn render_status_bar(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect) {
     }
 }
 
-fn render_confirmation_dialog(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect) {
+fn render_confirmation_dialog(
+    f: &mut Frame,
+    app: &AppState,
+    theme: &Theme,
+    area: Rect,
+    hits: &mut HitMap,
+) {
     if let Some(ref dialog) = app.confirmation_dialog {
         let popup_area = centered_rect(60, 40, area);
 
@@ -660,7 +1104,12 @@ fn render_confirmation_dialog(f: &mut Frame, app: &AppState, theme: &Theme, area
             Line::from(vec![
                 Span::styled(danger_indicator, Style::default().fg(accent_color)),
                 Span::raw(" "),
-                Span::styled(dialog.title.clone(), Style::default().fg(title_color).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    dialog.title.clone(),
+                    Style::default()
+                        .fg(title_color)
+                        .add_modifier(Modifier::BOLD),
+                ),
             ]),
             Line::from(""),
             Line::from(dialog.message.clone()).style(Style::default().fg(theme.foreground)),
@@ -670,84 +1119,471 @@ fn render_confirmation_dialog(f: &mut Frame, app: &AppState, theme: &Theme, area
         if let Some(ref context) = dialog.context_info {
             dialog_lines.push(Line::from(""));
             dialog_lines.push(Line::from(vec![
-                Span::styled("Details: ", Style::default().fg(theme.text_secondary).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    "Details: ",
+                    Style::default()
+                        .fg(theme.text_secondary)
+                        .add_modifier(Modifier::BOLD),
+                ),
                 Span::raw(context.clone()).style(Style::default().fg(theme.text_secondary)),
             ]));
         }
 
-        dialog_lines.push(Line::from(""));
         dialog_lines.push(Line::from(""));
 
-        // Enhanced confirmation options
-        match dialog.danger_level {
-            crate::tui::DangerLevel::Critical => {
-                dialog_lines.push(Line::from(vec![
-                    Span::styled("Type 'YES' to confirm: ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-                    Span::styled(&app.critical_confirmation_buffer, Style::default().fg(Color::Red).bg(theme.highlight)),
-                    Span::styled("_", Style::default().fg(Color::Red)),
-                ]));
-                dialog_lines.push(Line::from(vec![
-                    Span::styled("n/Esc", Style::default().fg(theme.secondary)),
-                    Span::raw(" - Cancel"),
-                ]));
-            },
-            _ => {
-                dialog_lines.push(Line::from(vec![
-                    Span::styled("y/Enter", Style::default().fg(accent_color).add_modifier(Modifier::BOLD)),
-                    Span::raw(" - Confirm  |  "),
-                    Span::styled("n/Esc", Style::default().fg(theme.secondary)),
-                    Span::raw(" - Cancel"),
-                ]));
-            }
-        }
+        // Enhanced confirmation options; typed confirmations can only be
+        // cancelled with the mouse
+        let typed = app.requires_typed_confirmation(dialog);
+        let cancel_button = (" Cancel (n/Esc) ", ClickTarget::CancelDialog);
+        let buttons = if typed {
+            dialog_lines.push(Line::from(vec![
+                Span::styled(
+                    "Type 'YES' to confirm: ",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    &app.critical_confirmation_buffer,
+                    Style::default().fg(Color::Red).bg(theme.highlight),
+                ),
+                Span::styled("_", Style::default().fg(Color::Red)),
+            ]));
+            vec![cancel_button]
+        } else {
+            vec![
+                (" Confirm (y/Enter) ", ClickTarget::ConfirmDialog),
+                cancel_button,
+            ]
+        };
+
+        let block = Block::default()
+            .title(" Confirmation Required ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color));
+        let inner = block.inner(popup_area);
+        f.render_widget(block, popup_area);
+
+        let body = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(inner);
 
         let dialog_widget = Paragraph::new(dialog_lines)
-            .block(
-                Block::default()
-                    .title(" Confirmation Required ")
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(border_color)),
-            )
             .style(Style::default().fg(theme.foreground))
             .wrap(Wrap { trim: true });
+        f.render_widget(dialog_widget, body[0]);
 
-        f.render_widget(dialog_widget, popup_area);
+        // Buttons, centred on the last line
+        let gap = 3;
+        let total: u16 = buttons
+            .iter()
+            .map(|(label, _)| label.len() as u16)
+            .sum::<u16>()
+            + gap * (buttons.len() as u16 - 1);
+        let mut x = body[1].x + body[1].width.saturating_sub(total) / 2;
+        for (label, target) in buttons {
+            let style = match target {
+                ClickTarget::ConfirmDialog => Style::default()
+                    .fg(theme.background)
+                    .bg(accent_color)
+                    .add_modifier(Modifier::BOLD),
+                _ => Style::default().fg(theme.foreground).bg(theme.highlight),
+            };
+            let button = Rect::new(x, body[1].y, label.len() as u16, 1).intersection(body[1]);
+            f.render_widget(Paragraph::new(label).style(style), button);
+            hits.add(button, target);
+            x += label.len() as u16 + gap;
+        }
     }
 }
 
-fn render_help_dialog(f: &mut Frame, theme: &Theme, area: Rect) {
-    let popup_area = centered_rect(70, 60, area);
+fn render_quick_actions_menu(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect) {
+    let popup_area = centered_rect(60, 50, area);
+    f.render_widget(Clear, popup_area);
+
+    let items: Vec<ListItem> = app
+        .presets
+        .quick_actions
+        .values()
+        .map(|action| {
+            let targets = if action.ports.is_empty() {
+                action.processes.join(", ")
+            } else {
+                let ports: Vec<String> = action.ports.iter().map(|p| format!(":{p}")).collect();
+                ports.join(" ")
+            };
+            ListItem::new(vec![
+                Line::from(Span::styled(
+                    action.name.clone(),
+                    Style::default()
+                        .fg(theme.primary)
+                        .add_modifier(Modifier::BOLD),
+                )),
+                Line::from(Span::styled(
+                    format!("  {}", action.description),
+                    Style::default().fg(theme.foreground),
+                )),
+                Line::from(Span::styled(
+                    format!("  {}", truncate_string(&targets, 60)),
+                    Style::default().fg(theme.text_secondary),
+                )),
+            ])
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(app.quick_actions_menu);
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(" Quick Actions | Enter run | Esc close ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    f.render_stateful_widget(list, popup_area, &mut list_state);
+}
 
+fn render_signal_picker(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect) {
+    let popup_area = centered_rect(60, 50, area);
     f.render_widget(Clear, popup_area);
 
-    let help_text = vec![
-        Line::from(vec![Span::styled(
-            "BossyRust - Process Manager Help",
+    let items: Vec<ListItem> = Signal::ALL
+        .iter()
+        .map(|signal| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<9}", signal.to_string()),
+                    Style::default()
+                        .fg(theme.primary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(signal.description(), Style::default().fg(theme.foreground)),
+            ]))
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(app.signal_picker);
+
+    let targets = match app.selection.iter().filter(|item| !item.exited).count() {
+        0 => app
+            .filtered_processes
+            .get(app.selected_index)
+            .map(|p| format!("{} ({})", p.name, p.pid))
+            .unwrap_or_default(),
+        count => format!("{count} selected"),
+    };
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    " Send Signal to {targets} | Enter send | Esc close "
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
             Style::default()
-                .fg(theme.primary)
+                .bg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
-        )]),
+        )
+        .highlight_symbol("> ");
+
+    f.render_stateful_widget(list, popup_area, &mut list_state);
+}
+
+fn render_history(f: &mut Frame, history: &HistoryPane, theme: &Theme, area: Rect) {
+    let popup_area = centered_rect(90, 80, area);
+    f.render_widget(Clear, popup_area);
+
+    let dim = Style::default().fg(theme.text_secondary);
+    let lines: Vec<Line> = if let Some(error) = &history.error {
+        vec![Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(Color::Red),
+        ))]
+    } else if history.entries.is_empty() {
+        vec![Line::from(Span::styled(
+            "Nothing recorded yet: signals sent from the TUI or the CLI show up here",
+            dim,
+        ))]
+    } else {
+        history
+            .entries
+            .iter()
+            .skip(history.scroll)
+            .map(|entry| {
+                let outcome = entry.outcome();
+                let outcome_color = match (entry.success, entry.failure) {
+                    (true, _) => Color::Green,
+                    (false, Some(KillFailure::Protected | KillFailure::NotFound)) => Color::Yellow,
+                    (false, _) => Color::Red,
+                };
+                let initiator = match &entry.rule {
+                    Some(rule) => format!("rule:{rule}"),
+                    None => entry.initiator.as_str().to_string(),
+                };
+                let process = format!(
+                    "{} ({})",
+                    entry.name.as_deref().unwrap_or("unknown"),
+                    entry.pid
+                );
+                Line::from(vec![
+                    Span::styled(format!("{} ", format_timestamp(entry.timestamp)), dim),
+                    Span::styled(
+                        format!("{:<13}", entry.action),
+                        Style::default()
+                            .fg(theme.primary)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(format!("{outcome:<18}"), Style::default().fg(outcome_color)),
+                    Span::styled(format!("{initiator:<14} "), dim),
+                    Span::styled(
+                        format!("{process:<28} "),
+                        Style::default().fg(theme.foreground),
+                    ),
+                    Span::styled(entry.command_line.clone().unwrap_or_default(), dim),
+                ])
+            })
+            .collect()
+    };
+
+    let position = if history.entries.is_empty() {
+        String::new()
+    } else {
+        format!(" {}/{}", history.scroll + 1, history.entries.len())
+    };
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(
+                " History{position} (newest first) | ↑↓ scroll | Esc close "
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border)),
+    );
+    f.render_widget(paragraph, popup_area);
+}
+
+fn render_remote_details(
+    f: &mut Frame,
+    app: &AppState,
+    details: &RemoteDetails,
+    theme: &Theme,
+    area: Rect,
+) {
+    let popup_area = centered_rect(60, 50, area);
+    f.render_widget(Clear, popup_area);
+
+    let connection = &details.connection;
+    let summary = &details.summary;
+    let owner = match (connection.pid, &connection.process_name) {
+        (Some(pid), Some(name)) => format!("{name} (PID {pid})"),
+        (Some(pid), None) => format!("PID {pid}"),
+        (None, _) => "unknown".to_string(),
+    };
+    let remote_port = connection.remote_address.port();
+    let service = NetworkUtils::get_well_known_ports()
+        .get(&remote_port)
+        .map(|name| format!("{remote_port} ({name})"))
+        .unwrap_or_else(|| remote_port.to_string());
+    let hosts = if summary.host_names.is_empty() {
+        "not listed".to_string()
+    } else {
+        summary.host_names.join(", ")
+    };
+    let reverse = match (&summary.reverse_name, details.resolving) {
+        (_, true) => "resolving...".to_string(),
+        (Some(name), false) => name.clone(),
+        (None, false) => "no name found".to_string(),
+    };
+    let scope_color = if summary.scope.is_local() {
+        Color::Green
+    } else {
+        Color::Yellow
+    };
+
+    let field = |label: &str, value: String, color: Color| {
+        Line::from(vec![
+            Span::styled(
+                format!("{label:<13}"),
+                Style::default().fg(theme.text_secondary),
+            ),
+            Span::styled(value, Style::default().fg(color)),
+        ])
+    };
+    let mode = &app.mode;
+    let lines = vec![
+        field(
+            "Connection",
+            format!(
+                "{:?} {} -> {}",
+                connection.protocol, connection.local_address, connection.remote_address
+            ),
+            theme.foreground,
+        ),
+        field("Process", owner, theme.accent),
         Line::from(""),
-        Line::from(vec![Span::styled(
-            "Navigation:",
+        field("Address", summary.address.to_string(), theme.secondary),
+        field("Port", service, theme.foreground),
+        field("Scope", summary.scope.label().to_string(), scope_color),
+        field("/etc/hosts", hosts, theme.foreground),
+        field("Reverse DNS", reverse, theme.foreground),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "{}: kill owner | {}: go to process | {}: go to listener | {}: close",
+                app.keymap.hint(mode, Action::Kill),
+                app.keymap.hint(mode, Action::ShowOwner),
+                app.keymap.hint(mode, Action::ShowListener),
+                app.keymap.hint(mode, Action::Back)
+            ),
+            Style::default().fg(theme.text_secondary),
+        )),
+    ];
+
+    let popup = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Remote Address ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(popup, popup_area);
+}
+
+fn render_search_picker(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect) {
+    let popup_area = centered_rect(60, 50, area);
+    f.render_widget(Clear, popup_area);
+
+    let items: Vec<ListItem> = app
+        .search_patterns()
+        .into_iter()
+        .map(|(_, pattern, saved)| {
+            let mut title = vec![
+                Span::styled(
+                    format!("{:24} ", truncate_string(&pattern.name, 24)),
+                    Style::default()
+                        .fg(theme.primary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(pattern.pattern.clone(), Style::default().fg(theme.accent)),
+            ];
+            if saved {
+                title.push(Span::styled(
+                    " (saved)",
+                    Style::default().fg(theme.text_secondary),
+                ));
+            }
+
+            let mut lines = vec![Line::from(title)];
+            if !pattern.description.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    format!("  {}", pattern.description),
+                    Style::default().fg(theme.text_secondary),
+                )));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(app.search_picker);
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(" Search Patterns | Enter apply | Esc close ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
             Style::default()
-                .fg(theme.secondary)
+                .bg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from("  1: Dashboard | 2: Processes | 3: Ports | 4: Connections | 5: Themes"),
-        Line::from("  ↑/↓ or j/k - Navigate    u/d - Page up/down    g/G - Top/bottom"),
-        Line::from("  Space - Multi-select    c - Clear selection    Esc - Smart back"),
+        )
+        .highlight_symbol("> ");
+
+    f.render_stateful_widget(list, popup_area, &mut list_state);
+}
+
+fn render_save_search_prompt(f: &mut Frame, app: &AppState, name: &str, theme: &Theme, area: Rect) {
+    let popup_area = centered_rect(50, 20, area);
+    f.render_widget(Clear, popup_area);
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("Search: ", Style::default().fg(theme.text_secondary)),
+            Span::styled(app.search_query.clone(), Style::default().fg(theme.accent)),
+        ]),
         Line::from(""),
+        Line::from(vec![
+            Span::styled("Name: ", Style::default().fg(theme.text_secondary)),
+            Span::styled(name.to_string(), Style::default().fg(theme.foreground)),
+            Span::styled("_", Style::default().fg(theme.primary)),
+        ]),
+        Line::from(""),
+        Line::from("Enter to save, Esc to cancel").style(Style::default().fg(theme.text_secondary)),
+    ];
+
+    let prompt = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Save Search ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border)),
+    );
+    f.render_widget(prompt, popup_area);
+}
+
+fn render_help_dialog(f: &mut Frame, app: &AppState, theme: &Theme, area: Rect) {
+    let popup_area = centered_rect(80, 80, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let heading = |title: String| {
         Line::from(vec![Span::styled(
-            "Actions:",
+            title,
             Style::default()
                 .fg(theme.secondary)
                 .add_modifier(Modifier::BOLD),
+        )])
+    };
+
+    let mut help_text = vec![
+        Line::from(vec![Span::styled(
+            "BossyRust - Process Manager Help",
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
         )]),
-        Line::from("  Enter/x/Delete - Kill selected process/port"),
-        Line::from("  / - Search mode    s - Cycle sort options"),
-        Line::from("  r/Ctrl+R - Refresh data    q - Quit    h - Help"),
         Line::from(""),
+    ];
+
+    // Generated from the active keymap so it always matches the bindings
+    for (section, rows) in HELP_SECTIONS.iter().zip(app.keymap.help_rows(&app.mode)) {
+        help_text.push(heading(format!("{section}:")));
+        for pair in rows.chunks(2) {
+            let line = pair
+                .iter()
+                .map(|(keys, description)| format!("  {keys:<16}{description:<34}"))
+                .collect::<String>();
+            help_text.push(Line::from(line.trim_end().to_string()));
+        }
+        help_text.push(Line::from(""));
+    }
+    help_text.push(Line::from(
+        "  Ctrl+R - Refresh    Ctrl+C - Quit (always available)",
+    ));
+    help_text.push(Line::from(""));
+
+    help_text.extend([
         Line::from(vec![Span::styled(
             "Search Patterns:",
             Style::default()
@@ -759,6 +1595,8 @@ fn render_help_dialog(f: &mut Frame, theme: &Theme, area: Rect) {
         Line::from("  >50% - Processes using more than 50% CPU"),
         Line::from("  >1GB - Processes using more than 1GB memory"),
         Line::from("  node - Search by process name"),
+        Line::from("  node|python - Match any of several terms"),
+        Line::from("  Tab (while searching) - Pick a saved pattern    Ctrl+S - Save the search"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Tips:",
@@ -766,12 +1604,17 @@ fn render_help_dialog(f: &mut Frame, theme: &Theme, area: Rect) {
                 .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from("  • Multi-select with Space, then Delete to kill multiple"),
+        Line::from("  • Multi-select, then kill to act on all selected items"),
         Line::from("  • Use Ctrl+R to refresh if data seems stale"),
         Line::from("  • Connection view requires active network connections"),
+        Line::from("  • Rebind keys under [keyboard_shortcuts] in presets.toml"),
         Line::from(""),
-        Line::from("Press h, ?, or Esc to close this help"),
-    ];
+        Line::from(format!(
+            "Press {} or {} to close this help",
+            app.keymap.hint(&app.mode, Action::Help),
+            app.keymap.hint(&app.mode, Action::Back)
+        )),
+    ]);
 
     let help_widget = Paragraph::new(help_text)
         .block(
@@ -806,6 +1649,218 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// Block title for a list view, naming the project while its filter is on.
+fn view_title(app: &AppState, title: &str) -> String {
+    match &app.project {
+        Some(project) if app.project_filter => format!("{title} [project: {}]", project.name),
+        _ => title.to_string(),
+    }
+}
+
+/// Header line for the view keys, following the active keymap.
+fn view_hints(app: &AppState) -> String {
+    let mode = &app.mode;
+    [
+        (Action::ShowDashboard, "Dashboard"),
+        (Action::ShowProcesses, "Processes"),
+        (Action::ShowPorts, "Ports"),
+        (Action::ShowConnections, "Connections"),
+        (Action::ShowThemes, "Themes"),
+        (Action::ShowSettings, "Settings"),
+        (Action::Help, "Help"),
+        (Action::Quit, "Quit"),
+    ]
+    .iter()
+    .map(|&(action, label)| format!("{}: {label}", app.keymap.hint(mode, action)))
+    .collect::<Vec<_>>()
+    .join(" | ")
+}
+
+/// Draw the view names over the bottom border of a header block, right
+/// aligned. Each name switches to its view when clicked.
+fn render_view_tabs(f: &mut Frame, app: &AppState, theme: &Theme, header: Rect, hits: &mut HitMap) {
+    let tabs = [
+        (AppMode::Dashboard, " Dashboard "),
+        (AppMode::ProcessView, " Processes "),
+        (AppMode::PortView, " Ports "),
+        (AppMode::ConnectionView, " Connections "),
+        (AppMode::ThemeSelector, " Themes "),
+        (AppMode::Settings, " Settings "),
+    ];
+    // One border cell between tabs and before the corner
+    let total: u16 = tabs.iter().map(|(_, label)| label.len() as u16 + 1).sum();
+    if header.height < 2 || header.width < total + 2 {
+        return;
+    }
+
+    let y = header.bottom() - 1;
+    let mut x = header.right() - 1 - total;
+    for (mode, label) in tabs {
+        let style = if mode == app.mode {
+            Style::default()
+                .fg(theme.background)
+                .bg(theme.primary)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text_secondary)
+        };
+        let tab = Rect::new(x, y, label.len() as u16, 1);
+        f.render_widget(Paragraph::new(label).style(style), tab);
+        hits.add(tab, ClickTarget::View(mode));
+        x += label.len() as u16 + 1;
+    }
+}
+
+/// Title line naming the columns of a bordered list in `area`, indented past
+/// the highlight symbol. Sortable headings are registered as click targets.
+fn column_headings(area: Rect, headings: &[(String, Option<SortBy>)], hits: &mut HitMap) -> String {
+    let mut title = String::from("  ");
+    for (heading, sort) in headings {
+        if let Some(by) = sort {
+            let x = area.x + 1 + title.chars().count() as u16;
+            let width = heading.trim_end().chars().count() as u16;
+            let cell = Rect::new(x, area.y, width, 1).intersection(area);
+            hits.add(cell, ClickTarget::Sort(by.clone()));
+        }
+        title.push_str(heading);
+    }
+    title.trim_end().to_string()
+}
+
+/// Bottom border title summing up the multi-selection. Selected items that
+/// have exited are named, since they no longer have a row to mark.
+fn selection_title<'a>(app: &AppState, theme: &Theme) -> Title<'a> {
+    let mut spans = Vec::new();
+    if !app.selection.is_empty() {
+        spans.push(Span::styled(
+            format!(" {} selected ", app.selection.len()),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+        let exited: Vec<&str> = app
+            .selection
+            .iter()
+            .filter(|item| item.exited)
+            .map(|item| item.label.as_str())
+            .collect();
+        if !exited.is_empty() {
+            spans.push(Span::styled(
+                format!(" ✗ exited: {} ", exited.join(", ")),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
+        spans.push(Span::styled(
+            format!(
+                " {}: clear ",
+                app.keymap.hint(&app.mode, Action::ClearSelection)
+            ),
+            Style::default().fg(theme.text_secondary),
+        ));
+    }
+    Title::from(Line::from(spans)).position(Position::Bottom)
+}
+
+/// The sort a process column heading selects, if it has one.
+fn column_sort(column: ProcessColumn) -> Option<SortBy> {
+    match column {
+        ProcessColumn::Pid => Some(SortBy::Pid),
+        ProcessColumn::Name => Some(SortBy::Name),
+        ProcessColumn::Cpu => Some(SortBy::Cpu),
+        ProcessColumn::Mem => Some(SortBy::Memory),
+        _ => None,
+    }
+}
+
+fn process_column_width(column: ProcessColumn) -> usize {
+    match column {
+        ProcessColumn::Pid | ProcessColumn::Ppid => 8,
+        ProcessColumn::User => 12,
+        ProcessColumn::Name => 20,
+        ProcessColumn::Cpu => 7,
+        ProcessColumn::Mem => 10,
+        ProcessColumn::Status => 10,
+        ProcessColumn::Nice | ProcessColumn::Pri => 4,
+        ProcessColumn::Start => 8,
+        ProcessColumn::Command => 60,
+    }
+}
+
+/// Pad `text` to the column's width, right-aligning numbers.
+fn pad_cell(column: ProcessColumn, text: &str) -> String {
+    let width = process_column_width(column);
+    let text = truncate_string(text, width);
+    match column {
+        ProcessColumn::Pid
+        | ProcessColumn::Ppid
+        | ProcessColumn::Cpu
+        | ProcessColumn::Mem
+        | ProcessColumn::Nice
+        | ProcessColumn::Pri => format!("{text:>width$} "),
+        _ => format!("{text:width$} "),
+    }
+}
+
+/// Text and colour of one process-view cell. `None` keeps the row colour.
+fn process_cell(
+    app: &AppState,
+    theme: &Theme,
+    p: &ProcessInfo,
+    column: ProcessColumn,
+    now: u64,
+) -> (String, Option<Color>) {
+    let (text, color) = match column {
+        ProcessColumn::Pid => (p.pid.to_string(), None),
+        ProcessColumn::Ppid => (
+            p.parent_pid
+                .map(|pid| pid.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            None,
+        ),
+        ProcessColumn::User => (user_label(p, &app.user_names), Some(theme.text_secondary)),
+        ProcessColumn::Name => {
+            // Processes from a preset development group stand out by name
+            let color = if p.development_group(&app.presets).is_some() {
+                theme.secondary
+            } else {
+                theme.primary
+            };
+            if p.is_stopped() {
+                (format!("⏸ {}", p.name), Some(color))
+            } else {
+                (p.name.clone(), Some(color))
+            }
+        }
+        ProcessColumn::Cpu => (format!("{:.1}%", p.cpu_usage), Some(theme.accent)),
+        ProcessColumn::Mem => (
+            app.settings.memory_units.format(p.memory),
+            Some(theme.secondary),
+        ),
+        ProcessColumn::Status if p.is_stopped() => (p.status.clone(), Some(Color::Yellow)),
+        ProcessColumn::Status => (p.status.clone(), Some(theme.text_secondary)),
+        // Favoured processes in the accent colour, deprioritised ones dimmed
+        ProcessColumn::Nice => match p.nice {
+            Some(nice) if nice < 0 => (nice.to_string(), Some(theme.accent)),
+            Some(nice) if nice > 0 => (nice.to_string(), Some(theme.text_secondary)),
+            Some(nice) => (nice.to_string(), None),
+            None => ("-".to_string(), Some(theme.text_secondary)),
+        },
+        ProcessColumn::Pri => (
+            p.priority
+                .map(|pri| pri.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            Some(theme.text_secondary),
+        ),
+        ProcessColumn::Start => (
+            format_elapsed(now.saturating_sub(p.start_time)),
+            Some(theme.text_secondary),
+        ),
+        ProcessColumn::Command => (command_label(p), Some(theme.text_secondary)),
+    };
+    (pad_cell(column, &text), color)
+}
+
 fn truncate_string(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
         s.to_string()
@@ -1,33 +1,107 @@
-use anyhow::Result;
-use crossterm::event::{self, Event, KeyEvent};
+use crate::tui::collector::Snapshot;
+use crate::tui::KillReport;
+use anyhow::{anyhow, Result};
+use crossterm::event::{self, Event, EventStream, KeyEvent, MouseEvent};
+use futures::StreamExt;
+use std::net::IpAddr;
 use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{interval_at, Instant, Interval, MissedTickBehavior};
 
 pub enum AppEvent {
     Key(KeyEvent),
+    Mouse(MouseEvent),
     Resize(u16, u16),
+    FocusGained,
+    FocusLost,
+    Paste(String),
+    /// The tick timer fired
     Refresh,
+    /// The background collector finished a snapshot
+    Snapshot(Result<Snapshot>),
+    /// A kill started from the TUI finished
+    Kill(KillReport),
+    /// The reverse lookup for the remote address popup finished
+    ReverseLookup(IpAddr, Option<String>),
 }
 
+/// Sends events from background tasks into the event loop.
+pub type EventSender = mpsc::UnboundedSender<AppEvent>;
+
+/// Merges terminal input, ticks and events sent by background tasks.
 pub struct EventHandler {
-    tick_rate: Duration,
+    /// Opened on first use
+    terminal: Option<EventStream>,
+    terminal_closed: bool,
+    ticks: Interval,
+    sender: EventSender,
+    receiver: mpsc::UnboundedReceiver<AppEvent>,
 }
 
 impl EventHandler {
+    /// Must be called from within a tokio runtime.
     pub fn new(tick_rate: Duration) -> Self {
-        Self { tick_rate }
+        let mut ticks = interval_at(Instant::now() + tick_rate, tick_rate);
+        // After a stall, tick once rather than catching up
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        Self {
+            terminal: None,
+            terminal_closed: false,
+            ticks,
+            sender,
+            receiver,
+        }
     }
 
-    pub async fn next(&self) -> Result<AppEvent> {
-        let timeout = self.tick_rate;
+    /// Sender for background tasks reporting back to the event loop.
+    pub fn sender(&self) -> EventSender {
+        self.sender.clone()
+    }
+
+    /// Wait for the next event from any source.
+    pub async fn next(&mut self) -> Result<AppEvent> {
+        if self.terminal.is_none() && !self.terminal_closed {
+            // `EventStream` panics when there is no terminal; `poll` returns an error
+            event::poll(Duration::ZERO)?;
+            self.terminal = Some(EventStream::new());
+        }
 
-        if event::poll(timeout)? {
-            match event::read()? {
-                Event::Key(key_event) => Ok(AppEvent::Key(key_event)),
-                Event::Resize(width, height) => Ok(AppEvent::Resize(width, height)),
-                _ => Ok(AppEvent::Refresh),
+        loop {
+            let terminal = async {
+                match self.terminal.as_mut() {
+                    Some(stream) => stream.next().await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            tokio::select! {
+                input = terminal => match input {
+                    Some(Ok(event)) => return Ok(event.into()),
+                    Some(Err(e)) => return Err(anyhow!("Failed to read terminal input: {e}")),
+                    None => {
+                        self.terminal = None;
+                        self.terminal_closed = true;
+                    }
+                },
+                _ = self.ticks.tick() => return Ok(AppEvent::Refresh),
+                // The handler holds a sender itself, so this never ends
+                Some(event) = self.receiver.recv() => return Ok(event),
             }
-        } else {
-            Ok(AppEvent::Refresh)
+        }
+    }
+}
+
+impl From<Event> for AppEvent {
+    fn from(event: Event) -> Self {
+        match event {
+            Event::Key(key) => AppEvent::Key(key),
+            Event::Mouse(mouse) => AppEvent::Mouse(mouse),
+            Event::Resize(width, height) => AppEvent::Resize(width, height),
+            Event::FocusGained => AppEvent::FocusGained,
+            Event::FocusLost => AppEvent::FocusLost,
+            Event::Paste(text) => AppEvent::Paste(text),
         }
     }
 }
@@ -0,0 +1,700 @@
+use crate::config::presets::KeyboardShortcuts;
+use crate::tui::AppMode;
+use anyhow::{anyhow, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Something a key can be bound to in the TUI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::enum_variant_names)] // `PrimaryAction` mirrors its config name
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    PageUp,
+    PageDown,
+    GoToTop,
+    GoToBottom,
+    Back,
+    ShowDashboard,
+    ShowProcesses,
+    ShowPorts,
+    ShowConnections,
+    ShowThemes,
+    ShowSettings,
+    Help,
+    Search,
+    Refresh,
+    ToggleAutoRefresh,
+    ToggleProjectFilter,
+    ToggleSelection,
+    ClearSelection,
+    PrimaryAction,
+    Kill,
+    CycleSort,
+    QuickActions,
+    SendSignal,
+    RaisePriority,
+    LowerPriority,
+    ShowOwner,
+    ShowListener,
+    RemoteDetails,
+    ShowHistory,
+    Quit,
+}
+
+/// Headings used to group actions in the help dialog.
+pub const HELP_SECTIONS: [&str; 3] = ["Navigation", "Views", "Actions"];
+
+impl Action {
+    pub const ALL: [Action; 32] = [
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::PageUp,
+        Action::PageDown,
+        Action::GoToTop,
+        Action::GoToBottom,
+        Action::Back,
+        Action::ShowDashboard,
+        Action::ShowProcesses,
+        Action::ShowPorts,
+        Action::ShowConnections,
+        Action::ShowThemes,
+        Action::ShowSettings,
+        Action::Help,
+        Action::Search,
+        Action::Refresh,
+        Action::ToggleAutoRefresh,
+        Action::ToggleProjectFilter,
+        Action::ToggleSelection,
+        Action::ClearSelection,
+        Action::PrimaryAction,
+        Action::Kill,
+        Action::CycleSort,
+        Action::QuickActions,
+        Action::SendSignal,
+        Action::RaisePriority,
+        Action::LowerPriority,
+        Action::ShowOwner,
+        Action::ShowListener,
+        Action::RemoteDetails,
+        Action::ShowHistory,
+        Action::Quit,
+    ];
+
+    /// Name used in `[keyboard_shortcuts]`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::MoveUp => "move_up",
+            Action::MoveDown => "move_down",
+            Action::PageUp => "page_up",
+            Action::PageDown => "page_down",
+            Action::GoToTop => "go_to_top",
+            Action::GoToBottom => "go_to_bottom",
+            Action::Back => "back",
+            Action::ShowDashboard => "show_dashboard",
+            Action::ShowProcesses => "show_processes",
+            Action::ShowPorts => "show_ports",
+            Action::ShowConnections => "show_connections",
+            Action::ShowThemes => "show_themes",
+            Action::ShowSettings => "show_settings",
+            Action::Help => "help",
+            Action::Search => "search",
+            Action::Refresh => "refresh",
+            Action::ToggleAutoRefresh => "toggle_auto_refresh",
+            Action::ToggleProjectFilter => "toggle_project_filter",
+            Action::ToggleSelection => "toggle_selection",
+            Action::ClearSelection => "clear_selection",
+            Action::PrimaryAction => "primary_action",
+            Action::Kill => "kill",
+            Action::CycleSort => "cycle_sort",
+            Action::QuickActions => "quick_actions",
+            Action::SendSignal => "send_signal",
+            Action::RaisePriority => "raise_priority",
+            Action::LowerPriority => "lower_priority",
+            Action::ShowOwner => "show_owner",
+            Action::ShowListener => "show_listener",
+            Action::RemoteDetails => "remote_details",
+            Action::ShowHistory => "show_history",
+            Action::Quit => "quit",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::MoveUp => "Move up",
+            Action::MoveDown => "Move down",
+            Action::PageUp => "Page up",
+            Action::PageDown => "Page down",
+            Action::GoToTop => "Go to top",
+            Action::GoToBottom => "Go to bottom",
+            Action::Back => "Back (clear search/selection, close help, quit)",
+            Action::ShowDashboard => "Dashboard",
+            Action::ShowProcesses => "Processes",
+            Action::ShowPorts => "Ports",
+            Action::ShowConnections => "Connections",
+            Action::ShowThemes => "Themes",
+            Action::ShowSettings => "Settings",
+            Action::Help => "Toggle this help",
+            Action::Search => "Search mode",
+            Action::Refresh => "Refresh data",
+            Action::ToggleAutoRefresh => "Toggle auto-refresh",
+            Action::ToggleProjectFilter => "Show only this project (.bossy.toml)",
+            Action::ToggleSelection => "Multi-select",
+            Action::ClearSelection => "Clear selection",
+            Action::PrimaryAction => {
+                "Kill selected process/port/connection owner (with confirmation)"
+            }
+            Action::Kill => "Kill selected or multi-selected items",
+            Action::CycleSort => "Cycle sort options",
+            Action::QuickActions => "Quick actions",
+            Action::SendSignal => "Send a signal (stop, continue, hang up, ...)",
+            Action::RaisePriority => "Raise priority: nice -1 (needs root)",
+            Action::LowerPriority => "Lower priority: nice +1",
+            Action::ShowOwner => "Go to the owning process (ports, connections)",
+            Action::ShowListener => "Go to the listening port (connections)",
+            Action::RemoteDetails => "Remote address details (connections)",
+            Action::ShowHistory => "History of sent signals (audit log)",
+            Action::Quit => "Quit",
+        }
+    }
+
+    /// Index into `HELP_SECTIONS`.
+    pub fn section(self) -> usize {
+        match self {
+            Action::MoveUp
+            | Action::MoveDown
+            | Action::PageUp
+            | Action::PageDown
+            | Action::GoToTop
+            | Action::GoToBottom
+            | Action::Back => 0,
+            Action::ShowDashboard
+            | Action::ShowProcesses
+            | Action::ShowPorts
+            | Action::ShowConnections
+            | Action::ShowThemes
+            | Action::ShowSettings
+            | Action::ShowHistory
+            | Action::Help => 1,
+            _ => 2,
+        }
+    }
+
+    fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            Action::MoveUp => &["Up", "k"],
+            Action::MoveDown => &["Down", "j"],
+            Action::PageUp => &["PageUp", "u"],
+            Action::PageDown => &["PageDown", "d"],
+            Action::GoToTop => &["Home", "g"],
+            Action::GoToBottom => &["End", "G"],
+            Action::Back => &["Esc"],
+            Action::ShowDashboard => &["1"],
+            Action::ShowProcesses => &["2", "F1"],
+            Action::ShowPorts => &["3", "F2"],
+            Action::ShowConnections => &["4", "F3"],
+            Action::ShowThemes => &["5"],
+            Action::ShowSettings => &["6"],
+            Action::Help => &["F4", "?", "h"],
+            Action::Search => &["/"],
+            Action::Refresh => &["r"],
+            Action::ToggleAutoRefresh => &[],
+            Action::ToggleProjectFilter => &["p"],
+            Action::ToggleSelection => &["Space"],
+            Action::ClearSelection => &["c"],
+            Action::PrimaryAction => &["Enter"],
+            Action::Kill => &["Delete", "x"],
+            Action::CycleSort => &["s"],
+            Action::QuickActions => &["a"],
+            Action::SendSignal => &["S"],
+            Action::RaisePriority => &["-"],
+            Action::LowerPriority => &["+", "="],
+            Action::ShowOwner => &["o"],
+            Action::ShowListener => &["l"],
+            Action::RemoteDetails => &["i"],
+            Action::ShowHistory => &["H"],
+            Action::Quit => &["q"],
+        }
+    }
+}
+
+/// Where a binding applies. View scopes take precedence over `Global`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyScope {
+    Global,
+    Dashboard,
+    ProcessView,
+    PortView,
+    ConnectionView,
+}
+
+impl KeyScope {
+    pub const ALL: [KeyScope; 5] = [
+        KeyScope::Global,
+        KeyScope::Dashboard,
+        KeyScope::ProcessView,
+        KeyScope::PortView,
+        KeyScope::ConnectionView,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyScope::Global => "global",
+            KeyScope::Dashboard => "dashboard",
+            KeyScope::ProcessView => "process_view",
+            KeyScope::PortView => "port_view",
+            KeyScope::ConnectionView => "connection_view",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|scope| scope.name() == name)
+    }
+
+    fn for_mode(mode: &AppMode) -> Option<Self> {
+        match mode {
+            AppMode::Dashboard => Some(KeyScope::Dashboard),
+            AppMode::ProcessView => Some(KeyScope::ProcessView),
+            AppMode::PortView => Some(KeyScope::PortView),
+            AppMode::ConnectionView => Some(KeyScope::ConnectionView),
+            AppMode::ThemeSelector | AppMode::Settings => None,
+        }
+    }
+}
+
+/// A key with its Ctrl/Alt modifiers, e.g. `x`, `F4` or `Ctrl+r`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    fn from_event(key: &KeyEvent) -> Self {
+        // Shift is already reflected in the character (`G` vs `g`)
+        let modifiers = key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+        Self {
+            code: key.code,
+            modifiers,
+        }
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            KeyCode::Up => write!(f, "Up"),
+            KeyCode::Down => write!(f, "Down"),
+            KeyCode::Left => write!(f, "Left"),
+            KeyCode::Right => write!(f, "Right"),
+            KeyCode::PageUp => write!(f, "PageUp"),
+            KeyCode::PageDown => write!(f, "PageDown"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Delete"),
+            KeyCode::Insert => write!(f, "Insert"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+/// Parse a key such as `x`, `G`, `F4`, `PageDown`, `Space` or `Ctrl+r`.
+pub fn parse_key(value: &str) -> Result<KeyBinding> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = value;
+    loop {
+        if let Some(stripped) = strip_prefix_ignore_case(rest, "ctrl+") {
+            modifiers |= KeyModifiers::CONTROL;
+            rest = stripped;
+        } else if let Some(stripped) = strip_prefix_ignore_case(rest, "alt+") {
+            modifiers |= KeyModifiers::ALT;
+            rest = stripped;
+        } else {
+            break;
+        }
+    }
+
+    let mut chars = rest.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) if !c.is_whitespace() => KeyCode::Char(c),
+        _ => match rest.to_lowercase().as_str() {
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "enter" => KeyCode::Enter,
+            "esc" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "delete" => KeyCode::Delete,
+            "insert" => KeyCode::Insert,
+            name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n @ 1..=12) => KeyCode::F(n),
+                _ => return Err(anyhow!("unknown key '{value}'")),
+            },
+        },
+    };
+
+    Ok(KeyBinding { code, modifiers })
+}
+
+fn parse_binding(
+    scope_name: &str,
+    action_name: &str,
+    keys: &[String],
+) -> Result<(KeyScope, Action, Vec<KeyBinding>)> {
+    let scope = KeyScope::from_name(scope_name)
+        .ok_or_else(|| anyhow!("keyboard_shortcuts.{scope_name}: unknown scope"))?;
+    let action = Action::from_name(action_name)
+        .ok_or_else(|| anyhow!("keyboard_shortcuts.{scope_name}.{action_name}: unknown action"))?;
+    let keys = keys
+        .iter()
+        .map(|key| {
+            parse_key(key)
+                .map_err(|e| anyhow!("keyboard_shortcuts.{scope_name}.{action_name}: {e}"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((scope, action, keys))
+}
+
+fn strip_prefix_ignore_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    value
+        .get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &value[prefix.len()..])
+}
+
+/// Key bindings for the list views, per scope.
+///
+/// Modal input (search, dialogs, popups and the theme selector) is handled
+/// directly by `AppState`, as are Ctrl+C and Ctrl+R, which always work.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyScope, BTreeMap<usize, Vec<KeyBinding>>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let global = Action::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, action)| {
+                let keys = action
+                    .default_keys()
+                    .iter()
+                    .map(|key| parse_key(key).expect("default key bindings are valid"))
+                    .collect();
+                (i, keys)
+            })
+            .collect();
+
+        Self {
+            bindings: HashMap::from([(KeyScope::Global, global)]),
+        }
+    }
+}
+
+impl Keymap {
+    /// The default keymap with the `[keyboard_shortcuts]` bindings applied.
+    /// Each configured action replaces that action's keys in its scope. Fails
+    /// if a key ends up bound to two actions in the same scope.
+    pub fn from_config(config: &KeyboardShortcuts) -> Result<Self> {
+        let mut keymap = Self::default();
+
+        for (scope_name, actions) in config {
+            for (action_name, keys) in actions {
+                let (scope, action, keys) = parse_binding(scope_name, action_name, keys)?;
+                keymap
+                    .bindings
+                    .entry(scope)
+                    .or_default()
+                    .insert(action.index(), keys);
+            }
+        }
+
+        keymap.check_conflicts()?;
+        Ok(keymap)
+    }
+
+    /// Check the scope, action and key names of a single binding without
+    /// looking for conflicts.
+    pub fn check_binding(scope: &str, action: &str, keys: &[String]) -> Result<()> {
+        parse_binding(scope, action, keys).map(|_| ())
+    }
+
+    fn check_conflicts(&self) -> Result<()> {
+        for scope in KeyScope::ALL {
+            let mut seen: HashMap<KeyBinding, Action> = HashMap::new();
+            for (action, keys) in self.scope_bindings(scope) {
+                for key in keys {
+                    if let Some(other) = seen.insert(*key, action) {
+                        return Err(anyhow!(
+                            "keyboard_shortcuts.{}: key '{key}' is bound to both '{}' and '{}'",
+                            scope.name(),
+                            other.name(),
+                            action.name()
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn scope_bindings(&self, scope: KeyScope) -> impl Iterator<Item = (Action, &Vec<KeyBinding>)> {
+        self.bindings
+            .get(&scope)
+            .into_iter()
+            .flatten()
+            .map(|(&i, keys)| (Action::ALL[i], keys))
+    }
+
+    /// The action bound to `key` in `mode`, checking the view's own scope
+    /// before the global one. An action the view rebinds no longer answers
+    /// to its global keys there, matching `keys_for`.
+    pub fn action_for(&self, mode: &AppMode, key: &KeyEvent) -> Option<Action> {
+        let pressed = KeyBinding::from_event(key);
+        let view = KeyScope::for_mode(mode);
+        let rebound_in_view = |action: Action| {
+            view.and_then(|scope| self.bindings.get(&scope))
+                .is_some_and(|bindings| bindings.contains_key(&action.index()))
+        };
+        view.into_iter()
+            .chain([KeyScope::Global])
+            .find_map(|scope| {
+                self.scope_bindings(scope)
+                    .find(|&(action, keys)| {
+                        keys.contains(&pressed)
+                            && (scope != KeyScope::Global || !rebound_in_view(action))
+                    })
+                    .map(|(action, _)| action)
+            })
+    }
+
+    /// Keys for `action` in `mode`: the view's own binding if it has one,
+    /// otherwise the global binding.
+    pub fn keys_for(&self, mode: &AppMode, action: Action) -> Vec<KeyBinding> {
+        KeyScope::for_mode(mode)
+            .into_iter()
+            .chain([KeyScope::Global])
+            .find_map(|scope| {
+                self.bindings
+                    .get(&scope)
+                    .and_then(|bindings| bindings.get(&action.index()))
+            })
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Short label for the first key of `action`, for on-screen hints.
+    pub fn hint(&self, mode: &AppMode, action: Action) -> String {
+        self.keys_for(mode, action)
+            .first()
+            .map(|key| key.to_string())
+            .unwrap_or_else(|| "-".to_string())
+    }
+
+    /// `(keys, description)` rows for the help dialog, grouped by
+    /// `HELP_SECTIONS`. Unbound actions are left out.
+    pub fn help_rows(&self, mode: &AppMode) -> Vec<Vec<(String, &'static str)>> {
+        let mut sections = vec![Vec::new(); HELP_SECTIONS.len()];
+        for action in Action::ALL {
+            let keys = self.keys_for(mode, action);
+            if keys.is_empty() {
+                continue;
+            }
+            let keys: Vec<String> = keys.iter().map(KeyBinding::to_string).collect();
+            sections[action.section()].push((keys.join("/"), action.description()));
+        }
+        sections
+    }
+}
+
+impl Action {
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&action| action == self)
+            .expect("every action is listed in Action::ALL")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(entries: &[(&str, &str, &[&str])]) -> KeyboardShortcuts {
+        let mut config = KeyboardShortcuts::new();
+        for (scope, action, keys) in entries {
+            config.entry(scope.to_string()).or_default().insert(
+                action.to_string(),
+                keys.iter().map(|k| k.to_string()).collect(),
+            );
+        }
+        config
+    }
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_default_bindings() {
+        let keymap = Keymap::default();
+        let mode = AppMode::ProcessView;
+        assert_eq!(
+            keymap.action_for(&mode, &press(KeyCode::Char('j'))),
+            Some(Action::MoveDown)
+        );
+        assert_eq!(
+            keymap.action_for(&mode, &press(KeyCode::F(2))),
+            Some(Action::ShowPorts)
+        );
+        assert_eq!(
+            keymap.action_for(
+                &mode,
+                &KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT)
+            ),
+            Some(Action::GoToBottom)
+        );
+        assert_eq!(keymap.action_for(&mode, &press(KeyCode::Char('z'))), None);
+    }
+
+    #[test]
+    fn test_config_replaces_action_keys() {
+        let keymap = Keymap::from_config(&config(&[("global", "kill", &["K", "Ctrl+d"])])).unwrap();
+        let mode = AppMode::PortView;
+
+        assert_eq!(keymap.action_for(&mode, &press(KeyCode::Char('x'))), None);
+        assert_eq!(
+            keymap.action_for(&mode, &press(KeyCode::Char('K'))),
+            Some(Action::Kill)
+        );
+        assert_eq!(
+            keymap.action_for(
+                &mode,
+                &KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)
+            ),
+            Some(Action::Kill)
+        );
+        // Plain `d` is still page down
+        assert_eq!(
+            keymap.action_for(&mode, &press(KeyCode::Char('d'))),
+            Some(Action::PageDown)
+        );
+        assert_eq!(keymap.hint(&mode, Action::Kill), "K");
+    }
+
+    #[test]
+    fn test_view_scope_overrides_global() {
+        let keymap =
+            Keymap::from_config(&config(&[("process_view", "cycle_sort", &["x"])])).unwrap();
+
+        let sort = keymap.action_for(&AppMode::ProcessView, &press(KeyCode::Char('x')));
+        assert_eq!(sort, Some(Action::CycleSort));
+        let kill = keymap.action_for(&AppMode::PortView, &press(KeyCode::Char('x')));
+        assert_eq!(kill, Some(Action::Kill));
+    }
+
+    #[test]
+    fn test_view_binding_replaces_global_keys() {
+        let keymap = Keymap::from_config(&config(&[("process_view", "kill", &["K"])])).unwrap();
+        let mode = AppMode::ProcessView;
+
+        // Only the keys the help shows kill in the process view
+        let help = keymap.help_rows(&mode).concat();
+        let kill_row = help
+            .iter()
+            .find(|(_, description)| *description == Action::Kill.description());
+        assert_eq!(kill_row.map(|(keys, _)| keys.as_str()), Some("K"));
+        assert_eq!(
+            keymap.action_for(&mode, &press(KeyCode::Char('K'))),
+            Some(Action::Kill)
+        );
+        assert_eq!(keymap.action_for(&mode, &press(KeyCode::Char('x'))), None);
+        assert_eq!(keymap.action_for(&mode, &press(KeyCode::Delete)), None);
+
+        // Other views keep the global keys
+        let kill = keymap.action_for(&AppMode::PortView, &press(KeyCode::Char('x')));
+        assert_eq!(kill, Some(Action::Kill));
+    }
+
+    #[test]
+    fn test_conflicts_are_rejected() {
+        let error = Keymap::from_config(&config(&[("global", "search", &["x"])]))
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("key 'x' is bound to both"));
+        assert!(error.contains("'search'") && error.contains("'kill'"));
+
+        // Rebinding the other action first resolves the conflict
+        assert!(Keymap::from_config(&config(&[
+            ("global", "search", &["x"]),
+            ("global", "kill", &["/"]),
+        ]))
+        .is_ok());
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(Keymap::from_config(&config(&[("global", "explode", &["e"])])).is_err());
+        assert!(Keymap::from_config(&config(&[("theme_view", "quit", &["q"])])).is_err());
+        assert!(Keymap::from_config(&config(&[("global", "quit", &["F13"])])).is_err());
+    }
+
+    #[test]
+    fn test_parse_and_display_keys() {
+        for key in [
+            "x",
+            "G",
+            "?",
+            "F4",
+            "PageDown",
+            "Space",
+            "Ctrl+r",
+            "Alt+Enter",
+        ] {
+            assert_eq!(parse_key(key).unwrap().to_string(), key);
+        }
+        assert_eq!(parse_key("ctrl+R").unwrap().to_string(), "Ctrl+R");
+        assert!(parse_key("").is_err());
+        assert!(parse_key("Hyper").is_err());
+    }
+
+    #[test]
+    fn test_help_rows_follow_bindings() {
+        let keymap = Keymap::from_config(&config(&[
+            ("global", "help", &["F1"]),
+            ("global", "show_processes", &["2"]),
+        ]))
+        .unwrap();
+        let rows = keymap.help_rows(&AppMode::Dashboard);
+        assert!(rows[1].contains(&("F1".to_string(), "Toggle this help")));
+        assert!(rows[1].contains(&("2".to_string(), "Processes")));
+        // Unbound actions are not listed
+        assert!(!rows[2].iter().any(|(_, d)| *d == "Toggle auto-refresh"));
+    }
+}
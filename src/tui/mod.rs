@@ -1,6 +1,11 @@
 pub mod app;
+pub mod collector;
 pub mod dashboard;
 pub mod events;
+pub mod keymap;
+pub mod mouse;
+pub mod selection;
+pub mod settings_editor;
 pub mod themes;
 
 pub use app::*;
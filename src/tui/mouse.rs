@@ -0,0 +1,110 @@
+use crate::tui::{AppMode, SortBy};
+use ratatui::layout::{Margin, Rect};
+use std::time::{Duration, Instant};
+
+/// Two clicks on the same target closer together than this are a double click.
+pub const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// Rows moved per scroll wheel step.
+pub const SCROLL_LINES: usize = 3;
+
+/// What clicking somewhere on screen does.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClickTarget {
+    /// A view name in the header
+    View(AppMode),
+    /// A sortable column heading
+    Sort(SortBy),
+    /// A row of the current view's list
+    Row(usize),
+    ConfirmDialog,
+    CancelDialog,
+}
+
+/// Screen areas of the last frame that react to clicks. Rebuilt on every
+/// draw, so it always matches what the user sees.
+#[derive(Debug, Clone, Default)]
+pub struct HitMap {
+    regions: Vec<(Rect, ClickTarget)>,
+}
+
+impl HitMap {
+    pub fn add(&mut self, area: Rect, target: ClickTarget) {
+        self.regions.push((area, target));
+    }
+
+    /// Register the visible rows of a bordered list drawn in `area` and
+    /// scrolled to `offset`.
+    pub fn add_list(&mut self, area: Rect, offset: usize, len: usize) {
+        let inner = area.inner(&Margin::new(1, 1));
+        for line in 0..inner.height {
+            let index = offset + line as usize;
+            if index >= len {
+                break;
+            }
+            let row = Rect::new(inner.x, inner.y + line, inner.width, 1);
+            self.add(row, ClickTarget::Row(index));
+        }
+    }
+
+    /// Drop everything registered so far, for overlays that cover the view.
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+
+    /// The target under a cell; later registrations are drawn on top.
+    pub fn target_at(&self, column: u16, row: u16) -> Option<&ClickTarget> {
+        self.regions
+            .iter()
+            .rev()
+            .find(|(area, _)| {
+                column >= area.x && column < area.right() && row >= area.y && row < area.bottom()
+            })
+            .map(|(_, target)| target)
+    }
+
+    /// Top-left cell of a target, if it is on screen.
+    #[allow(dead_code)] // Used by the TUI tests
+    pub fn position_of(&self, target: &ClickTarget) -> Option<(u16, u16)> {
+        self.regions
+            .iter()
+            .find(|(_, registered)| registered == target)
+            .map(|(area, _)| (area.x, area.y))
+    }
+}
+
+/// The previous left click, for spotting double clicks.
+#[derive(Debug, Clone)]
+pub struct LastClick {
+    pub target: ClickTarget,
+    pub at: Instant,
+}
+
+impl LastClick {
+    pub fn is_double(&self, target: &ClickTarget) -> bool {
+        &self.target == target && self.at.elapsed() < DOUBLE_CLICK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hit_map_rows_and_overlays() {
+        let mut hits = HitMap::default();
+        // A bordered list of 5 rows, 3 of them visible, scrolled by one
+        hits.add_list(Rect::new(0, 10, 20, 5), 1, 5);
+        assert_eq!(hits.target_at(5, 10), None); // top border
+        assert_eq!(hits.target_at(5, 11), Some(&ClickTarget::Row(1)));
+        assert_eq!(hits.target_at(5, 13), Some(&ClickTarget::Row(3)));
+        assert_eq!(hits.target_at(0, 12), None); // left border
+        assert_eq!(hits.position_of(&ClickTarget::Row(2)), Some((1, 12)));
+
+        hits.add(Rect::new(2, 11, 4, 1), ClickTarget::CancelDialog);
+        assert_eq!(hits.target_at(3, 11), Some(&ClickTarget::CancelDialog));
+
+        hits.clear();
+        assert_eq!(hits.target_at(5, 11), None);
+    }
+}
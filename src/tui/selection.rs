@@ -0,0 +1,79 @@
+use crate::network::{ConnectionInfo, PortInfo, Protocol};
+use crate::process::ProcessInfo;
+use std::net::SocketAddr;
+
+/// Identity of a list row that survives refreshes and re-sorting. Row
+/// indices don't: the same index points at another item once the list is
+/// collected again or sorted differently.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemKey {
+    /// The start time tells a reused PID apart from the original process
+    Process { pid: u32, start_time: u64 },
+    Port {
+        protocol: Protocol,
+        local_address: SocketAddr,
+        remote_address: Option<SocketAddr>,
+    },
+    Connection {
+        protocol: Protocol,
+        local_address: SocketAddr,
+        remote_address: SocketAddr,
+    },
+}
+
+impl ItemKey {
+    pub fn process(process: &ProcessInfo) -> Self {
+        ItemKey::Process {
+            pid: process.pid,
+            start_time: process.start_time,
+        }
+    }
+
+    pub fn port(port: &PortInfo) -> Self {
+        ItemKey::Port {
+            protocol: port.protocol.clone(),
+            local_address: port.local_address,
+            remote_address: port.remote_address,
+        }
+    }
+
+    pub fn connection(connection: &ConnectionInfo) -> Self {
+        ItemKey::Connection {
+            protocol: connection.protocol.clone(),
+            local_address: connection.local_address,
+            remote_address: connection.remote_address,
+        }
+    }
+}
+
+/// One multi-selected row.
+#[derive(Debug, Clone)]
+pub struct SelectedItem {
+    pub key: ItemKey,
+    /// How the item is named in dialogs and messages, kept for when it's gone
+    pub label: String,
+    /// No longer in the collected data. Exited items stay selected, and
+    /// marked, until the selection is cleared; they are never acted on.
+    pub exited: bool,
+}
+
+/// Label for a process in the selection.
+pub fn process_label(process: &ProcessInfo) -> String {
+    format!("{} (PID {})", process.name, process.pid)
+}
+
+/// Label for a port in the selection.
+pub fn port_label(port: &PortInfo) -> String {
+    match &port.process_name {
+        Some(name) => format!("{:?} {} ({name})", port.protocol, port.port),
+        None => format!("{:?} {}", port.protocol, port.port),
+    }
+}
+
+/// Label for a connection in the selection.
+pub fn connection_label(connection: &ConnectionInfo) -> String {
+    format!(
+        "{} -> {}",
+        connection.local_address, connection.remote_address
+    )
+}
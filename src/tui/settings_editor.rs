@@ -0,0 +1,223 @@
+use crate::commands::ProcessColumn;
+use crate::config::settings::{ConfirmationLevel, MemoryUnits, StartView, UserSettings, ViewSort};
+use crate::tui::{SortBy, SortOrder};
+use clap::ValueEnum;
+
+const REFRESH_STEP_MS: u64 = 250;
+const MIN_REFRESH_MS: u64 = 250;
+const MAX_REFRESH_MS: u64 = 60_000;
+
+/// One editable row on the settings screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingsField {
+    RefreshInterval,
+    AutoRefresh,
+    StartView,
+    ProcessSort,
+    ProcessSortOrder,
+    PortSortOrder,
+    ConnectionSort,
+    ConnectionSortOrder,
+    Confirmations,
+    MemoryUnits,
+    Column(ProcessColumn),
+}
+
+impl SettingsField {
+    /// Rows in display order: general settings, then one toggle per column.
+    pub fn all() -> Vec<SettingsField> {
+        let mut fields = vec![
+            SettingsField::RefreshInterval,
+            SettingsField::AutoRefresh,
+            SettingsField::StartView,
+            SettingsField::ProcessSort,
+            SettingsField::ProcessSortOrder,
+            SettingsField::PortSortOrder,
+            SettingsField::ConnectionSort,
+            SettingsField::ConnectionSortOrder,
+            SettingsField::Confirmations,
+            SettingsField::MemoryUnits,
+        ];
+        fields.extend(
+            ProcessColumn::value_variants()
+                .iter()
+                .map(|&column| SettingsField::Column(column)),
+        );
+        fields
+    }
+
+    pub fn label(self) -> String {
+        match self {
+            SettingsField::RefreshInterval => "Refresh interval".to_string(),
+            SettingsField::AutoRefresh => "Auto-refresh".to_string(),
+            SettingsField::StartView => "Start view".to_string(),
+            SettingsField::ProcessSort => "Process sort".to_string(),
+            SettingsField::ProcessSortOrder => "Process sort order".to_string(),
+            SettingsField::PortSortOrder => "Port sort order".to_string(),
+            SettingsField::ConnectionSort => "Connection sort".to_string(),
+            SettingsField::ConnectionSortOrder => "Connection sort order".to_string(),
+            SettingsField::Confirmations => "Confirmations".to_string(),
+            SettingsField::MemoryUnits => "Memory units".to_string(),
+            SettingsField::Column(column) => format!("Column: {}", column.header()),
+        }
+    }
+
+    /// Current value as shown on screen. `default_refresh_ms` comes from the
+    /// presets and applies until the interval is changed here.
+    pub fn value(self, settings: &UserSettings, default_refresh_ms: u64) -> String {
+        let on_off = |on: bool| if on { "on" } else { "off" }.to_string();
+        match self {
+            SettingsField::RefreshInterval => format!(
+                "{} ms",
+                settings.refresh_interval_ms.unwrap_or(default_refresh_ms)
+            ),
+            SettingsField::AutoRefresh => on_off(settings.auto_refresh),
+            SettingsField::StartView => format!("{:?}", settings.start_view),
+            SettingsField::ProcessSort => format!("{:?}", settings.process_sort.by),
+            SettingsField::ProcessSortOrder => format!("{:?}", settings.process_sort.order),
+            SettingsField::PortSortOrder => format!("{:?}", settings.port_sort.order),
+            SettingsField::ConnectionSort => format!("{:?}", settings.connection_sort.by),
+            SettingsField::ConnectionSortOrder => {
+                format!("{:?}", settings.connection_sort.order)
+            }
+            SettingsField::Confirmations => format!("{:?}", settings.confirmations),
+            SettingsField::MemoryUnits => format!(
+                "{:?} ({})",
+                settings.memory_units,
+                settings.memory_units.format(1536 * 1024 * 1024)
+            ),
+            SettingsField::Column(column) => on_off(settings.process_columns.contains(&column)),
+        }
+    }
+
+    /// Step the value forwards or backwards. Returns an error message when
+    /// the change isn't allowed.
+    pub fn adjust(
+        self,
+        settings: &mut UserSettings,
+        default_refresh_ms: u64,
+        forward: bool,
+    ) -> Result<(), String> {
+        match self {
+            SettingsField::RefreshInterval => {
+                let current = settings.refresh_interval_ms.unwrap_or(default_refresh_ms);
+                let next = if forward {
+                    current.saturating_add(REFRESH_STEP_MS)
+                } else {
+                    current.saturating_sub(REFRESH_STEP_MS)
+                };
+                settings.refresh_interval_ms = Some(next.clamp(MIN_REFRESH_MS, MAX_REFRESH_MS));
+            }
+            SettingsField::AutoRefresh => settings.auto_refresh = !settings.auto_refresh,
+            SettingsField::StartView => {
+                settings.start_view = cycle(&StartView::ALL, &settings.start_view, forward);
+            }
+            SettingsField::ProcessSort => {
+                let fields = [SortBy::Name, SortBy::Pid, SortBy::Cpu, SortBy::Memory];
+                cycle_sort_by(&mut settings.process_sort, &fields, forward);
+            }
+            SettingsField::ConnectionSort => {
+                let fields = [SortBy::LocalAddress, SortBy::RemoteAddress, SortBy::Pid];
+                cycle_sort_by(&mut settings.connection_sort, &fields, forward);
+            }
+            SettingsField::ProcessSortOrder => toggle_order(&mut settings.process_sort),
+            SettingsField::PortSortOrder => toggle_order(&mut settings.port_sort),
+            SettingsField::ConnectionSortOrder => toggle_order(&mut settings.connection_sort),
+            SettingsField::Confirmations => {
+                settings.confirmations =
+                    cycle(&ConfirmationLevel::ALL, &settings.confirmations, forward);
+            }
+            SettingsField::MemoryUnits => {
+                settings.memory_units = cycle(&MemoryUnits::ALL, &settings.memory_units, forward);
+            }
+            SettingsField::Column(column) => {
+                let columns = &mut settings.process_columns;
+                if columns.contains(&column) {
+                    if columns.len() == 1 {
+                        return Err("At least one column must stay visible".to_string());
+                    }
+                    columns.retain(|&c| c != column);
+                } else {
+                    // Keep columns in their canonical order
+                    columns.push(column);
+                    let order = ProcessColumn::value_variants();
+                    columns.sort_by_key(|c| order.iter().position(|o| o == c));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn cycle<T: PartialEq + Clone>(options: &[T], current: &T, forward: bool) -> T {
+    let index = options.iter().position(|o| o == current).unwrap_or(0);
+    let next = if forward {
+        (index + 1) % options.len()
+    } else {
+        (index + options.len() - 1) % options.len()
+    };
+    options[next].clone()
+}
+
+fn cycle_sort_by(sort: &mut ViewSort, fields: &[SortBy], forward: bool) {
+    sort.by = cycle(fields, &sort.by, forward);
+}
+
+fn toggle_order(sort: &mut ViewSort) {
+    sort.order = match sort.order {
+        SortOrder::Ascending => SortOrder::Descending,
+        SortOrder::Descending => SortOrder::Ascending,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjust_cycles_and_clamps() {
+        let mut settings = UserSettings::default();
+
+        SettingsField::RefreshInterval
+            .adjust(&mut settings, 2000, true)
+            .unwrap();
+        assert_eq!(settings.refresh_interval_ms, Some(2250));
+        settings.refresh_interval_ms = Some(MIN_REFRESH_MS);
+        SettingsField::RefreshInterval
+            .adjust(&mut settings, 2000, false)
+            .unwrap();
+        assert_eq!(settings.refresh_interval_ms, Some(MIN_REFRESH_MS));
+
+        SettingsField::MemoryUnits
+            .adjust(&mut settings, 2000, false)
+            .unwrap();
+        assert_eq!(settings.memory_units, MemoryUnits::Decimal);
+
+        SettingsField::ProcessSort
+            .adjust(&mut settings, 2000, true)
+            .unwrap();
+        assert_eq!(settings.process_sort.by, SortBy::Memory);
+    }
+
+    #[test]
+    fn test_column_toggles_keep_order_and_one_column() {
+        let mut settings = UserSettings {
+            process_columns: vec![ProcessColumn::Name, ProcessColumn::Mem],
+            ..UserSettings::default()
+        };
+
+        SettingsField::Column(ProcessColumn::Pid)
+            .adjust(&mut settings, 2000, true)
+            .unwrap();
+        assert_eq!(
+            settings.process_columns,
+            vec![ProcessColumn::Pid, ProcessColumn::Name, ProcessColumn::Mem]
+        );
+
+        settings.process_columns = vec![ProcessColumn::Name];
+        assert!(SettingsField::Column(ProcessColumn::Name)
+            .adjust(&mut settings, 2000, true)
+            .is_err());
+        assert_eq!(settings.process_columns, vec![ProcessColumn::Name]);
+    }
+}
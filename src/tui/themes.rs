@@ -1,4 +1,10 @@
+use crate::config::paths::config_dir;
+use anyhow::{anyhow, Context, Result};
 use ratatui::style::Color;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 #[derive(Debug, Clone)]
 pub struct Theme {
@@ -15,7 +21,118 @@ pub struct Theme {
 
 pub struct ThemeManager;
 
+/// A theme file from the themes directory. Without `inherits` every colour
+/// must be given; with it, only the colours that differ from the base.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    name: String,
+    /// Name of a built-in theme to start from
+    inherits: Option<String>,
+    background: Option<String>,
+    foreground: Option<String>,
+    primary: Option<String>,
+    secondary: Option<String>,
+    accent: Option<String>,
+    highlight: Option<String>,
+    border: Option<String>,
+    text_secondary: Option<String>,
+}
+
 impl ThemeManager {
+    /// Directory scanned for `*.toml` theme files.
+    pub fn themes_dir() -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join("themes"))
+    }
+
+    /// Built-in themes followed by the user themes from `themes_dir`, plus a
+    /// warning for each theme file that couldn't be loaded. A user theme
+    /// with the same name as a built-in one replaces it.
+    pub fn load_themes() -> (Vec<Theme>, Vec<String>) {
+        match Self::themes_dir() {
+            Some(dir) if dir.is_dir() => Self::load_themes_from(&dir),
+            _ => (Self::get_themes(), Vec::new()),
+        }
+    }
+
+    fn load_themes_from(dir: &Path) -> (Vec<Theme>, Vec<String>) {
+        let mut themes = Self::get_themes();
+        let mut warnings = Vec::new();
+
+        let mut paths: Vec<PathBuf> = match fs::read_dir(dir) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+                .collect(),
+            Err(e) => {
+                warnings.push(format!("Could not read {}: {e}", dir.display()));
+                Vec::new()
+            }
+        };
+        paths.sort();
+
+        let builtins = Self::get_themes();
+        for path in paths {
+            let loaded = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))
+                .and_then(|source| {
+                    Self::parse_theme(&source, &builtins)
+                        .with_context(|| format!("Invalid theme {}", path.display()))
+                });
+            match loaded {
+                Ok(theme) => match themes.iter_mut().find(|t| t.name == theme.name) {
+                    Some(existing) => *existing = theme,
+                    None => themes.push(theme),
+                },
+                Err(e) => warnings.push(format!("{e:#}")),
+            }
+        }
+
+        (themes, warnings)
+    }
+
+    /// Parse a theme file, resolving `inherits` against `builtins`.
+    /// Colours are hex (`#7fadad`), names (`light-blue`) or ANSI indices (`208`).
+    pub fn parse_theme(source: &str, builtins: &[Theme]) -> Result<Theme> {
+        let file: ThemeFile = toml::from_str(source)?;
+
+        let base = match &file.inherits {
+            Some(base) => Some(
+                builtins
+                    .iter()
+                    .find(|t| t.name.eq_ignore_ascii_case(base))
+                    .ok_or_else(|| anyhow!("inherits: unknown built-in theme '{base}'"))?,
+            ),
+            None => None,
+        };
+
+        let colour = |key: &str, value: &Option<String>, inherited: Option<Color>| match (
+            value, inherited,
+        ) {
+            (Some(value), _) => {
+                Color::from_str(value).map_err(|_| anyhow!("{key}: unknown colour '{value}'"))
+            }
+            (None, Some(color)) => Ok(color),
+            (None, None) => Err(anyhow!("{key}: missing (set it or use `inherits`)")),
+        };
+
+        Ok(Theme {
+            name: file.name.clone(),
+            background: colour("background", &file.background, base.map(|b| b.background))?,
+            foreground: colour("foreground", &file.foreground, base.map(|b| b.foreground))?,
+            primary: colour("primary", &file.primary, base.map(|b| b.primary))?,
+            secondary: colour("secondary", &file.secondary, base.map(|b| b.secondary))?,
+            accent: colour("accent", &file.accent, base.map(|b| b.accent))?,
+            highlight: colour("highlight", &file.highlight, base.map(|b| b.highlight))?,
+            border: colour("border", &file.border, base.map(|b| b.border))?,
+            text_secondary: colour(
+                "text_secondary",
+                &file.text_secondary,
+                base.map(|b| b.text_secondary),
+            )?,
+        })
+    }
+
     pub fn get_themes() -> Vec<Theme> {
         vec![
             // Kanagawa
@@ -141,3 +258,81 @@ impl ThemeManager {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_inherits_and_overrides() {
+        let builtins = ThemeManager::get_themes();
+        let theme = ThemeManager::parse_theme(
+            r##"
+            name = "Night Shift"
+            inherits = "nord"
+            accent = "#ff8800"
+            border = "light-blue"
+            highlight = "236"
+            "##,
+            &builtins,
+        )
+        .unwrap();
+
+        let nord = builtins.iter().find(|t| t.name == "Nord").unwrap();
+        assert_eq!(theme.name, "Night Shift");
+        assert_eq!(theme.accent, Color::Rgb(255, 136, 0));
+        assert_eq!(theme.border, Color::LightBlue);
+        assert_eq!(theme.highlight, Color::Indexed(236));
+        assert_eq!(theme.primary, nord.primary);
+    }
+
+    #[test]
+    fn test_invalid_themes_are_reported() {
+        let builtins = ThemeManager::get_themes();
+        let error = |source: &str| {
+            ThemeManager::parse_theme(source, &builtins)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(error("name = \"X\"\ninherits = \"Nope\"\n").contains("'Nope'"));
+        assert!(error("name = \"X\"\nprimary = \"#fff\"\n").contains("background: missing"));
+        assert!(
+            error("name = \"X\"\ninherits = \"Nord\"\naccent = \"blurple\"\n")
+                .contains("accent: unknown colour")
+        );
+        assert!(
+            error("name = \"X\"\ninherits = \"Nord\"\ncolour = \"red\"\n")
+                .contains("unknown field")
+        );
+    }
+
+    #[test]
+    fn test_load_themes_from_dir() {
+        let dir = std::env::temp_dir().join(format!("bossy-themes-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("dim.toml"),
+            "name = \"Dim\"\ninherits = \"Dracula\"\naccent = \"red\"\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("nord.toml"),
+            "name = \"Nord\"\ninherits = \"Nord\"\naccent = \"1\"\n",
+        )
+        .unwrap();
+        fs::write(dir.join("broken.toml"), "name = ").unwrap();
+        fs::write(dir.join("notes.txt"), "not a theme").unwrap();
+
+        let (themes, warnings) = ThemeManager::load_themes_from(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let builtin_count = ThemeManager::get_themes().len();
+        assert_eq!(themes.len(), builtin_count + 1);
+        assert_eq!(themes.last().unwrap().name, "Dim");
+        let nord = themes.iter().find(|t| t.name == "Nord").unwrap();
+        assert_eq!(nord.accent, Color::Indexed(1));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("broken.toml"));
+    }
+}
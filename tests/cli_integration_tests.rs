@@ -72,7 +72,10 @@ fn test_kill_process_command_help() {
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("Kill processes by name"))
-        .stdout(predicate::str::contains("--force"));
+        .stdout(predicate::str::contains("--force"))
+        .stdout(predicate::str::contains("--match"))
+        .stdout(predicate::str::contains("--dry-run"))
+        .stdout(predicate::str::contains("--all-users"));
 }
 
 #[test]
@@ -85,7 +88,11 @@ fn test_ps_command_help() {
         .stdout(predicate::str::contains("Show processes"))
         .stdout(predicate::str::contains("--top-cpu"))
         .stdout(predicate::str::contains("--top-memory"))
-        .stdout(predicate::str::contains("--limit"));
+        .stdout(predicate::str::contains("--limit"))
+        .stdout(predicate::str::contains("--filter"))
+        .stdout(predicate::str::contains("--sort"))
+        .stdout(predicate::str::contains("--columns"))
+        .stdout(predicate::str::contains("--children-of"));
 }
 
 #[test]
@@ -98,7 +105,9 @@ fn test_cleanup_command_help() {
         .stdout(predicate::str::contains(
             "Cleanup common development processes",
         ))
-        .stdout(predicate::str::contains("--dev"));
+        .stdout(predicate::str::contains("--dev"))
+        .stdout(predicate::str::contains("--group"))
+        .stdout(predicate::str::contains("--dry-run"));
 }
 
 #[test]
@@ -169,6 +178,69 @@ fn test_ps_command_with_limit() {
     cmd.assert().success();
 }
 
+#[test]
+fn test_ps_command_columns_csv() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args([
+        "ps",
+        "--sort",
+        "mem",
+        "--reverse",
+        "--columns",
+        "pid,user,command",
+        "--format",
+        "csv",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("pid,user,command\n"));
+}
+
+#[test]
+fn test_ps_command_invalid_column() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["ps", "--columns", "pid,bogus"]);
+
+    cmd.assert().code(2);
+}
+
+#[test]
+fn test_ps_command_sort_conflicts_with_top_cpu() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["ps", "--top-cpu", "--sort", "pid"]);
+
+    cmd.assert().code(2);
+}
+
+#[test]
+fn test_ps_watch_rejects_bad_interval() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["ps", "--watch", "soon"]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("invalid interval"));
+}
+
+#[test]
+fn test_watch_requires_table_output() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["ports", "--watch", "1", "--format", "json"]);
+
+    cmd.assert().code(1).stderr(predicate::str::contains(
+        "--watch only supports table output",
+    ));
+}
+
+#[test]
+fn test_highlight_requires_watch() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["ps", "--highlight"]);
+
+    cmd.assert().code(2);
+}
+
 #[test]
 fn test_ports_command_basic() {
     let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
@@ -217,20 +289,303 @@ fn test_kill_process_non_existent() {
     let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
     cmd.args(["kill-process", "non_existent_process_name_12345"]);
 
-    // Should succeed but report no processes found
+    // Should report no processes found with the "not found" exit status
     cmd.assert()
-        .success()
+        .code(3)
         .stdout(predicate::str::contains("No processes found"));
 }
 
+#[test]
+fn test_kill_port_unused() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["kill-port", "65534"]);
+
+    cmd.assert().code(3).stdout(predicate::str::contains(
+        "No process found using port 65534",
+    ));
+}
+
+#[test]
+fn test_kill_port_lookup_failure() {
+    // Without lsof on the PATH the lookup fails; that isn't "not found"
+    let empty = tempfile::TempDir::new().unwrap();
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.env("PATH", empty.path()).args(["kill-port", "65534"]);
+
+    cmd.assert()
+        .code(1)
+        .stderr(predicate::str::contains("Failed to run lsof"));
+}
+
+#[test]
+fn test_kill_process_dry_run_never_lists_itself() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["kill-process", "bossy-rust", "--dry-run", "--format", "csv"]);
+
+    // The only bossy-rust process may be this one, which is always excluded
+    cmd.assert()
+        .stdout(predicate::str::contains("kill-process bossy-rust --dry-run").not());
+}
+
+#[test]
+fn test_signal_rejects_unknown_signal() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["signal", "1", "SIGBOGUS"]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("unknown signal 'SIGBOGUS'"));
+}
+
+#[test]
+fn test_signal_non_existent() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["signal", "non_existent_process_name_12345", "HUP"]);
+
+    cmd.assert()
+        .code(3)
+        .stdout(predicate::str::contains("No processes found"));
+}
+
+#[test]
+fn test_signal_stop_and_continue() {
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .unwrap();
+    let pid = child.id().to_string();
+    let state = || {
+        let output = std::process::Command::new("ps")
+            .args(["-o", "stat=", "-p", &pid])
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    };
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["signal", &pid, "STOP"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Sent SIGSTOP to sleep"));
+    assert!(state().starts_with('T'), "stat: {}", state());
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["signal", &pid, "sigcont", "--format", "json"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"success\": true"));
+    assert!(!state().starts_with('T'), "stat: {}", state());
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn test_signal_by_other_name() {
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .unwrap();
+    let pid = child.id().to_string();
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["signal", &pid, "WINCH"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Sent SIGWINCH to sleep"));
+    // SIGWINCH is ignored unless handled
+    assert!(child.try_wait().unwrap().is_none());
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn test_renice() {
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .unwrap();
+    let pid = child.id().to_string();
+    let nice = || {
+        let output = std::process::Command::new("ps")
+            .args(["-o", "ni=", "-p", &pid])
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    };
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["renice", &pid, "5"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("nice 0 → 5"));
+    assert_eq!(nice(), "5");
+
+    // Out of range values are rejected before anything is changed
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["renice", &pid, "-21"]);
+    cmd.assert().code(2);
+    assert_eq!(nice(), "5");
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn test_renice_non_existent() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["renice", "99999999", "10"]);
+
+    cmd.assert()
+        .code(3)
+        .stdout(predicate::str::contains("Process 99999999 not found"));
+}
+
+#[test]
+fn test_history_records_signals() {
+    let data = tempfile::TempDir::new().unwrap();
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .unwrap();
+    let pid = child.id().to_string();
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.env("BOSSY_DATA", data.path()).args(["history"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No recorded actions"));
+
+    for signal in ["STOP", "CONT"] {
+        let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+        cmd.env("BOSSY_DATA", data.path())
+            .args(["signal", &pid, signal]);
+        cmd.assert().success();
+    }
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.env("BOSSY_DATA", data.path())
+        .args(["history", "--search", &pid, "--format", "json"]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let entries: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let entries = entries.as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["action"], "SIGSTOP");
+    assert_eq!(entries[1]["action"], "SIGCONT");
+    assert_eq!(entries[1]["initiator"], "cli");
+    assert_eq!(entries[1]["name"], "sleep");
+    assert_eq!(entries[1]["command_line"], "sleep 30");
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.env("BOSSY_DATA", data.path())
+        .args(["history", "--limit", "1"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("SIGCONT"))
+        .stdout(predicate::str::contains("SIGSTOP").not());
+}
+
+#[test]
+fn test_kill_process_invalid_regex() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["kill-process", "node(", "--match", "regex"]);
+
+    cmd.assert()
+        .code(1)
+        .stderr(predicate::str::contains("Invalid pattern"));
+}
+
+/// A scratch directory with the given `.bossy.toml`, removed by the caller.
+fn project_dir(name: &str, profile: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("bossy-cli-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join(".bossy.toml"), profile).unwrap();
+    dir
+}
+
+#[test]
+fn test_status_without_project_file() {
+    let dir = std::env::temp_dir().join(format!("bossy-cli-none-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.current_dir(&dir).arg("status");
+
+    cmd.assert()
+        .code(1)
+        .stderr(predicate::str::contains("No .bossy.toml found"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_status_reports_stopped_services() {
+    let dir = project_dir(
+        "status",
+        "name = \"demo\"\n[services.worker]\nprocess = \"non_existent_process_name_12345\"\n",
+    );
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.current_dir(&dir).args(["status", "--format", "csv"]);
+
+    // Nothing running maps to the "not found" exit status
+    cmd.assert()
+        .code(3)
+        .stdout(predicate::str::contains("worker,stopped"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_stop_with_nothing_running() {
+    let dir = project_dir(
+        "stop",
+        "[services.worker]\nprocess = \"non_existent_process_name_12345\"\n",
+    );
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.current_dir(&dir).args(["stop", "--yes"]);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Nothing to do"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_status_rejects_invalid_profile() {
+    let dir = project_dir("invalid", "[services.web]\nport = 0\n");
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.current_dir(&dir).arg("status");
+
+    cmd.assert()
+        .code(1)
+        .stderr(predicate::str::contains("services.web.port"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
 #[test]
 fn test_cleanup_without_dev_flag() {
     let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
     cmd.args(["cleanup"]);
 
     cmd.assert()
-        .success()
-        .stdout(predicate::str::contains("Please specify --dev"));
+        .code(2)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("Please specify --dev"));
+}
+
+#[test]
+fn test_cleanup_without_group_keeps_json_clean() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["cleanup", "--format", "json"]);
+
+    cmd.assert()
+        .code(2)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("Available groups:"));
 }
 
 #[test]
@@ -242,6 +597,51 @@ fn test_cleanup_with_dev_flag() {
     cmd.assert().success();
 }
 
+#[test]
+fn test_cleanup_dry_run_summarises_groups() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["cleanup", "--group", "frontend,databases", "--dry-run"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Cleanup summary"))
+        .stdout(predicate::str::contains("frontend"))
+        .stdout(predicate::str::contains("databases"));
+}
+
+#[test]
+fn test_cleanup_unknown_group() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["cleanup", "--group", "no_such_group", "--dry-run"]);
+
+    cmd.assert()
+        .code(1)
+        .stderr(predicate::str::contains("Unknown process group"));
+}
+
+#[test]
+fn test_action_lists_presets() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["--format", "csv", "action"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "action,name,description,processes,ports,confirmation_required",
+        ))
+        .stdout(predicate::str::contains("free_dev_ports"));
+}
+
+#[test]
+fn test_action_unknown_name() {
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.args(["action", "no_such_action", "--dry-run"]);
+
+    cmd.assert()
+        .code(1)
+        .stderr(predicate::str::contains("Unknown quick action"));
+}
+
 #[test]
 fn test_invalid_command() {
     let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
@@ -337,3 +737,103 @@ fn test_output_format_consistency() {
         .stdout(predicate::str::contains("PID"))
         .stdout(predicate::str::contains("Process"));
 }
+
+fn config_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("bossy-config-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_config_path_uses_config_flag() {
+    let dir = config_dir("path");
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.current_dir(&dir).env_remove("BOSSY_CONFIG").args([
+        "--config",
+        dir.to_str().unwrap(),
+        "config",
+        "path",
+        "--format",
+        "csv",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "user presets,{},false",
+            dir.join("presets.toml").display()
+        )));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_config_check_reports_line() {
+    let dir = config_dir("check");
+    std::fs::write(
+        dir.join("presets.toml"),
+        "[ui_settings]\nrefresh_interval_ms = 10\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.current_dir(&dir)
+        .env("BOSSY_CONFIG", &dir)
+        .args(["config", "check"]);
+
+    cmd.assert().code(1).stdout(predicate::str::contains(
+        "presets.toml:2: ui_settings.refresh_interval_ms",
+    ));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_config_check_rejects_short_refresh_interval() {
+    let dir = config_dir("interval");
+    std::fs::write(
+        dir.join("settings.toml"),
+        "theme_name = \"Nord\"\nrefresh_interval_ms = 0\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.current_dir(&dir)
+        .env("BOSSY_CONFIG", &dir)
+        .env("BOSSY_REFRESH_INTERVAL_MS", "50")
+        .args(["config", "check"]);
+
+    cmd.assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "settings.toml:2: refresh_interval_ms must be at least 100 (got 0)",
+        ))
+        .stdout(predicate::str::contains(
+            "BOSSY_REFRESH_INTERVAL_MS: invalid value '50'",
+        ));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_config_show_env_override() {
+    let dir = config_dir("show");
+
+    let mut cmd = Command::cargo_bin("bossy-rust").unwrap();
+    cmd.current_dir(&dir).env("BOSSY_THEME_NAME", "Nord").args([
+        "--config",
+        dir.to_str().unwrap(),
+        "config",
+        "show",
+        "--format",
+        "csv",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "settings.theme_name,\"\"\"Nord\"\"\",env BOSSY_THEME_NAME",
+        ))
+        .stdout(predicate::str::contains(
+            "settings.auto_refresh,true,default",
+        ));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -23,7 +23,7 @@ mod event_handling_tests {
 
     #[tokio::test]
     async fn test_event_polling_timeout() {
-        let handler = EventHandler::new(Duration::from_millis(10));
+        let mut handler = EventHandler::new(Duration::from_millis(10));
         
         // Should timeout and return Refresh event
         let start = std::time::Instant::now();
@@ -60,7 +60,7 @@ mod event_handling_tests {
 
     #[tokio::test]
     async fn test_event_handler_responsiveness() {
-        let handler = EventHandler::new(Duration::from_millis(1));
+        let mut handler = EventHandler::new(Duration::from_millis(1));
         
         // Test that handler responds quickly
         let start = std::time::Instant::now();
@@ -73,7 +73,7 @@ mod event_handling_tests {
 
     #[tokio::test]
     async fn test_multiple_event_polling() {
-        let handler = EventHandler::new(Duration::from_millis(5));
+        let mut handler = EventHandler::new(Duration::from_millis(5));
         
         // Poll multiple events quickly
         for _ in 0..5 {
@@ -84,7 +84,7 @@ mod event_handling_tests {
 
     #[tokio::test]
     async fn test_event_handler_error_resilience() {
-        let handler = EventHandler::new(Duration::from_millis(1));
+        let mut handler = EventHandler::new(Duration::from_millis(1));
         
         // Even if there are system issues, handler should not panic
         for _ in 0..10 {
@@ -241,9 +241,13 @@ mod event_integration_tests {
         helper.press_key(KeyCode::F(1)).await.unwrap();
         // Help state should toggle
         
-        // Test tab completion
+        // Tab opens the saved search picker; Esc closes it and stays in search
         helper.press_key(KeyCode::Tab).await.unwrap();
-        // Should handle tab completion
+        if helper.app.search_picker.is_some() {
+            helper.press_key(KeyCode::Esc).await.unwrap();
+            assert!(helper.app.search_picker.is_none());
+            assert!(helper.app.search_active);
+        }
         
         // Exit search mode
         helper.press_key(KeyCode::Esc).await.unwrap();
@@ -401,4 +405,73 @@ mod event_integration_tests {
         helper.press_key(KeyCode::Char('q')).await.unwrap();
         assert!(helper.app.should_quit);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_mouse_events() {
+        use bossy_rust::config::settings::ConfirmationLevel;
+        use bossy_rust::tui::mouse::ClickTarget;
+        use bossy_rust::tui::{AppMode, SortBy, SortOrder};
+        use crossterm::event::{MouseEvent, MouseEventKind};
+
+        let mut helper = TUITestHelper::new();
+        helper.app.settings.confirmations = ConfirmationLevel::Normal;
+
+        // View names in the header switch views
+        helper.render().unwrap();
+        helper
+            .click_target(&ClickTarget::View(AppMode::ProcessView))
+            .await
+            .unwrap();
+        assert_eq!(helper.app.mode, AppMode::ProcessView);
+
+        // Column headings sort, and flip the order when clicked again
+        helper.render().unwrap();
+        helper
+            .click_target(&ClickTarget::Sort(SortBy::Name))
+            .await
+            .unwrap();
+        assert_eq!(helper.app.sort_by, SortBy::Name);
+        assert_eq!(helper.app.sort_order, SortOrder::Ascending);
+        helper.render().unwrap();
+        helper
+            .click_target(&ClickTarget::Sort(SortBy::Name))
+            .await
+            .unwrap();
+        assert_eq!(helper.app.sort_order, SortOrder::Descending);
+
+        // Clicking a row selects it; the wheel moves the selection
+        helper.render().unwrap();
+        helper.click_target(&ClickTarget::Row(2)).await.unwrap();
+        assert_eq!(helper.app.selected_index, 2);
+        let scroll = MouseEvent {
+            kind: MouseEventKind::ScrollDown,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert!(helper
+            .app
+            .handle_event(AppEvent::Mouse(scroll))
+            .await
+            .unwrap());
+        assert_eq!(helper.app.selected_index, 5);
+
+        // Clicking outside any target does nothing
+        assert!(!helper.click(0, 0).await.unwrap());
+
+        // A double click opens the kill dialog, whose buttons are clickable
+        helper.render().unwrap();
+        helper.click_target(&ClickTarget::Row(1)).await.unwrap();
+        helper.click_target(&ClickTarget::Row(1)).await.unwrap();
+        assert_eq!(helper.app.selected_index, 1);
+        assert!(helper.app.confirmation_dialog.is_some());
+
+        helper.render().unwrap();
+        assert_eq!(helper.app.hit_map.position_of(&ClickTarget::Row(1)), None);
+        helper
+            .click_target(&ClickTarget::CancelDialog)
+            .await
+            .unwrap();
+        assert!(helper.app.confirmation_dialog.is_none());
+    }
+}